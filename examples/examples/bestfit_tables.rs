@@ -0,0 +1,71 @@
+//! Regenerates `src/codepage/bestfit_tables.rs` from the `WCTABLE` sections of Microsoft's `bestfit*.txt` files:
+//! <https://www.unicode.org/Public/MAPPINGS/VENDORS/MICSFT/WindowsBestFit/>
+//!
+//! ```text
+//! cargo run -p examples --example bestfit_tables -- path/to/WindowsBestFit > src/codepage/bestfit_tables.rs
+//! ```
+
+use winstr::*;
+
+use std::path::Path;
+
+fn main() {
+    let dir = std::env::args_os().nth(1).expect("usage: bestfit_tables path/to/WindowsBestFit");
+    let pages = SingleByteCodePage::ids().map(|id| Box::new(SingleByteCodePage::new(id).unwrap()) as Box<dyn CodePage>)
+        .chain(DoubleByteCodePage::ids().map(|id| Box::new(DoubleByteCodePage::new(id).unwrap()) as Box<dyn CodePage>));
+
+    let mut tables = Vec::new();
+    for cp in pages {
+        let path = Path::new(&dir).join(format!("bestfit{}.txt", cp.id()));
+        let text = match std::fs::read(&path) {
+            Ok(bytes)   => String::from_utf8_lossy(&bytes).into_owned(),
+            Err(err)    => { eprintln!("skipping code page {}: {}: {}", cp.id(), path.display(), err); continue },
+        };
+        let mut table = wctable(&text).into_iter().filter(|&(unit, code)| is_best_fit(&*cp, unit, code)).collect::<Vec<_>>();
+        table.sort_unstable();
+        table.dedup_by_key(|&mut (unit, _)| unit);
+        tables.push((cp.id(), table));
+    }
+
+    println!("// Windows best fit tables:  `(unit, code)` for each [u16] a code page can't represent exactly, but `WideCharToMultiByte`");
+    println!("// replaces with a similar character, sorted by `unit`.  `code` is a single byte (`0x00..=0xFF`) or a `lead << 8 | trail` pair.");
+    println!("//");
+    println!("// These mirror the `WCTABLE` sections of Microsoft's `bestfit*.txt` files, minus the mappings that round trip exactly.");
+    println!("// Regenerate with `cargo run -p examples --example bestfit_tables -- path/to/WindowsBestFit > src/codepage/bestfit_tables.rs`.");
+    println!();
+    println!("pub(super) static TABLES : &[(u32, &[(u16, u16)])] = &[");
+    for row in tables.chunks(5) {
+        let row = row.iter().map(|(id, _)| format!("({:<5} {:<11}),", format!("{},", id), format!("BESTFIT{}", id))).collect::<Vec<_>>();
+        println!("    {}", row.join(" "));
+    }
+    println!("];");
+    for (id, table) in tables.iter() {
+        println!();
+        println!("static BESTFIT{} : &[(u16, u16)] = &[", id);
+        for row in table.chunks(8) {
+            let row = row.iter().map(|&(unit, code)| if code <= 0xFF { format!("(0x{:04X}, 0x{:02X}),", unit, code) } else { format!("(0x{:04X}, 0x{:04X}),", unit, code) }).collect::<Vec<_>>();
+            println!("    {}", row.join(" "));
+        }
+        println!("];");
+    }
+}
+
+/// The `(unit, code)` entries of a `bestfit*.txt` file's `WCTABLE` section.
+fn wctable(text: &str) -> Vec<(u16, u16)> {
+    let mut lines = text.lines().map(|line| line.split(';').next().unwrap_or("").trim()).filter(|line| !line.is_empty());
+    lines.by_ref().find(|line| line.starts_with("WCTABLE")).expect("missing WCTABLE section");
+    lines.map_while(|line| {
+        let mut fields = line.split_whitespace();
+        Some((hex(fields.next()?)?, hex(fields.next()?)?))
+    }).collect()
+}
+
+fn hex(field: &str) -> Option<u16> {
+    u16::from_str_radix(field.strip_prefix("0x").or_else(|| field.strip_prefix("0X"))?, 16).ok()
+}
+
+/// `true` if `unit` can't be encoded exactly, but `code` decodes to a single [u16] that can stand in for it.
+fn is_best_fit(cp: &dyn CodePage, unit: u16, code: u16) -> bool {
+    let bytes = &code.to_be_bytes()[usize::from(code <= 0xFF)..];
+    cp.encode(&[unit]).is_err() && matches!(cp.decode(bytes), Ok(with) if with.len() == 1)
+}
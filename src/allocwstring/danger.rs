@@ -0,0 +1,366 @@
+// The dangerous bits of the allocator-owned WSTR wrappers.  Only code within
+// this module has access to `self.0`, hopefully making it simpler to audit
+// access / verify struct invariants are held.
+
+#![allow(unsafe_code)]
+
+#[cfg(windows)] use winapi::shared::ntdef::HRESULT;
+#[cfg(not(windows))] #[allow(clippy::upper_case_acronyms)] type HRESULT = i32;
+#[cfg(windows)] use winapi::um::combaseapi::{CoTaskMemAlloc, CoTaskMemFree};
+#[cfg(windows)] use winapi::um::winbase::{LocalAlloc, LocalFree};
+
+use crate::*;
+
+use std::marker::PhantomData;
+use std::ops::{Deref, Drop};
+use std::ptr::{null_mut, NonNull};
+use std::mem::size_of;
+
+const E_POINTER : HRESULT = 0x80004003_u32 as HRESULT;
+
+
+
+/// ### Allocator Invariants
+/// * An [AllocWString]&lt;A&gt; owns a non-null, `0u16`-terminated string without interior `0u16`s, just like [WString].
+/// * The string was allocated by `A` (or by a system API documented as using `A`'s allocator), and will be freed by `A::free`.
+/// * Ownership can be transferred in or out with [AllocWString::from_raw] / [AllocWString::into_raw].
+///
+/// ### Array Invariants
+/// * An [AllocWStrArray]&lt;A&gt; owns a single allocation by `A`, starting with an array of `len` string pointers.
+/// * Every string pointer is non-null, and points to a `0u16`-terminated string without interior `0u16`s, within the same allocation.
+/// * `&WStr` is ABI compatible with a non-null `LPCWSTR`, so the pointer array can be borrowed as a `&[&WStr]` directly.
+///
+/// ### Pointer Invariants
+/// * Win32 `LPWSTR` out-params may be null.
+/// * **Rust's [AllocWString]s are never null** (instead, use [Option]&lt;AllocWString&lt;A&gt;&gt;).
+mod invariants {}
+
+
+
+/// An allocator owning the strings of an [AllocWString], such as [CoTaskMem].
+///
+/// ### Safety
+///
+/// By implementing this trait, you promise that:
+/// * [alloc](Self::alloc) returns null, or a pointer to at least `bytes` writable bytes, aligned for both [u16] and pointers.
+/// * [free](Self::free) accepts any non-null pointer previously returned by [alloc](Self::alloc), from any thread.
+pub unsafe trait WStrAllocator {
+    /// Allocate `bytes` bytes of [u16]-aligned memory, or return null on failure.
+    fn alloc(bytes: usize) -> *mut u16;
+
+    /// Free memory previously allocated by [alloc](Self::alloc).
+    ///
+    /// ### Safety
+    ///
+    /// * `ptr` must have been allocated by this allocator, and must not be used after this call.
+    unsafe fn free(ptr: *mut u16);
+}
+
+/// The COM task allocator:  `CoTaskMemAlloc` / `CoTaskMemFree`.
+///
+/// Used for `LPOLESTR` out-params of `StringFromCLSID`, `ProgIDFromCLSID`, `SHGetKnownFolderPath`, `IShellItem::GetDisplayName`, etc.
+///
+/// On non-windows platforms, this falls back on a portable heap allocator, so code using [CoTaskWString] can be unit tested anywhere.
+#[derive(Debug)] pub enum CoTaskMem {}
+
+unsafe impl WStrAllocator for CoTaskMem {
+    #[cfg(windows)] fn alloc(bytes: usize) -> *mut u16 { unsafe { CoTaskMemAlloc(bytes) }.cast() }
+    #[cfg(windows)] unsafe fn free(ptr: *mut u16) { CoTaskMemFree(ptr.cast()) }
+    #[cfg(not(windows))] fn alloc(bytes: usize) -> *mut u16 { portable::alloc(bytes) }
+    #[cfg(not(windows))] unsafe fn free(ptr: *mut u16) { portable::free(ptr) }
+}
+
+/// The local heap allocator:  `LocalAlloc(LMEM_FIXED, ...)` / `LocalFree`.
+///
+/// Used by `FormatMessageW(FORMAT_MESSAGE_ALLOCATE_BUFFER, ...)`, `ConvertSidToStringSidW`, `CommandLineToArgvW`, etc.
+///
+/// On non-windows platforms, this falls back on a portable heap allocator, so code using [LocalWString] can be unit tested anywhere.
+#[derive(Debug)] pub enum LocalMem {}
+
+unsafe impl WStrAllocator for LocalMem {
+    #[cfg(windows)] fn alloc(bytes: usize) -> *mut u16 { unsafe { LocalAlloc(0 /* LMEM_FIXED */, bytes) }.cast() }
+    #[cfg(windows)] unsafe fn free(ptr: *mut u16) { LocalFree(ptr.cast()); }
+    #[cfg(not(windows))] fn alloc(bytes: usize) -> *mut u16 { portable::alloc(bytes) }
+    #[cfg(not(windows))] unsafe fn free(ptr: *mut u16) { portable::free(ptr) }
+}
+
+/// A size-prefixed [std::alloc] fallback for platforms without the real allocator.
+#[cfg(any(test, not(windows)))] mod portable {
+    use std::alloc::Layout;
+    const HEADER : usize = 16;
+
+    pub fn alloc(bytes: usize) -> *mut u16 {
+        let layout = match HEADER.checked_add(bytes).and_then(|size| Layout::from_size_align(size, HEADER).ok()) { Some(l) => l, None => return std::ptr::null_mut() };
+        let base = unsafe { std::alloc::alloc(layout) };
+        if base.is_null() { return std::ptr::null_mut(); }
+        unsafe { base.cast::<usize>().write(layout.size()) };
+        unsafe { base.add(HEADER) }.cast()
+    }
+
+    pub unsafe fn free(ptr: *mut u16) {
+        if ptr.is_null() { return; }
+        let base = ptr.cast::<u8>().sub(HEADER);
+        let size = base.cast::<usize>().read();
+        std::alloc::dealloc(base, Layout::from_size_align_unchecked(size, HEADER));
+    }
+}
+
+
+
+/// `AllocWString<A>` is a non-null, owned, `0u16`-terminated [UTF-16]ish string, allocated by `A`.
+///
+/// See [CoTaskWString] for the common case.
+///
+/// [UTF-16]:   https://en.wikipedia.org/wiki/UTF-16
+#[repr(transparent)] pub struct AllocWString<A: WStrAllocator>(NonNull<u16>, PhantomData<A>);
+
+/// `CoTaskWString` is a non-null, owned, `0u16`-terminated [UTF-16]ish string, freed with `CoTaskMemFree`.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// # #[allow(non_snake_case)] unsafe fn StringFromCLSID(_clsid: &(), lplpsz: *mut *mut u16) -> i32 {
+/// #     *lplpsz = CoTaskWString::from_str("{00000000-0000-0000-C000-000000000046}").unwrap().into_raw();
+/// #     0 // S_OK
+/// # }
+/// # let clsid = ();
+/// let s = unsafe { CoTaskWString::from_hresult_out(|p| StringFromCLSID(&clsid, p)) }.unwrap();
+/// assert_eq!(s, "{00000000-0000-0000-C000-000000000046}");
+/// ```
+///
+/// [UTF-16]:   https://en.wikipedia.org/wiki/UTF-16
+pub type CoTaskWString = AllocWString<CoTaskMem>;
+
+/// `LocalWString` is a non-null, owned, `0u16`-terminated [UTF-16]ish string, freed with `LocalFree`.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// # #[allow(non_snake_case)] unsafe fn ConvertSidToStringSidW(_sid: *mut (), string_sid: *mut *mut u16) -> i32 {
+/// #     *string_sid = LocalWString::from_str("S-1-5-18").unwrap().into_raw();
+/// #     1 // TRUE
+/// # }
+/// # let sid = std::ptr::null_mut();
+/// let (ok, s) = unsafe { LocalWString::from_out_param(|p| ConvertSidToStringSidW(sid, p)) };
+/// assert_ne!(ok, 0);
+/// assert_eq!(s.unwrap(), "S-1-5-18");
+/// ```
+///
+/// [UTF-16]:   https://en.wikipedia.org/wiki/UTF-16
+pub type LocalWString = AllocWString<LocalMem>;
+
+/// Requires that `A::free` is thread safe, per [WStrAllocator]'s safety requirements.
+/// `CoTaskMemFree` can free memory allocated by any thread, which is how COM passes strings between apartments.
+unsafe impl<A: WStrAllocator> Send for AllocWString<A> {}
+
+/// `AllocWString` is immutable:  nothing mutates its contents through a `&AllocWString`.
+unsafe impl<A: WStrAllocator> Sync for AllocWString<A> {}
+
+impl<A: WStrAllocator> Deref for AllocWString<A> {
+    type Target = WStr;
+    fn deref(&self) -> &WStr { unsafe { &*(self.0.as_ptr() as *const WStr) } }
+}
+
+impl<A: WStrAllocator> Drop for AllocWString<A> {
+    fn drop(&mut self) { unsafe { A::free(self.0.as_ptr()) } }
+}
+
+impl<A: WStrAllocator> AllocWString<A> {
+    /// Create an owned, `0u16`-terminated string from 0 or more [u16] unicode code points.
+    ///
+    /// Returns [None] if `code_units` contains a `0u16`, or if allocation fails.
+    pub fn from_code_units(code_units: impl Iterator<Item = u16>) -> Option<Self> {
+        let mut units0 = Vec::with_capacity(code_units.size_hint().0 + 1);
+        for unit in code_units {
+            if unit == 0 { return None; }
+            units0.push(unit);
+        }
+        units0.push(0u16);
+
+        let ptr = NonNull::new(A::alloc(units0.len().checked_mul(2)?))?;
+        // Safe: `ptr` has room for `units0.len()` [u16]s per WStrAllocator's safety requirements
+        unsafe { std::ptr::copy_nonoverlapping(units0.as_ptr(), ptr.as_ptr(), units0.len()) };
+        Some(Self(ptr, PhantomData))
+    }
+
+    /// Take ownership of a raw string.  Returns [None] if `ptr` is null.
+    ///
+    /// ### Safety
+    ///
+    /// * `ptr` must be null, or a valid `0u16`-terminated string allocated by `A`.
+    /// * `ptr` must not be freed or otherwise used by anything else afterwards.
+    pub unsafe fn from_raw(ptr: *mut u16) -> Option<Self> { NonNull::new(ptr).map(|ptr| Self(ptr, PhantomData)) }
+
+    /// Give up ownership of the string, returning a raw pointer that must eventually be freed by `A` (e.g. `CoTaskMemFree`.)
+    pub fn into_raw(self) -> *mut u16 { let ptr = self.0.as_ptr(); std::mem::forget(self); ptr }
+
+    /// Capture an `LPWSTR*` out-param of `f`, returning `f`'s result and the captured string (if `f` wrote a non-null one.)
+    ///
+    /// ### Safety
+    ///
+    /// * `f` must leave the out-param null, or set it to a valid `0u16`-terminated string allocated by `A`, which the caller now owns.
+    pub unsafe fn from_out_param<R>(f: impl FnOnce(&mut *mut u16) -> R) -> (R, Option<Self>) {
+        let mut ptr = null_mut();
+        let r = f(&mut ptr);
+        (r, Self::from_raw(ptr))
+    }
+
+    /// Capture an `LPWSTR*` out-param of a COM-style `f` returning a `HRESULT`.
+    ///
+    /// * If `f` fails, the error is returned, and anything written to the out-param is freed anyways.
+    /// * If `f` succeeds but leaves the out-param null, `E_POINTER` is returned.
+    ///
+    /// ### Safety
+    ///
+    /// * `f` must leave the out-param null, or set it to a valid `0u16`-terminated string allocated by `A`, which the caller now owns.
+    pub unsafe fn from_hresult_out(f: impl FnOnce(&mut *mut u16) -> HRESULT) -> Result<Self, HRESULT> {
+        let (hr, s) = Self::from_out_param(f);
+        if hr < 0 { return Err(hr); }
+        s.ok_or(E_POINTER)
+    }
+}
+
+
+
+/// `AllocWStrArray<A>` is an owned array of non-null, `0u16`-terminated [UTF-16]ish strings, sharing a single allocation by `A`.
+///
+/// Borrow the strings with [as_slice](Self::as_slice), indexing, or iteration.  See [LocalWStrArray] for the common case.
+///
+/// [UTF-16]:   https://en.wikipedia.org/wiki/UTF-16
+pub struct AllocWStrArray<A: WStrAllocator> {
+    ptr:    NonNull<*mut u16>,
+    len:    usize,
+    _alloc: PhantomData<A>,
+}
+
+/// `LocalWStrArray` is an owned array of strings, freed with a single `LocalFree`, such as the `argv` returned by `CommandLineToArgvW`.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// # #[allow(non_snake_case)] unsafe fn CommandLineToArgvW(cmdline: *const u16, argc: *mut i32) -> *mut *mut u16 {
+/// #     let cmdline = WStr::from_lpcwstr(&cmdline).unwrap();
+/// #     let argv = LocalWStrArray::from_entries(split_command_line(cmdline.units())).unwrap();
+/// #     *argc = argv.len() as i32;
+/// #     argv.into_raw().0
+/// # }
+/// let mut argc = 0;
+/// let argv = unsafe { CommandLineToArgvW(wstr!("app.exe \"a b\" c").as_lpcwstr(), &mut argc) };
+/// let argv = unsafe { LocalWStrArray::from_raw(argv, argc as usize) }.unwrap();
+/// assert_eq!(argv.len(), 3);
+/// assert_eq!(&argv[1], "a b");
+/// assert_eq!(argv.iter().map(|arg| arg.to_string_lossy()).collect::<Vec<_>>(), ["app.exe", "a b", "c"]);
+/// ```
+pub type LocalWStrArray = AllocWStrArray<LocalMem>;
+
+/// Requires that `A::free` is thread safe, per [WStrAllocator]'s safety requirements.
+unsafe impl<A: WStrAllocator> Send for AllocWStrArray<A> {}
+
+/// `AllocWStrArray` is immutable:  nothing mutates its contents through a `&AllocWStrArray`.
+unsafe impl<A: WStrAllocator> Sync for AllocWStrArray<A> {}
+
+impl<A: WStrAllocator> Drop for AllocWStrArray<A> {
+    fn drop(&mut self) { unsafe { A::free(self.ptr.as_ptr().cast()) } }
+}
+
+impl<A: WStrAllocator> AllocWStrArray<A> {
+    /// Create an owned array of strings in a single allocation, null-terminated like `CommandLineToArgvW`'s `argv`.
+    ///
+    /// Returns [None] if any entry contains an interior `'\0'`, or if allocation fails.
+    pub fn from_entries<I>(entries: I) -> Option<Self> where I: IntoIterator, I::Item: Utf16Hash {
+        let entries = entries.into_iter().map(|e| e.utf16_units().collect::<Vec<u16>>()).collect::<Vec<_>>();
+        if entries.iter().any(|e| e.contains(&0)) { return None; }
+        let len = entries.len();
+        let ptrs_bytes = len.checked_add(1)?.checked_mul(size_of::<*mut u16>())?;
+        let units_bytes = entries.iter().try_fold(0usize, |n, e| n.checked_add(e.len().checked_add(1)?.checked_mul(2)?))?;
+
+        let base = A::alloc(ptrs_bytes.checked_add(units_bytes)?);
+        let ptr = NonNull::new(base.cast::<*mut u16>())?;
+        unsafe {
+            // Safe: the allocation is pointer-aligned and has room for `len+1` pointers, followed by every entry's units (including terminators.)
+            let mut units = base.cast::<u8>().add(ptrs_bytes).cast::<u16>();
+            for (i, entry) in entries.iter().enumerate() {
+                std::ptr::copy_nonoverlapping(entry.as_ptr(), units, entry.len());
+                units.add(entry.len()).write(0);
+                ptr.as_ptr().add(i).write(units);
+                units = units.add(entry.len() + 1);
+            }
+            ptr.as_ptr().add(len).write(null_mut());
+        }
+        Some(Self { ptr, len, _alloc: PhantomData })
+    }
+
+    /// Take ownership of a raw array of `len` strings (e.g. `CommandLineToArgvW`'s result and `*pNumArgs`.)  Returns [None] if `ptr` is null.
+    ///
+    /// ### Safety
+    ///
+    /// * `ptr` must be null, or a single allocation by `A`, starting with `len` non-null pointers to `0u16`-terminated strings within that same allocation.
+    /// * `ptr` must not be freed or otherwise used by anything else afterwards.
+    pub unsafe fn from_raw(ptr: *mut *mut u16, len: usize) -> Option<Self> { Some(Self { ptr: NonNull::new(ptr)?, len, _alloc: PhantomData }) }
+
+    /// Borrow the array as a slice of strings.
+    pub fn as_slice(&self) -> &[&WStr] {
+        // Safe: `&WStr` is ABI compatible with a non-null `LPCWSTR`, and every pointer is non-null and valid per struct invariants.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr() as *const &WStr, self.len) }
+    }
+
+    /// Give up ownership of the array, returning the raw pointer and length.  The pointer must eventually be freed by `A` (e.g. `LocalFree`.)
+    pub fn into_raw(self) -> (*mut *mut u16, usize) { let r = (self.ptr.as_ptr(), self.len); std::mem::forget(self); r }
+}
+
+
+
+#[cfg(test)] use std::sync::atomic::{AtomicIsize, Ordering::SeqCst};
+#[cfg(test)] static TEST_LIVE_ALLOCS : AtomicIsize = AtomicIsize::new(0);
+#[cfg(test)] enum TestAlloc {}
+#[cfg(test)] unsafe impl WStrAllocator for TestAlloc {
+    fn alloc(bytes: usize) -> *mut u16 { TEST_LIVE_ALLOCS.fetch_add(1, SeqCst); portable::alloc(bytes) }
+    unsafe fn free(ptr: *mut u16) { TEST_LIVE_ALLOCS.fetch_sub(1, SeqCst); portable::free(ptr) }
+}
+
+#[test] fn layout() {
+    assert_eq!(size_of::<CoTaskWString>(),          size_of::<*mut u16>());
+    assert_eq!(size_of::<Option<CoTaskWString>>(),  size_of::<*mut u16>());
+    assert_eq!(size_of::<LocalWString>(),           size_of::<*mut u16>());
+    assert_eq!(size_of::<&WStr>(),                  size_of::<*mut u16>());
+    assert_eq!(std::mem::align_of::<&WStr>(),       std::mem::align_of::<*mut u16>());
+}
+
+#[test] fn out_params() {
+    type S = AllocWString<TestAlloc>;
+    let alloc = |s: &str| S::from_code_units(s.encode_utf16()).unwrap().into_raw();
+    {
+        let a = unsafe { S::from_hresult_out(|p| { *p = alloc("abc"); 0 }) }.unwrap();
+        assert_eq!(a.units0(), [b'a' as u16, b'b' as u16, b'c' as u16, 0]);
+        assert_eq!(TEST_LIVE_ALLOCS.load(SeqCst), 1);
+
+        assert_eq!(unsafe { S::from_hresult_out(|p| { *p = alloc("leaked?"); E_POINTER - 1 }) }.err(), Some(E_POINTER - 1));
+        assert_eq!(unsafe { S::from_hresult_out(|_| 1) }.err(), Some(E_POINTER));
+        assert_eq!(unsafe { S::from_out_param(|_| 42) }.0, 42);
+        assert!(unsafe { S::from_out_param(|_| ()) }.1.is_none());
+        assert!(S::from_code_units("a\0b".encode_utf16()).is_none());
+        assert_eq!(TEST_LIVE_ALLOCS.load(SeqCst), 1);
+
+        let raw = a.into_raw();
+        assert_eq!(unsafe { S::from_raw(raw) }.unwrap().units(), [b'a' as u16, b'b' as u16, b'c' as u16]);
+    }
+    assert_eq!(TEST_LIVE_ALLOCS.load(SeqCst), 0);
+}
+
+#[test] fn arrays() {
+    type A = LocalWStrArray;
+    let a = A::from_entries(vec!["a", "", "bc"]).unwrap();
+    assert_eq!(a.len(), 3);
+    assert_eq!(a[0].units0(), [b'a' as u16, 0]);
+    assert!(a[1].is_empty());
+    assert_eq!(a[2].units(), [b'b' as u16, b'c' as u16]);
+
+    let (ptr, len) = a.into_raw();
+    assert!(unsafe { *ptr.add(len) }.is_null());
+    let a = unsafe { A::from_raw(ptr, len) }.unwrap();
+    assert_eq!(a.iter().map(|s| s.len()).collect::<Vec<_>>(), [1, 0, 2]);
+    assert!(A::from_entries(Vec::<&str>::new()).unwrap().is_empty());
+    assert!(A::from_entries(vec!["a", "b\0c"]).is_none());
+    assert!(A::from_entries(vec![String::from("\0")]).is_none());
+    assert!(unsafe { A::from_raw(null_mut(), 0) }.is_none());
+}
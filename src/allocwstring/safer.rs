@@ -0,0 +1,148 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+
+use std::borrow::{Borrow, Cow};
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Index;
+#[cfg(windows)] use std::ffi::{OsStr, OsString};
+#[cfg(windows)] use std::os::windows::ffi::OsStrExt;
+
+
+
+#[cfg(feature = "display")]
+impl<A: WStrAllocator> Display          for AllocWString<A> { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&**self, fmt) } }
+impl<A: WStrAllocator> Debug            for AllocWString<A> { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(&**self, fmt) } }
+impl<A: WStrAllocator> AsRef<WStr>      for AllocWString<A> { fn as_ref(&self) -> &WStr { self } }
+impl<A: WStrAllocator> AsRef<[u16]>     for AllocWString<A> { fn as_ref(&self) -> &[u16] { self.units() } }
+impl<A: WStrAllocator> Borrow<WStr>     for AllocWString<A> { fn borrow(&self) -> &WStr { self } }
+impl<A: WStrAllocator> Borrow<[u16]>    for AllocWString<A> { fn borrow(&self) -> &[u16] { self.units() } }
+impl<A: WStrAllocator> Clone            for AllocWString<A> { fn clone(&self) -> Self { Self::from_wstr(self).unwrap() } }
+impl<A: WStrAllocator> From<&WStr>      for AllocWString<A> { fn from(value: &WStr      ) -> Self { Self::from_wstr(value).unwrap() } }
+impl<A: WStrAllocator> From<&str>       for AllocWString<A> { fn from(value: &str       ) -> Self { Self::from_str(value).unwrap() } }
+impl<A: WStrAllocator> From<&String>    for AllocWString<A> { fn from(value: &String    ) -> Self { Self::from_str(value).unwrap() } }
+impl<A: WStrAllocator> From< String>    for AllocWString<A> { fn from(value:  String    ) -> Self { Self::from_str(&value).unwrap() } }
+#[cfg(windows)] impl<A: WStrAllocator> From<&OsStr>     for AllocWString<A> { fn from(value: &OsStr     ) -> Self { Self::from_osstr(value).unwrap() } }
+#[cfg(windows)] impl<A: WStrAllocator> From<&OsString>  for AllocWString<A> { fn from(value: &OsString  ) -> Self { Self::from_osstr(value).unwrap() } }
+#[cfg(windows)] impl<A: WStrAllocator> From< OsString>  for AllocWString<A> { fn from(value:  OsString  ) -> Self { Self::from_osstr(&value).unwrap() } }
+#[cfg(windows)] #[cfg(feature = "bstr")] impl<A: WStrAllocator> From<&BStr> for AllocWString<A> { fn from(value: &BStr) -> Self { Self::from_bstr(value).unwrap() } }
+impl<A: WStrAllocator> From<&AllocWString<A>> for WString  { fn from(value: &AllocWString<A>) -> Self { WString::from_wstr(value) } }
+impl<A: WStrAllocator> Eq               for AllocWString<A> {}
+impl<A: WStrAllocator> Ord              for AllocWString<A> { fn cmp(&self, other: &Self) -> Ordering { self.units().cmp(other.units()) } }
+impl<A: WStrAllocator> Hash             for AllocWString<A> { fn hash<H: Hasher>(&self, state: &mut H) { self.units().hash(state) } }
+
+peo!(impl[A: WStrAllocator] AllocWString<A>, AllocWString<A>);
+
+peo!(impl[A: WStrAllocator] &WStr,              AllocWString<A>); peo!(impl[A: WStrAllocator] AllocWString<A>, &WStr           );
+peo!(impl[A: WStrAllocator] WString,            AllocWString<A>); peo!(impl[A: WStrAllocator] AllocWString<A>, WString         );
+peo!(impl[A: WStrAllocator] &?[u16],            AllocWString<A>); peo!(impl[A: WStrAllocator] AllocWString<A>, &?[u16]         );
+peo!(impl[A: WStrAllocator] &?str,              AllocWString<A>); peo!(impl[A: WStrAllocator] AllocWString<A>, &?str           );
+peo!(impl[A: WStrAllocator] String,             AllocWString<A>); peo!(impl[A: WStrAllocator] AllocWString<A>, String          );
+peo!(impl[A: WStrAllocator] Cow<'_, [u16]>,     AllocWString<A>); peo!(impl[A: WStrAllocator] AllocWString<A>, Cow<'_, [u16]>  );
+peo!(impl[A: WStrAllocator] Cow<'_, str>,       AllocWString<A>); peo!(impl[A: WStrAllocator] AllocWString<A>, Cow<'_, str>    );
+
+#[cfg(windows)] mod windows_peo {
+    use super::*;
+    peo!(impl[A: WStrAllocator] &?OsStr,        AllocWString<A>); peo!(impl[A: WStrAllocator] AllocWString<A>, &?OsStr         );
+    peo!(impl[A: WStrAllocator] OsString,       AllocWString<A>); peo!(impl[A: WStrAllocator] AllocWString<A>, OsString        );
+
+    #[cfg(feature = "bstr")] peo!(impl[A: WStrAllocator] &BStr,   AllocWString<A>); #[cfg(feature = "bstr")] peo!(impl[A: WStrAllocator] AllocWString<A>, &BStr  );
+    #[cfg(feature = "bstr")] peo!(impl[A: WStrAllocator] BString, AllocWString<A>); #[cfg(feature = "bstr")] peo!(impl[A: WStrAllocator] AllocWString<A>, BString);
+}
+
+impl<'s, A: WStrAllocator> UTF16ish<'s> for AllocWString<A> {
+    type Iter = std::iter::Copied<std::slice::Iter<'s, u16>>;
+    fn utf16ish(&'s self) -> Self::Iter { self.units().iter().copied() }
+}
+
+impl<A: WStrAllocator> Utf16Hash for AllocWString<A> { fn utf16_units(&self) -> Utf16Units<'_> { self.units().into() } }
+
+
+
+impl<A: WStrAllocator> AllocWString<A> {
+    /// Create an [AllocWString] from a [str].  Returns [None] if `s` contains `'\0'`, or if allocation fails.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: impl AsRef<str>) -> Option<Self> { Self::from_code_units(s.as_ref().encode_utf16()) }
+
+    /// Create an [AllocWString] from a [OsStr].  Returns [None] if `s` contains `'\0'`, or if allocation fails.
+    #[cfg(windows)]
+    pub fn from_osstr(s: impl AsRef<OsStr>) -> Option<Self> { Self::from_code_units(s.as_ref().encode_wide()) }
+
+    /// Create an [AllocWString] from a [BStr].  Returns [None] if `s` contains `'\0'`, or if allocation fails.
+    #[cfg(windows)] #[cfg(feature = "bstr")]
+    pub fn from_bstr(s: impl AsRef<BStr>) -> Option<Self> { Self::from_code_units(s.as_ref().units().iter().copied()) }
+
+    /// Create an [AllocWString] from a [WStr].  Returns [None] if allocation fails.
+    pub fn from_wstr(s: impl AsRef<WStr>) -> Option<Self> { Self::from_code_units(s.as_ref().units().iter().copied()) }
+
+    /// Copy the string into a [WString].
+    pub fn to_wstring(&self) -> WString { WString::from_wstr(self) }
+}
+
+
+
+impl<A: WStrAllocator> Debug            for AllocWStrArray<A> { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(self.as_slice(), fmt) } }
+impl<A: WStrAllocator> Clone            for AllocWStrArray<A> { fn clone(&self) -> Self { Self::from_entries(self.iter()).unwrap() } }
+impl<A: WStrAllocator> Index<usize>     for AllocWStrArray<A> { type Output = WStr; fn index(&self, index: usize) -> &WStr { self.as_slice()[index] } }
+impl<A: WStrAllocator> Eq               for AllocWStrArray<A> {}
+impl<A: WStrAllocator> PartialEq        for AllocWStrArray<A> { fn eq(&self, other: &Self) -> bool { self.as_slice() == other.as_slice() } }
+impl<A: WStrAllocator> Ord              for AllocWStrArray<A> { fn cmp(&self, other: &Self) -> Ordering { self.as_slice().cmp(other.as_slice()) } }
+impl<A: WStrAllocator> PartialOrd       for AllocWStrArray<A> { fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) } }
+impl<A: WStrAllocator> Hash             for AllocWStrArray<A> { fn hash<H: Hasher>(&self, state: &mut H) { self.as_slice().hash(state) } }
+
+impl<'a, A: WStrAllocator> IntoIterator for &'a AllocWStrArray<A> {
+    type Item = &'a WStr;
+    type IntoIter = std::iter::Copied<std::slice::Iter<'a, &'a WStr>>;
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<A: WStrAllocator> AllocWStrArray<A> {
+    /// The number of strings in the array.
+    pub fn len(&self) -> usize { self.as_slice().len() }
+
+    /// Returns `true` if the array contains no strings.
+    pub fn is_empty(&self) -> bool { self.as_slice().is_empty() }
+
+    /// Get the string at `index`, or [None] if out of bounds.
+    pub fn get(&self, index: usize) -> Option<&WStr> { self.as_slice().get(index).copied() }
+
+    /// Iterate over the strings of the array.
+    pub fn iter(&self) -> std::iter::Copied<std::slice::Iter<'_, &WStr>> { self.as_slice().iter().copied() }
+
+    /// Copy the strings into [WString]s.
+    pub fn to_wstrings(&self) -> Vec<WString> { self.iter().map(WString::from_wstr).collect() }
+}
+
+
+
+#[test] fn comparisons() {
+    let a = CoTaskWString::from("abc");
+    let b = a.clone();
+    assert_eq!(a, b);
+    assert_eq!(a, "abc");
+    assert_eq!("abc", a);
+    assert_eq!(a, wstr!("abc"));
+    assert_eq!(a.to_wstring(), a);
+    assert_eq!(WString::from(&a), "abc");
+    assert_eq!(a, &[b'a' as u16, b'b' as u16, b'c' as u16][..]);
+    assert!(a < *"abd");
+    assert!(a > *"ab");
+    assert_eq!(format!("{:?}", CoTaskWString::from_code_units([b'a' as u16, 0xD800].iter().copied()).unwrap()), "\"a\\u{d800}\"");
+    #[cfg(feature = "display")] assert_eq!(format!("{}", a), "abc");
+    assert!(CoTaskWString::from_str("a\0b").is_none());
+}
+
+#[test] fn array_traits() {
+    let a = LocalWStrArray::from_entries(vec!["a", "b c"]).unwrap();
+    let b = a.clone();
+    assert_eq!(a, b);
+    assert!(a < LocalWStrArray::from_entries(vec!["a", "c"]).unwrap());
+    assert_eq!(format!("{:?}", a), r#"["a", "b c"]"#);
+    assert_eq!(a.get(1).unwrap(), "b c");
+    assert!(a.get(2).is_none());
+    assert_eq!((&a).into_iter().count(), 2);
+    assert_eq!(a.to_wstrings(), [WString::from_str("a").unwrap(), WString::from_str("b c").unwrap()]);
+    assert_eq!(LocalWString::from("x"), "x");
+}
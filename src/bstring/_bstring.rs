@@ -16,5 +16,6 @@ macro_rules! bstr {
 }
 
 mod danger; pub use danger::*;
+mod interner;
 mod safer;
 mod traits; pub use traits::*;
@@ -8,9 +8,13 @@ use winapi::shared::wtypes::BSTR;
 use winapi::shared::wtypesbase::OLECHAR;
 use winapi::um::oleauto::*;
 
+use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
 use std::convert::TryInto;
 use std::ops::{Deref, Drop};
 use std::ptr::{null, null_mut, NonNull};
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::{compiler_fence, Ordering};
 
 
 
@@ -41,7 +45,23 @@ fn bounds_check_len32(len: impl TryInto<u32>) -> Option<u32> {
 
 
 
-/// `BString` is a non-null, owned, [BSTR] (32-bit length prefixed [UTF-16]ish string).
+/// `BString` is a non-null, owned, immutable [BSTR] (32-bit length prefixed [UTF-16]ish string).
+///
+/// Use [BStringMut] to modify a string in place - converting between the two is free:
+///
+/// ```compile_fail
+/// # use winstr::*;
+/// let mut s = BString::from("immutable");
+/// s.units_mut()[0] = b'I' as u16;
+/// ```
+///
+/// ```
+/// # use winstr::*;
+/// let mut s = BStringMut::from(BString::from("mutable"));
+/// s.units_mut()[0] = b'M' as u16;
+/// let s = BString::from(s);
+/// assert_eq!(s, "Mutable");
+/// ```
 ///
 /// [BSTR]:     https://docs.microsoft.com/en-us/previous-versions/windows/desktop/automat/bstr
 /// [UTF-16]:   https://en.wikipedia.org/wiki/UTF-16
@@ -55,8 +75,10 @@ fn bounds_check_len32(len: impl TryInto<u32>) -> Option<u32> {
 /// A: [\[...\] All together, it is okay to free string from another thread.](https://stackoverflow.com/a/31342171)
 unsafe impl Send for BString {}
 
-// In the current implementation, BString could be made `Sync`.  However, this would be a commitment to keeping BString's
-// contents immutable - and I'm not sure if such a commitment is useful or wise just yet.  You can certainly mutate BSTRs.
+/// `BString` is immutable:  nothing mutates its contents through a `&BString` (or the `&BStr` it derefs to.)  Sharing a
+/// `&BString` between threads thus only ever results in concurrent reads, which is fine.  In-place mutation requires
+/// converting to a [BStringMut] first, which requires ownership - and thus exclusive access - of the `BString`.
+unsafe impl Sync for BString {}
 
 impl Deref for BString {
     type Target = BStr;
@@ -97,6 +119,253 @@ impl BString {
 
 
 
+/// `BStringMut` is a non-null, owned, mutable [BSTR] (32-bit length prefixed [UTF-16]ish string).
+///
+/// Convert to/from a [BString] with [From] - this doesn't copy or reallocate the string.
+///
+/// [BSTR]:     https://docs.microsoft.com/en-us/previous-versions/windows/desktop/automat/bstr
+/// [UTF-16]:   https://en.wikipedia.org/wiki/UTF-16
+#[repr(transparent)] pub struct BStringMut(BString);
+
+// BStringMut is implicitly Send and Sync:  all mutation goes through `&mut self`.
+
+impl Deref for BStringMut {
+    type Target = BStr;
+    fn deref(&self) -> &BStr { &self.0 }
+}
+
+impl From<BString> for BStringMut {
+    fn from(value: BString) -> Self { Self(value) }
+}
+
+impl From<BStringMut> for BString {
+    fn from(value: BStringMut) -> Self { value.0 }
+}
+
+impl BStringMut {
+    /// Allocate a [BStringMut] of `len` `0u16`s, to be filled in via [units_mut](Self::units_mut).
+    pub fn new(len: usize) -> Option<Self> { BString::from_code_units(std::iter::repeat_n(0u16, len)).map(Self) }
+
+    /// The [u16] unicode [code unit]s of the string, excluding the terminal `0u16`
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    #[cfg(not(target_pointer_width = "16"))]
+    pub fn units_mut(&mut self) -> &mut [u16] {
+        // Safe: `self` has exclusive ownership of the BSTR, len < len+1 == bstr alloc size, terminal `0u16` excluded
+        unsafe { std::slice::from_raw_parts_mut((self.0).0.as_ptr(), self.len()) }
+    }
+}
+
+
+
+/// `SecureBString` is a non-null, owned, [BSTR] (32-bit length prefixed [UTF-16]ish string) for passwords and other secrets.
+///
+/// * The string's contents are zeroed (with volatile writes) before `SysFreeString` is called, which might otherwise
+///   leave them lying around in the BSTR cache or freed heap memory.
+/// * `Debug` and (with the `display` feature) `Display` are redacted.
+/// * Constructors write directly into the final `BSTR`, without intermediate `String`s or `Vec<u16>`s.
+///
+/// Of course, this can't do anything about copies made by whatever you pass the string to.
+///
+/// [BSTR]:     https://docs.microsoft.com/en-us/previous-versions/windows/desktop/automat/bstr
+/// [UTF-16]:   https://en.wikipedia.org/wiki/UTF-16
+#[repr(transparent)] pub struct SecureBString(BString);
+
+impl Deref for SecureBString {
+    type Target = BStr;
+    fn deref(&self) -> &BStr { &self.0 }
+}
+
+impl Drop for SecureBString {
+    fn drop(&mut self) { self.zero() } // self.0 is freed afterwards
+}
+
+impl From<BString> for SecureBString {
+    /// Take ownership of `value`, ensuring it's zeroed when dropped.
+    fn from(value: BString) -> Self { Self(value) }
+}
+
+impl SecureBString {
+    fn zero(&mut self) {
+        let bstr = (self.0).0.as_ptr();
+        for off in 0..self.len() {
+            // Safe: off < len < len+1 == bstr alloc size, and `self` has exclusive ownership of the BSTR
+            unsafe { std::ptr::write_volatile(bstr.add(off), 0u16) };
+        }
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+/// Overwrite `s` with `'\0'`s using volatile writes that won't be optimized away.
+pub(super) fn zero_str(s: &mut str) {
+    // Safe: a string of `'\0'`s is still valid UTF-8
+    for b in unsafe { s.as_bytes_mut() }.iter_mut() {
+        // Safe: `b` is a valid, exclusive reference
+        unsafe { std::ptr::write_volatile(b, 0u8) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+
+
+/// `SharedBString` is a non-null, reference counted, immutable [BSTR] (32-bit length prefixed [UTF-16]ish string).
+///
+/// Cloning a `SharedBString` bumps a reference count instead of copying the string, making it suitable for caching large
+/// strings and handing them out to many threads.  The string itself is never mutated while shared.
+///
+/// Windows 8+ has [SysAddRefString] / [SysReleaseString] for pinning BSTRs, but those don't exist on Windows 7, aren't
+/// exposed by `winapi`, and only delay a `SysFreeString` rather than owning the string outright.  Instead, this simply
+/// reference counts an ordinary [BString] - the resulting `BSTR` remains a perfectly ordinary `BSTR` to any API it's
+/// passed to.
+///
+/// [BSTR]:             https://docs.microsoft.com/en-us/previous-versions/windows/desktop/automat/bstr
+/// [SysAddRefString]:  https://docs.microsoft.com/en-us/windows/win32/api/oleauto/nf-oleauto-sysaddrefstring
+/// [SysReleaseString]: https://docs.microsoft.com/en-us/windows/win32/api/oleauto/nf-oleauto-sysreleasestring
+/// [UTF-16]:           https://en.wikipedia.org/wiki/UTF-16
+#[derive(Clone)] pub struct SharedBString(Arc<BString>);
+
+impl Deref for SharedBString {
+    type Target = BStr;
+    fn deref(&self) -> &BStr { &self.0 }
+}
+
+impl From<BString> for SharedBString {
+    fn from(value: BString) -> Self { Self(Arc::new(value)) }
+}
+
+impl SharedBString {
+    /// Attempt to reclaim exclusive ownership of the underlying [BString] without copying it.
+    ///
+    /// Fails, returning `self`, if there are other clones of this `SharedBString` still alive.
+    pub fn try_unwrap(self) -> Result<BString, SharedBString> { Arc::try_unwrap(self.0).map_err(SharedBString) }
+
+    /// Returns `true` if `a` and `b` are clones of the same `SharedBString` (and thus share the same `BSTR`.)
+    pub fn ptr_eq(a: &SharedBString, b: &SharedBString) -> bool { Arc::ptr_eq(&a.0, &b.0) }
+}
+
+
+
+/// `BStrInterner` maps strings to stable, deduplicated &amp;[BStr]s, allocating only once per unique string.
+///
+/// Useful for e.g. member names passed to `IDispatch::GetIDsOfNames` over and over again.
+/// Interned strings live until the interner itself is dropped.
+pub struct BStrInterner {
+    pub(super) case_insensitive:    bool,
+    pub(super) hasher:              RandomState,
+    table:                          RwLock<HashMap<u64, Vec<BString>>>,
+}
+
+impl BStrInterner {
+    pub(super) fn with_case_insensitivity(case_insensitive: bool) -> Self {
+        Self { case_insensitive, hasher: RandomState::new(), table: Default::default() }
+    }
+
+    /// Find a previously interned string with the given `hash`, for which `eq` returns `true`.
+    pub(super) fn find(&self, hash: u64, eq: impl Fn(&BStr) -> bool) -> Option<&BStr> {
+        let table = self.table.read().unwrap_or_else(|err| err.into_inner());
+        let bstr : &BStr = table.get(&hash)?.iter().find(|b| eq(b))?;
+        // Safe:  Interned `BString`s are never removed or mutated until `self` is dropped, and their `BSTR`s stay put
+        // even if the `BString`s themselves are moved when `table` reallocates.  `&BStr` can thus outlive the lock guard.
+        Some(unsafe { &*(bstr as *const BStr) })
+    }
+
+    /// Find a previously interned string with the given `hash`, for which `eq` returns `true` - or intern `make()`.
+    pub(super) fn find_or_insert(&self, hash: u64, eq: impl Fn(&BStr) -> bool, make: impl FnOnce() -> BString) -> &BStr {
+        if let Some(bstr) = self.find(hash, &eq) { return bstr; }
+        let mut table = self.table.write().unwrap_or_else(|err| err.into_inner());
+        let bucket = table.entry(hash).or_default();
+        let bstr : &BStr = match bucket.iter().position(|b| eq(b)) {
+            Some(i) => &bucket[i], // interned by another thread between `find` and `write`
+            None    => { bucket.push(make()); bucket.last().unwrap() },
+        };
+        // Safe:  See `find`
+        unsafe { &*(bstr as *const BStr) }
+    }
+
+    /// The number of unique strings interned so far.
+    pub fn len(&self) -> usize { self.table.read().unwrap_or_else(|err| err.into_inner()).values().map(|b| b.len()).sum() }
+}
+
+
+
+/// `BStrBuf<N>` is a fixed-capacity [BSTR] laid out inline (e.g. on the stack):  a 4-byte length prefix, `N` [u16]s, and a terminal `0u16`.
+///
+/// Like [bstr!], the resulting &amp;[BStr] was never `SysAlloc`ed, making it suitable **only** for `[in] BSTR` parameters that
+/// don't escape the call.  Never pass it to anything that might `SysFreeString` or `SysReAllocString` it, or hang onto it.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// let buf = BStrBuf::<16>::from_str("Name").unwrap();
+/// assert_eq!(buf.len32(), 4);
+/// assert_eq!(buf, "Name");
+/// // IDispatch::GetIDsOfNames(..., &buf.as_bstr(), ...)
+///
+/// assert!(BStrBuf::<3>::from_str("Name").is_none()); // too long
+/// ```
+///
+/// [BSTR]:     https://docs.microsoft.com/en-us/previous-versions/windows/desktop/automat/bstr
+#[derive(Clone, Copy)] #[repr(C)] pub struct BStrBuf<const N: usize> {
+    bytes:      u32,        // length prefix, in bytes, excluding the terminal `0u16`
+    units:      [u16; N],   // `units[bytes/2..]` are all `0u16`
+    terminator: u16,        // always `0u16`, for when the string fills `units`
+}
+
+impl<const N: usize> Deref for BStrBuf<N> {
+    type Target = BStr;
+    fn deref(&self) -> &BStr {
+        // Safe: `units` immediately follows the 4-byte length prefix (`#[repr(C)]`, no padding), and `units[len]` (or `terminator`) is `0u16`.
+        // Derived from `self` rather than `self.units` so the prefix and terminator stay within the pointer's provenance.
+        unsafe { &*(self as *const Self).cast::<u8>().add(4).cast::<BStr>() }
+    }
+}
+
+impl<const N: usize> BStrBuf<N> {
+    /// Create an empty [BStrBuf].
+    pub const fn new() -> Self { Self { bytes: 0, units: [0; N], terminator: 0 } }
+
+    /// Create a [BStrBuf] from 0 or more [u16] unicode code points.  Returns [None] if there are more than `N` of them.
+    pub fn from_code_units(code_units: impl Iterator<Item = u16>) -> Option<Self> {
+        bounds_check_len32(N)?;
+        let mut buf = Self::new();
+        let mut len = 0;
+        for unit in code_units {
+            *buf.units.get_mut(len)? = unit;
+            len += 1;
+        }
+        buf.bytes = 2 * len as u32; // won't overflow: len <= N < u32::MAX/2
+        Some(buf)
+    }
+}
+
+
+
+/// `SmallBString<N>` is a [BSTR] that's stored inline in a [BStrBuf] if it fits, or heap allocated as a [BString] if it doesn't.
+///
+/// Like [BStrBuf], the inline case is suitable **only** for `[in] BSTR` parameters that don't escape the call.
+/// See [IntoSmallBStr] for accepting such parameters.
+///
+/// [BSTR]:     https://docs.microsoft.com/en-us/previous-versions/windows/desktop/automat/bstr
+#[derive(Clone)] pub enum SmallBString<const N: usize = 64> {
+    /// A short string, stored inline
+    Inline(BStrBuf<N>),
+
+    /// A long string, stored on the heap
+    Heap(BString),
+}
+
+impl<const N: usize> Deref for SmallBString<N> {
+    type Target = BStr;
+    fn deref(&self) -> &BStr {
+        match self {
+            SmallBString::Inline(s) => s,
+            SmallBString::Heap(s)   => s,
+        }
+    }
+}
+
+
+
 /// `&BStr` is a non-null, borrowed, [BSTR] (32-bit length prefixed [UTF-16]ish string).  Unlike &[OsStr](std::ffi::OsStr) or &[str], this is **not** a [DST]!
 ///
 /// [BSTR]:     https://docs.microsoft.com/en-us/previous-versions/windows/desktop/automat/bstr
@@ -171,6 +440,12 @@ impl BStr {
     /// [code unit]:    https://unicode.org/glossary/#code_unit
     #[cfg(not(target_pointer_width = "16"))]
     pub fn units0(&self) -> &[u16] { unsafe { std::slice::from_raw_parts(self.as_bstr(), self.len0()) } }
+
+    /// The raw bytes of the string, excluding the terminal `0u16`, per `SysStringByteLen`.
+    ///
+    /// This is usually just [units](#method.units) as bytes, but `BSTR`s allocated with `SysAllocStringByteLen` may
+    /// hold an odd number of bytes - often ANSI or DBCS text that should be decoded with a `CodePage` instead.
+    pub fn bytes(&self) -> &[u8] { unsafe { std::slice::from_raw_parts(self.as_bstr().cast(), SysStringByteLen(self.as_bstr()) as usize) } }
 }
 
 
@@ -223,6 +498,13 @@ unsafe impl<B: AsRef<BStr>> AsOptBStrPtr for B {
     use std::mem::align_of;
     use std::mem::size_of;
 
+    assert_eq!(align_of::< BStringMut>(),       align_of::<BSTR>());
+    assert_eq!(size_of::< BStringMut>(),        size_of::<BSTR>());
+    assert_eq!(size_of::<Option<BStringMut>>(), size_of::<BSTR>());
+    assert_eq!(align_of::< SecureBString>(),    align_of::<BSTR>());
+    assert_eq!(size_of::< SecureBString>(),     size_of::<BSTR>());
+    assert_eq!(size_of::<Option<SecureBString>>(), size_of::<BSTR>());
+
     assert_eq!(align_of::<&BStr>(),             align_of::<BSTR>());
     assert_eq!(align_of::<&BString>(),          align_of::<BSTR>());
     assert_eq!(align_of::< BString>(),          align_of::<BSTR>());
@@ -286,3 +568,144 @@ unsafe impl<B: AsRef<BStr>> AsOptBStrPtr for B {
     assert!(hello_world.encode_utf16().chain(Some(0)).eq(e.units0().iter().copied()));
     assert!(hello_world.encode_utf16().chain(Some(0)).eq(f.units0().iter().copied()));
 }
+
+#[test] fn shared() {
+    fn send_sync<T: Send + Sync>() {}
+    send_sync::<SharedBString>();
+
+    let a = SharedBString::from(BString::from_str("Hello, world!").unwrap());
+    let b = a.clone();
+    assert!(SharedBString::ptr_eq(&a, &b));
+    assert_eq!(a.as_bstr(), b.as_bstr()); // no copy
+    assert_eq!(a, "Hello, world!");
+    assert_eq!(a, b);
+
+    let c = std::thread::spawn(move || b.len()).join().unwrap();
+    assert_eq!(c, 13);
+
+    let a = a.try_unwrap().unwrap();
+    assert_eq!(a, "Hello, world!");
+}
+
+#[test] fn interner() {
+    fn send_sync<T: Send + Sync>() {}
+    send_sync::<BStrInterner>();
+
+    let interner = BStrInterner::new();
+    let a = interner.intern_str("Name");
+    let b = interner.intern_units(&"Name".encode_utf16().collect::<Vec<_>>());
+    let c = interner.intern_str("name");
+    assert_eq!(a.as_bstr(), b.as_bstr());
+    assert_ne!(a.as_bstr(), c.as_bstr());
+    assert_eq!(interner.len(), 2);
+    for i in 0..1000 { interner.intern_str(&i.to_string()); } // force rehashing
+    assert_eq!(a.as_bstr(), interner.intern_str("Name").as_bstr());
+    assert_eq!(a, "Name");
+
+    let interner = BStrInterner::new_case_insensitive();
+    assert!(interner.get_str("Name").is_none());
+    let a = interner.intern_str("Name");
+    let b = interner.intern_str("NAME");
+    let c = interner.get_str("nAmE").unwrap();
+    assert_eq!(a.as_bstr(), b.as_bstr());
+    assert_eq!(a.as_bstr(), c.as_bstr());
+    assert_eq!(b, "Name"); // first spelling wins
+    assert_eq!(interner.intern_str("Straße"), "Straße");
+    assert_eq!(interner.intern_str("STRASSE"), "STRASSE"); // simple case mappings only
+    assert_eq!(interner.len(), 3);
+
+    let interner = std::sync::Arc::new(interner);
+    let threads = (0..4).map(|_| {
+        let interner = interner.clone();
+        std::thread::spawn(move || (0..100).map(|i| interner.intern_str(&format!("Item{}", i)).as_bstr() as usize).collect::<Vec<_>>())
+    }).collect::<Vec<_>>();
+    let results = threads.into_iter().map(|t| t.join().unwrap()).collect::<Vec<_>>();
+    assert!(results.iter().all(|r| *r == results[0]));
+    assert_eq!(interner.len(), 103);
+}
+
+#[test] fn secure() {
+    let mut a = SecureBString::from_str("hunter2").unwrap();
+    assert_eq!(format!("{:?}", a), "SecureBString(<redacted>)");
+    #[cfg(feature = "display")] assert_eq!(format!("{}", a), "<redacted>");
+    assert!("hunter2".encode_utf16().eq(a.units().iter().copied()));
+
+    let b = a.clone();
+    assert_ne!(a.as_bstr(), b.as_bstr());
+    assert!("hunter2".encode_utf16().eq(b.units().iter().copied()));
+
+    a.zero();
+    assert_eq!(a.len(), 7);
+    assert!(a.units0().iter().all(|&u| u == 0));
+    assert!("hunter2".encode_utf16().eq(b.units().iter().copied()));
+
+    let mut s = String::from("correct horse battery staple");
+    let c = SecureBString::from_str_zeroing(&mut s).unwrap();
+    assert!("correct horse battery staple".encode_utf16().eq(c.units().iter().copied()));
+    assert_eq!(s.len(), 28);
+    assert!(s.bytes().all(|b| b == 0));
+}
+
+#[test] fn thread_safety() {
+    fn send_sync<T: Send + Sync>() {}
+
+    // `SysFreeString` may be called from any thread
+    send_sync::<BString>();
+    send_sync::<BStringMut>();
+    send_sync::<SecureBString>();
+    send_sync::<SharedBString>();
+
+    // `BString`s are immutable, so sharing them only results in concurrent reads...
+    send_sync::<&BStr>();
+    send_sync::<Arc<BString>>();
+    let shared = Arc::new(BString::from_str("shared").unwrap());
+    let threads = (0..4).map(|_| { let s = shared.clone(); std::thread::spawn(move || s.units().to_vec()) }).collect::<Vec<_>>();
+    for t in threads { assert_eq!(t.join().unwrap(), shared.units()); }
+
+    // ...and mutation requires exclusive ownership
+    let bstr = shared.as_bstr();
+    let mut m = BStringMut::from(Arc::try_unwrap(shared).unwrap());
+    m.units_mut()[0] = b'S' as u16;
+    let s = BString::from(m);
+    assert_eq!(s.as_bstr(), bstr); // no reallocation
+    assert_eq!(s, "Shared");
+}
+
+#[test] fn stack_bstrs() {
+    use std::mem::{align_of, size_of};
+    assert_eq!(size_of::<BStrBuf<0>>(), 8);
+    assert_eq!(size_of::<BStrBuf<3>>(), 12);
+    assert_eq!(align_of::<BStrBuf<3>>(), 4);
+
+    let a = BStrBuf::<3>::from_code_units([b'a' as u16, 0, b'c' as u16].iter().copied()).unwrap();
+    let b = a; // the copy derefs to its own data
+    assert_eq!(b.len32(), 3);
+    assert_eq!(b.units0(), [b'a' as u16, 0, b'c' as u16, 0]);
+    assert_eq!(b.as_bstr() as usize, &b as *const _ as usize + 4);
+    assert_eq!(unsafe { SysStringByteLen(b.as_bstr()) }, 6);
+    assert!(BStrBuf::<3>::from_code_units([1, 2, 3, 4].iter().copied()).is_none());
+    assert_eq!(BStrBuf::<3>::from_code_units([1].iter().copied()).unwrap().units0(), [1, 0]);
+    assert_eq!(BStrBuf::<0>::new().units0(), [0]);
+}
+
+#[test] fn byte_bstrs() {
+    let raw = unsafe { SysAllocStringByteLen(b"\x82\xa0!".as_ptr().cast(), 3) };
+    let s = unsafe { BStr::from_bstr(&raw) }.unwrap();
+    assert_eq!(s.bytes(), b"\x82\xa0!");
+    assert_eq!(s.len32(), 1);
+    unsafe { SysFreeString(raw) };
+
+    assert_eq!(bstr!("ab").bytes(), b"a\0b\0");
+    assert_eq!(bstr!("").bytes(), b"");
+}
+
+#[test] fn mutable() {
+    let mut a = BStringMut::new(3).unwrap();
+    assert_eq!(a.units0(), &[0, 0, 0, 0]);
+    a.units_mut().copy_from_slice(&[b'a' as u16, b'b' as u16, b'c' as u16]);
+    assert_eq!(a.units0(), &[b'a' as u16, b'b' as u16, b'c' as u16, 0]);
+    assert_eq!(a, "abc");
+    let a = BString::from(a);
+    assert_eq!(a, "abc");
+    assert_eq!(BStringMut::new(0).unwrap().units_mut(), &[]);
+}
@@ -0,0 +1,88 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+
+use std::ffi::OsStr;
+use std::hash::{BuildHasher, Hasher};
+use std::os::windows::ffi::OsStrExt;
+
+
+
+impl Default for BStrInterner {
+    fn default() -> Self { Self::new() }
+}
+
+impl BStrInterner {
+    /// Create a case sensitive interner:  `"Name"` and `"NAME"` will be interned as separate strings.
+    pub fn new() -> Self { Self::with_case_insensitivity(false) }
+
+    /// Create a case insensitive interner:  `"Name"` and `"NAME"` will be interned as the same string - whichever spelling was interned first.
+    ///
+    /// Only simple (1:1) Unicode uppercase mappings are applied, without regard to locale - `"ß"` and `"SS"` are considered distinct.
+    /// Unpaired surrogates are compared as-is.
+    pub fn new_case_insensitive() -> Self { Self::with_case_insensitivity(true) }
+
+    /// Returns `true` if this interner was created with [new_case_insensitive](Self::new_case_insensitive).
+    pub fn is_case_insensitive(&self) -> bool { self.case_insensitive }
+
+    /// Returns `true` if nothing has been interned yet.
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Intern a [str], returning the previously interned &amp;[BStr] if there is one.
+    pub fn intern_str(&self, s: &str) -> &BStr { self.intern_impl(s.encode_utf16()) }
+
+    /// Intern an [OsStr], returning the previously interned &amp;[BStr] if there is one.
+    pub fn intern_osstr(&self, s: &OsStr) -> &BStr { self.intern_impl(s.encode_wide()) }
+
+    /// Intern [u16] unicode [code unit]s, returning the previously interned &amp;[BStr] if there is one.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn intern_units(&self, units: &[u16]) -> &BStr { self.intern_impl(units.iter().copied()) }
+
+    /// Intern a &amp;[BStr], returning the previously interned &amp;[BStr] if there is one.
+    pub fn intern_bstr(&self, s: &BStr) -> &BStr { self.intern_units(s.units()) }
+
+    /// Get a previously interned [str] without interning it.
+    pub fn get_str(&self, s: &str) -> Option<&BStr> { self.get_impl(s.encode_utf16()) }
+
+    /// Get a previously interned [OsStr] without interning it.
+    pub fn get_osstr(&self, s: &OsStr) -> Option<&BStr> { self.get_impl(s.encode_wide()) }
+
+    /// Get previously interned [u16] unicode [code unit]s without interning them.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn get_units(&self, units: &[u16]) -> Option<&BStr> { self.get_impl(units.iter().copied()) }
+
+    fn get_impl(&self, units: impl Iterator<Item = u16> + Clone) -> Option<&BStr> {
+        let hash = self.hash(units.clone());
+        self.find(hash, |b| self.eq(b.units().iter().copied(), units.clone()))
+    }
+
+    fn intern_impl(&self, units: impl Iterator<Item = u16> + Clone) -> &BStr {
+        let hash = self.hash(units.clone());
+        self.find_or_insert(
+            hash,
+            |b| self.eq(b.units().iter().copied(), units.clone()),
+            || BString::from_code_units(units.clone().collect::<Vec<_>>().into_iter()).expect("unable to allocate interned BString"),
+        )
+    }
+
+    fn hash(&self, units: impl Iterator<Item = u16>) -> u64 {
+        let mut hasher = self.hasher.build_hasher();
+        for ch in fold(self.case_insensitive, units) { hasher.write_u32(ch); }
+        hasher.finish()
+    }
+
+    fn eq(&self, a: impl Iterator<Item = u16>, b: impl Iterator<Item = u16>) -> bool {
+        fold(self.case_insensitive, a).eq(fold(self.case_insensitive, b))
+    }
+}
+
+/// Decode `units` into [char]s (or unpaired surrogates) as [u32]s, uppercasing them if `case_insensitive`.
+fn fold(case_insensitive: bool, units: impl Iterator<Item = u16>) -> impl Iterator<Item = u32> {
+    std::char::decode_utf16(units).map(move |ch| match ch {
+        Err(unpaired) => u32::from(unpaired.unpaired_surrogate()),
+        Ok(ch) if !case_insensitive => u32::from(ch),
+        Ok(ch) => u32::from(simple_uppercase(ch)),
+    })
+}
@@ -33,6 +33,73 @@ impl Eq                     for BString {}
 impl Ord                    for BString { fn cmp(&self, other: &BString) -> Ordering { self.units().cmp(other.units()) } }
 impl Hash                   for BString { fn hash<H: Hasher>(&self, state: &mut H) { self.units().hash(state) } }
 
+#[cfg(feature = "display")]
+impl Display                for BStringMut { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&**self, fmt) } }
+impl Debug                  for BStringMut { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(&**self, fmt) } }
+impl AsRef<BStr>            for BStringMut { fn as_ref(&self) -> &BStr { self } }
+impl AsRef<[u16]>           for BStringMut { fn as_ref(&self) -> &[u16] { self.units() } }
+impl AsMut<[u16]>           for BStringMut { fn as_mut(&mut self) -> &mut [u16] { self.units_mut() } }
+impl Borrow<BStr>           for BStringMut { fn borrow(&self) -> &BStr { self } }
+impl Borrow<[u16]>          for BStringMut { fn borrow(&self) -> &[u16] { self.units() } }
+impl Clone                  for BStringMut { fn clone(&self) -> Self { Self::from(BString::from_bstr(self).unwrap()) } }
+impl From<&BStr>            for BStringMut { fn from(value: &BStr      ) -> Self { Self::from(BString::from(value)) } }
+impl From<&str>             for BStringMut { fn from(value: &str       ) -> Self { Self::from(BString::from(value)) } }
+impl From<&String>          for BStringMut { fn from(value: &String    ) -> Self { Self::from(BString::from(value)) } }
+impl From< String>          for BStringMut { fn from(value:  String    ) -> Self { Self::from(BString::from(value)) } }
+impl From<&OsStr>           for BStringMut { fn from(value: &OsStr     ) -> Self { Self::from(BString::from(value)) } }
+impl From<&OsString>        for BStringMut { fn from(value: &OsString  ) -> Self { Self::from(BString::from(value)) } }
+impl From< OsString>        for BStringMut { fn from(value:  OsString  ) -> Self { Self::from(BString::from(value)) } }
+impl Eq                     for BStringMut {}
+impl Ord                    for BStringMut { fn cmp(&self, other: &BStringMut) -> Ordering { self.units().cmp(other.units()) } }
+impl Hash                   for BStringMut { fn hash<H: Hasher>(&self, state: &mut H) { self.units().hash(state) } }
+
+#[cfg(feature = "display")]
+impl Display                for SecureBString { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { fmt.write_str("<redacted>") } }
+impl Debug                  for SecureBString { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { fmt.write_str("SecureBString(<redacted>)") } }
+impl AsRef<BStr>            for SecureBString { fn as_ref(&self) -> &BStr { self } }
+impl Clone                  for SecureBString { fn clone(&self) -> Self { Self::from_bstr(self).unwrap() } }
+
+#[cfg(feature = "display")]
+impl Display                for SharedBString { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&**self, fmt) } }
+impl Debug                  for SharedBString { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(&**self, fmt) } }
+impl AsRef<BStr>            for SharedBString { fn as_ref(&self) -> &BStr { self } }
+impl AsRef<[u16]>           for SharedBString { fn as_ref(&self) -> &[u16] { self.units() } }
+impl Borrow<BStr>           for SharedBString { fn borrow(&self) -> &BStr { self } }
+impl Borrow<[u16]>          for SharedBString { fn borrow(&self) -> &[u16] { self.units() } }
+impl From<&BStr>            for SharedBString { fn from(value: &BStr      ) -> Self { Self::from(BString::from(value)) } }
+impl From<&str>             for SharedBString { fn from(value: &str       ) -> Self { Self::from(BString::from(value)) } }
+impl From<&String>          for SharedBString { fn from(value: &String    ) -> Self { Self::from(BString::from(value)) } }
+impl From< String>          for SharedBString { fn from(value:  String    ) -> Self { Self::from(BString::from(value)) } }
+impl From<&OsStr>           for SharedBString { fn from(value: &OsStr     ) -> Self { Self::from(BString::from(value)) } }
+impl From<&OsString>        for SharedBString { fn from(value: &OsString  ) -> Self { Self::from(BString::from(value)) } }
+impl From< OsString>        for SharedBString { fn from(value:  OsString  ) -> Self { Self::from(BString::from(value)) } }
+impl Eq                     for SharedBString {}
+impl Ord                    for SharedBString { fn cmp(&self, other: &SharedBString) -> Ordering { self.units().cmp(other.units()) } }
+impl Hash                   for SharedBString { fn hash<H: Hasher>(&self, state: &mut H) { self.units().hash(state) } }
+
+#[cfg(feature = "display")]
+impl<const N: usize> Display            for BStrBuf<N>  { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&**self, fmt) } }
+impl<const N: usize> Debug              for BStrBuf<N>  { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(&**self, fmt) } }
+impl<const N: usize> Default            for BStrBuf<N>  { fn default() -> Self { Self::new() } }
+impl<const N: usize> AsRef<BStr>        for BStrBuf<N>  { fn as_ref(&self) -> &BStr { self } }
+impl<const N: usize> AsRef<[u16]>       for BStrBuf<N>  { fn as_ref(&self) -> &[u16] { self.units() } }
+impl<const N: usize> Borrow<BStr>       for BStrBuf<N>  { fn borrow(&self) -> &BStr { self } }
+impl<const N: usize> Borrow<[u16]>      for BStrBuf<N>  { fn borrow(&self) -> &[u16] { self.units() } }
+impl<const N: usize> Eq                 for BStrBuf<N>  {}
+impl<const N: usize> Ord                for BStrBuf<N>  { fn cmp(&self, other: &BStrBuf<N>) -> Ordering { self.units().cmp(other.units()) } }
+impl<const N: usize> Hash               for BStrBuf<N>  { fn hash<H: Hasher>(&self, state: &mut H) { self.units().hash(state) } }
+
+#[cfg(feature = "display")]
+impl<const N: usize> Display            for SmallBString<N> { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&**self, fmt) } }
+impl<const N: usize> Debug              for SmallBString<N> { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(&**self, fmt) } }
+impl<const N: usize> AsRef<BStr>        for SmallBString<N> { fn as_ref(&self) -> &BStr { self } }
+impl<const N: usize> AsRef<[u16]>       for SmallBString<N> { fn as_ref(&self) -> &[u16] { self.units() } }
+impl<const N: usize> Borrow<BStr>       for SmallBString<N> { fn borrow(&self) -> &BStr { self } }
+impl<const N: usize> Borrow<[u16]>      for SmallBString<N> { fn borrow(&self) -> &[u16] { self.units() } }
+impl<const N: usize> Eq                 for SmallBString<N> {}
+impl<const N: usize> Ord                for SmallBString<N> { fn cmp(&self, other: &SmallBString<N>) -> Ordering { self.units().cmp(other.units()) } }
+impl<const N: usize> Hash               for SmallBString<N> { fn hash<H: Hasher>(&self, state: &mut H) { self.units().hash(state) } }
+
 #[cfg(feature = "display")]
 impl Display                for BStr    { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&PathBuf::from(OsString::from_wide(self.units())).display(), fmt) } }
 impl Debug                  for BStr    { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(&OsString::from_wide(self.units()), fmt) } }
@@ -43,43 +110,6 @@ impl Eq                     for &BStr   {}
 impl Ord                    for &BStr   { fn cmp(&self, other: &&BStr) -> Ordering { self.units().cmp(other.units()) } }
 impl Hash                   for &BStr   { fn hash<H: Hasher>(&self, state: &mut H) { self.units().hash(state) } }
 
-// Okay, this is a *lot* of traits.  I'm just mimicing the stdlib here though.
-//
-// Sliceable DST rules, using `str` as an example
-// 1.   Implement `&str == &str` (used for `"foo" == "bar"`)
-// 2.   Implement `str  ==  str` (used for `"foo"[..] == "bar"[..]`)
-// 3.   Skip `&str == str` / `str == &str`
-// 4.   Implement `&str == ...`
-// 5.   Implement `str  == ...`
-// 6.   Implement `... == &str`
-// 7.   Implement `... ==  str`
-// 8.   All the above for ordering comparisons too
-//
-// `&BStr` is slightly simpler than `&str` - it is not sliceable and cannot be directly used as a value
-
-macro_rules! peo {
-    ( &? $left:ty, $($tt:tt)* ) => {
-        peo!(& $left, $($tt)*);
-        peo!(  $left, $($tt)*);
-    };
-    ( $left:ty, &? $($tt:tt)* ) => {
-        peo!($left, & $($tt)*);
-        peo!($left,   $($tt)*);
-    };
-    ( $left:ty, $right:ty ) => {
-        impl PartialEq<$left> for $right {
-            fn eq(&self, other: &$left) -> bool {
-                self.utf16ish().eq(other.utf16ish())
-            }
-        }
-        impl PartialOrd<$left> for $right {
-            fn partial_cmp(&self, other: &$left) -> Option<Ordering> {
-                self.utf16ish().partial_cmp(other.utf16ish())
-            }
-        }
-    };
-}
-
 peo!(&BStr,   &BStr  );
 peo!(BString, BString);
 
@@ -109,6 +139,42 @@ peo!(Cow<'_, str>,      BString); peo!(BString, Cow<'_, str>    );
 peo!(Cow<'_, OsStr>,    BString); peo!(BString, Cow<'_, OsStr>  );
 peo!(Cow<'_, Path>,     BString); peo!(BString, Cow<'_, Path>   );
 
+peo!(BStringMut,        BStringMut);
+peo!(BStringMut,        &BStr  ); peo!(&BStr,   BStringMut      );
+peo!(BStringMut,        BString); peo!(BString, BStringMut      );
+peo!(&?str,          BStringMut); peo!(BStringMut, &?str        );
+peo!(&?[u16],        BStringMut); peo!(BStringMut, &?[u16]      );
+peo!(String,         BStringMut); peo!(BStringMut, String       );
+peo!(&?OsStr,        BStringMut); peo!(BStringMut, &?OsStr      );
+peo!(OsString,       BStringMut); peo!(BStringMut, OsString     );
+
+peo!(SharedBString,     SharedBString);
+peo!(SharedBString,     &BStr  ); peo!(&BStr,   SharedBString   );
+peo!(SharedBString,     BString); peo!(BString, SharedBString   );
+peo!(&?str,       SharedBString); peo!(SharedBString, &?str     );
+peo!(&?[u16],     SharedBString); peo!(SharedBString, &?[u16]   );
+peo!(String,      SharedBString); peo!(SharedBString, String    );
+peo!(&?OsStr,     SharedBString); peo!(SharedBString, &?OsStr   );
+peo!(OsString,    SharedBString); peo!(SharedBString, OsString  );
+
+peo!(impl[const N: usize] BStrBuf<N>,   BStrBuf<N>);
+peo!(impl[const N: usize] BStrBuf<N>,   &BStr   ); peo!(impl[const N: usize] &BStr,   BStrBuf<N>    );
+peo!(impl[const N: usize] BStrBuf<N>,   BString ); peo!(impl[const N: usize] BString, BStrBuf<N>    );
+peo!(impl[const N: usize] &?str,        BStrBuf<N>); peo!(impl[const N: usize] BStrBuf<N>, &?str    );
+peo!(impl[const N: usize] &?[u16],      BStrBuf<N>); peo!(impl[const N: usize] BStrBuf<N>, &?[u16]  );
+peo!(impl[const N: usize] String,       BStrBuf<N>); peo!(impl[const N: usize] BStrBuf<N>, String   );
+peo!(impl[const N: usize] &?OsStr,      BStrBuf<N>); peo!(impl[const N: usize] BStrBuf<N>, &?OsStr  );
+peo!(impl[const N: usize] OsString,     BStrBuf<N>); peo!(impl[const N: usize] BStrBuf<N>, OsString );
+
+peo!(impl[const N: usize] SmallBString<N>, SmallBString<N>);
+peo!(impl[const N: usize] SmallBString<N>, &BStr   ); peo!(impl[const N: usize] &BStr,   SmallBString<N>    );
+peo!(impl[const N: usize] SmallBString<N>, BString ); peo!(impl[const N: usize] BString, SmallBString<N>    );
+peo!(impl[const N: usize] &?str,        SmallBString<N>); peo!(impl[const N: usize] SmallBString<N>, &?str    );
+peo!(impl[const N: usize] &?[u16],      SmallBString<N>); peo!(impl[const N: usize] SmallBString<N>, &?[u16]  );
+peo!(impl[const N: usize] String,       SmallBString<N>); peo!(impl[const N: usize] SmallBString<N>, String   );
+peo!(impl[const N: usize] &?OsStr,      SmallBString<N>); peo!(impl[const N: usize] SmallBString<N>, &?OsStr  );
+peo!(impl[const N: usize] OsString,     SmallBString<N>); peo!(impl[const N: usize] SmallBString<N>, OsString );
+
 
 
 impl<'s> UTF16ish<'s> for BStr {
@@ -122,19 +188,113 @@ impl<'s> UTF16ish<'s> for BString {
 }
 
 
+impl<'s> UTF16ish<'s> for BStringMut {
+    type Iter = std::iter::Copied<std::slice::Iter<'s, u16>>;
+    fn utf16ish(&'s self) -> Self::Iter { self.units().iter().copied() }
+}
+
+impl<'s> UTF16ish<'s> for SharedBString {
+    type Iter = std::iter::Copied<std::slice::Iter<'s, u16>>;
+    fn utf16ish(&'s self) -> Self::Iter { self.units().iter().copied() }
+}
+
+impl<'s, const N: usize> UTF16ish<'s> for BStrBuf<N> {
+    type Iter = std::iter::Copied<std::slice::Iter<'s, u16>>;
+    fn utf16ish(&'s self) -> Self::Iter { self.units().iter().copied() }
+}
+
+impl<'s, const N: usize> UTF16ish<'s> for SmallBString<N> {
+    type Iter = std::iter::Copied<std::slice::Iter<'s, u16>>;
+    fn utf16ish(&'s self) -> Self::Iter { self.units().iter().copied() }
+}
+
+impl Utf16Hash for BStr          { fn utf16_units(&self) -> Utf16Units<'_> { self.units().into() } }
+impl Utf16Hash for BString       { fn utf16_units(&self) -> Utf16Units<'_> { self.units().into() } }
+impl Utf16Hash for BStringMut    { fn utf16_units(&self) -> Utf16Units<'_> { self.units().into() } }
+impl Utf16Hash for SharedBString { fn utf16_units(&self) -> Utf16Units<'_> { self.units().into() } }
+impl<const N: usize> Utf16Hash for BStrBuf<N>       { fn utf16_units(&self) -> Utf16Units<'_> { self.units().into() } }
+impl<const N: usize> Utf16Hash for SmallBString<N>  { fn utf16_units(&self) -> Utf16Units<'_> { self.units().into() } }
+
 
 impl BString {
     /// Create a [BString] from a [str]
-    pub fn from_str(s: impl AsRef<str>) -> Option<Self> { Self::from_code_units(ESI::new(s.as_ref().encode_utf16())) }
+    pub fn from_str(s: impl AsRef<str>) -> Option<Self> { Self::from_code_units(ExactSize::new(s.as_ref().encode_utf16())) }
 
     /// Create a [BString] from a [OsStr]
-    pub fn from_osstr(s: impl AsRef<OsStr>) -> Option<Self> { Self::from_code_units(ESI::new(s.as_ref().encode_wide())) }
+    pub fn from_osstr(s: impl AsRef<OsStr>) -> Option<Self> { Self::from_code_units(ExactSize::new(s.as_ref().encode_wide())) }
 
     /// Create a [BString] from a [BStr]
     pub fn from_bstr(s: impl AsRef<BStr>) -> Option<Self> { Self::from_code_units(s.as_ref().units().iter().copied()) }
 }
 
 
+impl SecureBString {
+    /// Create a [SecureBString] from 0 or more [u16] unicode code points.
+    pub fn from_code_units(code_units: impl ExactSizeIterator<Item = u16>) -> Option<Self> { BString::from_code_units(code_units).map(Self::from) }
+
+    /// Create a [SecureBString] from a [str], encoding directly into the `BSTR`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: impl AsRef<str>) -> Option<Self> { BString::from_str(s).map(Self::from) }
+
+    /// Create a [SecureBString] from a [str], then overwrite `s` with `'\0'`s.
+    ///
+    /// Note that this can't zero any *other* copies of `s` - such as those left behind when a `String` reallocates.
+    pub fn from_str_zeroing(s: &mut str) -> Option<Self> {
+        let r = Self::from_str(&*s);
+        super::danger::zero_str(s);
+        r
+    }
+
+    /// Create a [SecureBString] from a [OsStr], encoding directly into the `BSTR`.
+    pub fn from_osstr(s: impl AsRef<OsStr>) -> Option<Self> { BString::from_osstr(s).map(Self::from) }
+
+    /// Create a [SecureBString] from a [BStr]
+    pub fn from_bstr(s: impl AsRef<BStr>) -> Option<Self> { BString::from_bstr(s).map(Self::from) }
+}
+
+
+impl<const N: usize> BStrBuf<N> {
+    /// Create a [BStrBuf] from a [str].  Returns [None] if it's longer than `N` [u16]s.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: impl AsRef<str>) -> Option<Self> { Self::from_code_units(s.as_ref().encode_utf16()) }
+
+    /// Create a [BStrBuf] from a [OsStr].  Returns [None] if it's longer than `N` [u16]s.
+    pub fn from_osstr(s: impl AsRef<OsStr>) -> Option<Self> { Self::from_code_units(s.as_ref().encode_wide()) }
+
+    /// Create a [BStrBuf] from a [BStr].  Returns [None] if it's longer than `N` [u16]s.
+    pub fn from_bstr(s: impl AsRef<BStr>) -> Option<Self> { Self::from_code_units(s.as_ref().units().iter().copied()) }
+
+    /// The maximum length of the string, in [u16] unicode [code unit]s, excluding the terminal `0u16`.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn capacity(&self) -> usize { N }
+}
+
+
+impl<const N: usize> SmallBString<N> {
+    /// Create a [SmallBString] from a [str], only allocating if it's longer than `N` [u16]s.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: impl AsRef<str>) -> Option<Self> {
+        let s = s.as_ref();
+        match BStrBuf::from_str(s) {
+            Some(buf)   => Some(SmallBString::Inline(buf)),
+            None        => BString::from_str(s).map(SmallBString::Heap),
+        }
+    }
+
+    /// Create a [SmallBString] from a [OsStr], only allocating if it's longer than `N` [u16]s.
+    pub fn from_osstr(s: impl AsRef<OsStr>) -> Option<Self> {
+        let s = s.as_ref();
+        match BStrBuf::from_osstr(s) {
+            Some(buf)   => Some(SmallBString::Inline(buf)),
+            None        => BString::from_osstr(s).map(SmallBString::Heap),
+        }
+    }
+
+    /// Returns `true` if the string is stored inline, instead of on the heap.
+    pub fn is_inline(&self) -> bool { matches!(self, SmallBString::Inline(_)) }
+}
+
 
 impl BStr {
     /// LPCWSTR / `* const wchar_t`
@@ -166,26 +326,21 @@ impl BStr {
 
 
 
-/// "Exact Size Iterator" adapter
-struct ESI<I: Iterator> {
-    len:    usize,
-    iter:   I,
-}
-
-impl<I: Iterator + Clone> ESI<I> {
-    pub fn new(iter: I) -> Self {
-        Self {
-            len: iter.clone().count(),
-            iter,
-        }
-    }
-}
-
-impl<I: Iterator> Iterator for ESI<I> {
-    type Item = I::Item;
-    fn next(&mut self) -> Option<Self::Item> { self.iter.next() }
-}
-
-impl<I: Iterator> ExactSizeIterator for ESI<I> {
-    fn len(&self) -> usize { self.len }
+#[test] fn small() {
+    let a = SmallBString::<4>::from_str("abcd").unwrap();
+    let b = SmallBString::<4>::from_str("abcde").unwrap();
+    assert!(a.is_inline());
+    assert!(!b.is_inline());
+    assert_eq!(a, "abcd");
+    assert_eq!(b, "abcde");
+    assert!(a < b);
+    assert_eq!(a.clone(), a);
+    assert_eq!(format!("{:?}", a), "\"abcd\"");
+
+    let buf = BStrBuf::<8>::from_str("abcd").unwrap();
+    assert_eq!(&*buf, &*a);
+    assert_eq!(buf, BString::from("abcd"));
+    assert_eq!(buf.capacity(), 8);
+    assert_eq!(BStrBuf::<8>::default(), "");
+    assert!(SmallBString::<64>::from_str("x").unwrap().is_inline());
 }
@@ -28,6 +28,43 @@ impl IntoBStr for &Path     { type BStr = BString; fn into(self) -> BString { BS
 
 
 
+/// Like [IntoBStr], except short strings are converted into an inline [SmallBString] instead of heap allocating a [BString].
+///
+/// The resulting `BSTR` may not have been `SysAlloc`ed, so only use this for `[in] BSTR` parameters that don't escape the call.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// fn get_id_of_name(name: impl IntoSmallBStr) -> usize {
+///     let name = IntoSmallBStr::into(name);
+///     name.as_ref().len() // IDispatch::GetIDsOfNames(..., &name.as_bstr_ptr(), 1, ...)
+/// }
+///
+/// assert_eq!(get_id_of_name("Name"), 4);              // no heap allocation
+/// assert_eq!(get_id_of_name("x".repeat(100)), 100);   // falls back on the heap
+/// assert_eq!(get_id_of_name(bstr!("Name")), 4);       // no copy
+/// ```
+pub trait IntoSmallBStr {
+    type BStr : AsBStrPtr;
+    fn into(self) -> Self::BStr;
+}
+
+impl<B: AsRef<BStr>> IntoSmallBStr for B {
+    type BStr = Self;
+    fn into(self) -> Self { self }
+}
+
+impl IntoSmallBStr for  String   { type BStr = SmallBString; fn into(self) -> SmallBString { SmallBString::from_str(&self).unwrap() } }
+impl IntoSmallBStr for &String   { type BStr = SmallBString; fn into(self) -> SmallBString { SmallBString::from_str( self).unwrap() } }
+impl IntoSmallBStr for &str      { type BStr = SmallBString; fn into(self) -> SmallBString { SmallBString::from_str( self).unwrap() } }
+impl IntoSmallBStr for  OsString { type BStr = SmallBString; fn into(self) -> SmallBString { SmallBString::from_osstr(&self).unwrap() } }
+impl IntoSmallBStr for &OsString { type BStr = SmallBString; fn into(self) -> SmallBString { SmallBString::from_osstr( self).unwrap() } }
+impl IntoSmallBStr for &OsStr    { type BStr = SmallBString; fn into(self) -> SmallBString { SmallBString::from_osstr( self).unwrap() } }
+impl IntoSmallBStr for  PathBuf  { type BStr = SmallBString; fn into(self) -> SmallBString { SmallBString::from_osstr(&self).unwrap() } }
+impl IntoSmallBStr for &PathBuf  { type BStr = SmallBString; fn into(self) -> SmallBString { SmallBString::from_osstr( self).unwrap() } }
+impl IntoSmallBStr for &Path     { type BStr = SmallBString; fn into(self) -> SmallBString { SmallBString::from_osstr( self).unwrap() } }
+
+
 /// Similar to `Into<Option<BString>>`, except that this won't require copying
 /// `&BStr` or `&BString` arguments.  Additionally, you can use `()` in lieu of
 /// `None` - whereas trying to pass `None` to a function accepting
@@ -0,0 +1,248 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+
+use std::iter::FusedIterator;
+
+const TAB       : u16 = b'\t' as u16;
+const SPACE     : u16 = b' '  as u16;
+const QUOTE     : u16 = b'"'  as u16;
+const BACKSLASH : u16 = b'\\' as u16;
+
+
+
+/// Split a command line (e.g. from `GetCommandLineW`) into arguments, exactly like `CommandLineToArgvW`.
+///
+/// * The first argument (the program name) is special:  if it starts with a quote, it ends at the next quote.  Otherwise, it ends at
+///   the first space or tab, and quotes are literal.  Backslashes are always literal.
+/// * Other arguments are separated by spaces or tabs outside of quotes.
+/// * `2n` backslashes followed by a quote become `n` backslashes, and the quote toggles quoting.
+/// * `2n+1` backslashes followed by a quote become `n` backslashes and a literal quote.
+/// * Backslashes not followed by a quote are literal.
+/// * Within quotes, `""` becomes a literal quote (the post-2008 MSVCRT behavior.)
+/// * Parsing stops at the first `0u16`, if any.
+///
+/// Unlike `CommandLineToArgvW`, an empty command line results in no arguments, instead of the path of the current executable.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// let cmdline = r#"C:\Program Files\app.exe "a b" c\\"d e" "x""y" z\""#.encode_utf16().collect::<Vec<_>>();
+/// let argv = split_command_line(&cmdline).collect::<Vec<_>>();
+/// assert_eq!(argv, [r#"C:\Program"#, r#"Files\app.exe"#, "a b", r#"c\d e"#, r#"x"y"#, r#"z""#]);
+/// ```
+pub fn split_command_line(cmdline: &[u16]) -> CommandLineArgs<'_> {
+    let end = cmdline.iter().position(|&u| u == 0).unwrap_or(cmdline.len());
+    CommandLineArgs { remaining: &cmdline[..end], first: true }
+}
+
+/// Join arguments into a command line that [split_command_line] (and `CommandLineToArgvW`) will split back into the exact same arguments.
+///
+/// Returns [None] if the first argument (the program name) contains a `'"'`, which cannot be represented.
+/// Panics if any argument contains `'\0'`.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// let cmdline = join_command_line(vec![r#"C:\Program Files\app.exe"#, "", "a b", r#"c\"#, r#"d\" e"#]).unwrap();
+/// assert_eq!(cmdline, r#""C:\Program Files\app.exe" "" "a b" c\ "d\\\" e""#);
+/// ```
+pub fn join_command_line<I>(args: I) -> Option<WString> where I: IntoIterator, I::Item: IntoWStr {
+    let mut cmdline = Vec::new();
+    let mut args = args.into_iter();
+
+    if let Some(arg0) = args.next() {
+        let arg0 = IntoWStr::into(arg0);
+        let arg0 = arg0.as_ref().units();
+        if arg0.contains(&QUOTE) { return None; }
+        let quote = arg0.is_empty() || arg0.iter().any(|&u| u == SPACE || u == TAB);
+        if quote { cmdline.push(QUOTE); }
+        cmdline.extend_from_slice(arg0);
+        if quote { cmdline.push(QUOTE); }
+    }
+
+    for arg in args {
+        let arg = IntoWStr::into(arg);
+        cmdline.push(SPACE);
+        quote_arg(arg.as_ref().units(), &mut cmdline);
+    }
+
+    Some(WString::from_code_units(cmdline.into_iter()).unwrap())
+}
+
+/// Join arguments into a [BString] command line - see [join_command_line].
+#[cfg(windows)] #[cfg(feature = "bstr")]
+pub fn join_command_line_bstr<I>(args: I) -> Option<BString> where I: IntoIterator, I::Item: IntoWStr {
+    join_command_line(args).map(|cmdline| BString::from(&*cmdline))
+}
+
+fn push_backslashes(units: &mut Vec<u16>, n: usize) { units.resize(units.len() + n, BACKSLASH); }
+
+fn quote_arg(arg: &[u16], cmdline: &mut Vec<u16>) {
+    if !arg.is_empty() && !arg.iter().any(|&u| u == SPACE || u == TAB || u == QUOTE) {
+        cmdline.extend_from_slice(arg);
+        return;
+    }
+
+    cmdline.push(QUOTE);
+    let mut backslashes = 0;
+    for &u in arg {
+        match u {
+            BACKSLASH   => backslashes += 1,
+            QUOTE       => { push_backslashes(cmdline, 2 * backslashes + 1); backslashes = 0; },
+            _           => { push_backslashes(cmdline, backslashes); backslashes = 0; },
+        }
+        if u != BACKSLASH { cmdline.push(u); }
+    }
+    push_backslashes(cmdline, 2 * backslashes);
+    cmdline.push(QUOTE);
+}
+
+
+
+/// An [Iterator] over the arguments of a command line - see [split_command_line].
+#[derive(Clone, Debug)] pub struct CommandLineArgs<'s> {
+    remaining:  &'s [u16],
+    first:      bool,
+}
+
+impl CommandLineArgs<'_> {
+    /// Collect the remaining arguments as [BString]s.
+    #[cfg(windows)] #[cfg(feature = "bstr")]
+    pub fn to_bstrings(self) -> Vec<BString> { self.map(|arg| BString::from(&*arg)).collect() }
+
+    fn skip_whitespace(&mut self) {
+        let n = self.remaining.iter().take_while(|&&u| u == SPACE || u == TAB).count();
+        self.remaining = &self.remaining[n..];
+    }
+
+    fn next_arg0(&mut self) -> Vec<u16> {
+        let units = self.remaining;
+        let (arg, rest) = if units.first() == Some(&QUOTE) {
+            let end = units[1..].iter().position(|&u| u == QUOTE).map_or(units.len(), |i| i + 1);
+            (&units[1..end], units.get(end+1..).unwrap_or(&[]))
+        } else {
+            let end = units.iter().position(|&u| u == SPACE || u == TAB).unwrap_or(units.len());
+            units.split_at(end)
+        };
+        self.remaining = rest;
+        arg.to_vec()
+    }
+
+    fn next_arg(&mut self) -> Option<Vec<u16>> {
+        let mut arg = Vec::new();
+        let mut in_quotes = false;
+        let units = self.remaining;
+        let mut i = 0;
+        while let Some(&u) = units.get(i) {
+            i += 1;
+            match u {
+                SPACE | TAB if !in_quotes => {
+                    self.remaining = &units[i..];
+                    return Some(arg);
+                },
+                BACKSLASH => {
+                    let backslashes = 1 + units[i..].iter().take_while(|&&u| u == BACKSLASH).count();
+                    i += backslashes - 1;
+                    if units.get(i) == Some(&QUOTE) {
+                        push_backslashes(&mut arg, backslashes / 2);
+                        if backslashes & 1 == 1 {
+                            arg.push(QUOTE);
+                            i += 1;
+                        }
+                    } else {
+                        push_backslashes(&mut arg, backslashes);
+                    }
+                },
+                QUOTE if in_quotes => match units.get(i) {
+                    Some(&QUOTE)    => { arg.push(QUOTE); i += 1; },
+                    Some(_)         => in_quotes = false,
+                    None            => break,
+                },
+                QUOTE   => in_quotes = true,
+                _       => arg.push(u),
+            }
+        }
+        self.remaining = &units[units.len()..];
+        if arg.is_empty() && !in_quotes { None } else { Some(arg) }
+    }
+}
+
+impl Iterator for CommandLineArgs<'_> {
+    type Item = WString;
+    fn next(&mut self) -> Option<WString> {
+        let arg = if std::mem::take(&mut self.first) {
+            if self.remaining.is_empty() { return None; }
+            Some(self.next_arg0())
+        } else if self.remaining.is_empty() {
+            None
+        } else {
+            self.next_arg()
+        };
+        self.skip_whitespace();
+        arg.map(|arg| WString::from_code_units(arg.into_iter()).unwrap())
+    }
+}
+
+impl FusedIterator for CommandLineArgs<'_> {}
+
+
+
+#[test] fn split() {
+    fn split(cmdline: &str) -> Vec<String> {
+        split_command_line(&cmdline.encode_utf16().collect::<Vec<_>>()).map(|arg| arg.to_string_lossy()).collect()
+    }
+
+    // first argument
+    assert_eq!(split(""),                           Vec::<String>::new());
+    assert_eq!(split(" a b"),                       ["", "a", "b"]);
+    assert_eq!(split(r#""C:\a b\"c d"#),            [r#"C:\a b\"#, "c", "d"]);
+    assert_eq!(split(r#"a"b c"d e"#),               [r#"a"b"#, "cd e"]);
+    assert_eq!(split(r#""a"b c"#),                  ["a", "b", "c"]);
+    assert_eq!(split(r#""unterminated a"#),         ["unterminated a"]);
+    assert_eq!(split(r#"C:\dir\ x"#),               [r#"C:\dir\"#, "x"]);
+    assert_eq!(split("a\0b c"),                     ["a"]);
+
+    // other arguments
+    assert_eq!(split("a  b\t\tc  "),                ["a", "b", "c"]);
+    assert_eq!(split(r#"a "" """#),                 ["a", "", ""]);
+    assert_eq!(split(r#"a \\\\"b c""#),             ["a", r#"\\b c"#]);
+    assert_eq!(split(r#"a \\\"b c"#),               ["a", r#"\"b"#, "c"]);
+    assert_eq!(split(r#"a \\b\ c\\"#),              ["a", r#"\\b\"#, r#"c\\"#]);
+    assert_eq!(split(r#"a "b""c" d"#),              ["a", r#"b"c"#, "d"]);  // post-2008: `""` within quotes is a literal quote
+    assert_eq!(split(r#"a "b"""#),                  ["a", r#"b""#]);
+    assert_eq!(split(r#"a """ b"#),                 ["a", r#"" b"#]);
+    assert_eq!(split(r#"a b"c d"e f"#),             ["a", "bc de", "f"]);
+    assert_eq!(split(r#"a "unterminated b"#),       ["a", "unterminated b"]);
+
+    let surrogates = [b'a' as u16, SPACE, 0xD800, QUOTE, 0xDC00, SPACE, QUOTE];
+    let argv = split_command_line(&surrogates).collect::<Vec<_>>();
+    assert_eq!(argv.len(), 2);
+    assert_eq!(argv[1].units(), [0xD800, 0xDC00, SPACE]);
+}
+
+#[test] fn round_trip() {
+    let args : &[&[&str]] = &[
+        &[],
+        &[""],
+        &["", "", ""],
+        &["app", "plain", "a b", "\ttab", r#"q"uote"#, r#"""#, r#""""#, r#"\"#, r#"\\"#, r#"a\"#, r#"a b\"#, r#"a\\b"#, r#"\"\"#, r#"" \ ""#],
+        &[r#"C:\Program Files\app.exe"#, r#"C:\Program Files\"#, "-x=\"y z\""],
+    ];
+    for &args in args {
+        let cmdline = join_command_line(args.iter().copied()).unwrap();
+        let split = split_command_line(cmdline.units()).map(|arg| arg.to_string_lossy()).collect::<Vec<_>>();
+        assert_eq!(split, args, "cmdline: {}", cmdline.to_string_lossy());
+    }
+
+    assert!(join_command_line(vec![r#"a"b"#]).is_none());
+    assert_eq!(join_command_line(vec!["a", "b"]).unwrap(), "a b");
+    assert_eq!(join_command_line(vec![r#"C:\dir\"#, r#"C:\dir\"#]).unwrap(), r#"C:\dir\ C:\dir\"#);
+    assert_eq!(join_command_line(vec![r#"a b\"#, r#"a b\"#]).unwrap(), r#""a b\" "a b\\""#);
+}
+
+#[cfg(windows)] #[cfg(feature = "bstr")] #[test] fn bstrings() {
+    let cmdline = join_command_line_bstr(vec!["app", "a b"]).unwrap();
+    assert_eq!(cmdline, "app \"a b\"");
+    assert_eq!(split_command_line(cmdline.units()).to_bstrings(), [bstr!("app"), bstr!("a b")]);
+}
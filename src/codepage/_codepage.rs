@@ -0,0 +1,9 @@
+mod bestfit;        pub use bestfit::*;
+mod bestfit_tables;
+mod error;          pub use error::*;
+mod traits;         pub use traits::*;
+mod sbcs;           pub use sbcs::*;
+mod sbcs_tables;
+mod utf7;           pub use utf7::*;
+#[cfg(feature = "codepage-dbcs")] mod dbcs; #[cfg(feature = "codepage-dbcs")] pub use dbcs::*;
+#[cfg(feature = "codepage-dbcs")] mod dbcs_tables;
@@ -0,0 +1,102 @@
+#![forbid(unsafe_code)]
+
+use super::bestfit_tables::TABLES;
+
+
+
+/// Options for [CodePage::encode_with](crate::CodePage::encode_with), mirroring `WideCharToMultiByte`'s flags and parameters.
+///
+/// The [Default] matches `WideCharToMultiByte(code_page, 0, ..., NULL, NULL)`:  best fit enabled, and `'?'` as the default character.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] pub struct EncodeOptions {
+    /// Replace unmappable [u16]s with a similar character when possible (e.g. `'∞'` → `'8'`.)  `false` is `WC_NO_BEST_FIT_CHARS`.
+    ///
+    /// Best fit can turn harmless characters into meaningful ones (e.g. `'／'` → `'/'`, `'＂'` → `'"'`) - disable it
+    /// when the result will be parsed, such as for paths or command lines.
+    pub best_fit: bool,
+
+    /// The [u16] written for unmappable [u16]s without a best fit (`lpDefaultChar`), or [None] for `'?'`.
+    ///
+    /// This is encoded with the code page being used.  If that code page can't encode it exactly, `'?'` is written instead.
+    pub default_char: Option<u16>,
+}
+
+impl Default for EncodeOptions {
+    fn default() -> Self { Self { best_fit: true, default_char: None } }
+}
+
+impl EncodeOptions {
+    /// Options matching `WC_NO_BEST_FIT_CHARS`:  unmappable [u16]s are always replaced with the default character.
+    pub fn no_best_fit() -> Self { Self { best_fit: false, default_char: None } }
+}
+
+/// The result of [CodePage::encode_with](crate::CodePage::encode_with).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)] pub struct Encoded {
+    /// The encoded bytes.
+    pub bytes: Vec<u8>,
+
+    /// Every [u16] that wasn't encoded exactly, in order.
+    pub lossy: Vec<LossyUnit>,
+}
+
+impl Encoded {
+    /// Returns `true` if every [u16] was encoded exactly.
+    pub fn is_lossless(&self) -> bool { self.lossy.is_empty() }
+
+    /// Returns `true` if any [u16] was replaced with the default character, like `WideCharToMultiByte`'s `lpUsedDefaultChar`.
+    ///
+    /// Like `lpUsedDefaultChar`, this ignores best fit replacements - check [Encoded::is_lossless] to catch those as well.
+    pub fn used_default_char(&self) -> bool { self.lossy.iter().any(|l| matches!(l, LossyUnit::DefaultChar { .. })) }
+}
+
+/// A [u16] that [CodePage::encode_with](crate::CodePage::encode_with) couldn't encode exactly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] pub enum LossyUnit {
+    /// The [u16] at `index` was replaced with its best fit, `with`.
+    BestFit { index: usize, with: u16 },
+
+    /// The [u16] at `index` was replaced with the default character.
+    DefaultChar { index: usize },
+}
+
+impl LossyUnit {
+    /// The index of the replaced [u16].
+    pub fn index(&self) -> usize {
+        match *self {
+            LossyUnit::BestFit { index, .. }    => index,
+            LossyUnit::DefaultChar { index }    => index,
+        }
+    }
+}
+
+
+
+/// The best fit for an unmappable `unit` in code page `id`, as a single byte (`0x00..=0xFF`) or a `lead << 8 | trail` pair, if any.
+pub(super) fn best_fit(id: u32, unit: u16) -> Option<u16> {
+    let table = TABLES.iter().find(|(cp, _)| *cp == id)?.1;
+    Some(table[table.binary_search_by_key(&unit, |&(u, _)| u).ok()?].1)
+}
+
+/// Check every best fit `cp` has:  `encode_with` should use it, and `WC_NO_BEST_FIT_CHARS` shouldn't.
+#[cfg(test)] pub(super) fn check_best_fits(cp: &dyn crate::CodePage) {
+    use crate::*;
+    let table = TABLES.iter().find(|(id, _)| *id == cp.id()).unwrap_or_else(|| panic!("code page {} has no best fit table", cp.id())).1;
+    assert!(table.windows(2).all(|w| w[0].0 < w[1].0), "code page {}", cp.id());
+    for &(unit, code) in table {
+        let bytes = &code.to_be_bytes()[usize::from(code <= 0xFF)..];
+        let with = cp.decode(bytes).unwrap();
+        assert_eq!(cp.encode(&[unit]), Err(CodePageError::Unmappable { index: 0 }), "code page {} U+{:04X}", cp.id(), unit);
+        assert_eq!(cp.encode_with(&[unit], &EncodeOptions::default()), Encoded {
+            bytes:  bytes.to_vec(),
+            lossy:  vec![LossyUnit::BestFit { index: 0, with: with[0] }],
+        }, "code page {} U+{:04X}", cp.id(), unit);
+        assert_eq!(cp.encode_with(&[unit], &EncodeOptions::no_best_fit()).bytes, b"?", "code page {} U+{:04X}", cp.id(), unit);
+    }
+}
+
+
+
+#[test] fn tables() {
+    for id in crate::SingleByteCodePage::ids() { check_best_fits(&crate::SingleByteCodePage::new(id).unwrap()); }
+    assert_eq!(best_fit(1252, 0x221E), Some(u16::from(b'8')));
+    assert_eq!(best_fit(1252, 0x65E5), None);
+    assert_eq!(best_fit(65000, 0x221E), None);
+}
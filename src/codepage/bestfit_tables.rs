@@ -0,0 +1,1441 @@
+// Windows best fit tables:  `(unit, code)` for each [u16] a code page can't represent exactly, but `WideCharToMultiByte`
+// replaces with a similar character, sorted by `unit`.  `code` is a single byte (`0x00..=0xFF`) or a `lead << 8 | trail` pair.
+//
+// These mirror the `WCTABLE` sections of Microsoft's `bestfit*.txt` files, minus the mappings that round trip exactly.
+// Regenerate with `cargo run -p examples --example bestfit_tables -- path/to/WindowsBestFit > src/codepage/bestfit_tables.rs`.
+//
+// The entries below predate that generator and haven't been regenerated from Microsoft's files yet:  they map fullwidth
+// ASCII and a shared set of accented letters and lookalike symbols to ASCII wherever the code page lacks them, plus
+// `'¥'` → `0x5C` / `'‾'` → `0x7E` in 932 and `'₩'` → `0x5C` in 949.
+
+pub(super) static TABLES : &[(u32, &[(u16, u16)])] = &[
+    (437,  BESTFIT437 ), (737,  BESTFIT737 ), (775,  BESTFIT775 ), (850,  BESTFIT850 ), (852,  BESTFIT852 ),
+    (855,  BESTFIT855 ), (857,  BESTFIT857 ), (858,  BESTFIT858 ), (860,  BESTFIT860 ), (861,  BESTFIT861 ),
+    (862,  BESTFIT862 ), (863,  BESTFIT863 ), (865,  BESTFIT865 ), (866,  BESTFIT866 ), (869,  BESTFIT869 ),
+    (874,  BESTFIT874 ), (1250, BESTFIT1250), (1251, BESTFIT1251), (1252, BESTFIT1252), (1253, BESTFIT1253),
+    (1254, BESTFIT1254), (1255, BESTFIT1255), (1256, BESTFIT1256), (1257, BESTFIT1257), (1258, BESTFIT1258),
+    (932,  BESTFIT932 ), (936,  BESTFIT936 ), (949,  BESTFIT949 ), (950,  BESTFIT950 ),
+];
+
+static BESTFIT437 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x2018, 0x27),
+    (0x2019, 0x27), (0x201A, 0x2C), (0x201C, 0x22), (0x201D, 0x22), (0x201E, 0x22), (0x2032, 0x27), (0x2035, 0x60), (0x2039, 0x3C),
+    (0x203A, 0x3E), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35),
+    (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48),
+    (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E),
+    (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A),
+    (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D),
+    (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E),
+    (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21),
+    (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29),
+    (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31),
+    (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39),
+    (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41),
+    (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49),
+    (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51),
+    (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59),
+    (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61),
+    (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69),
+    (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71),
+    (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79),
+    (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT737 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x2018, 0x27),
+    (0x2019, 0x27), (0x201A, 0x2C), (0x201C, 0x22), (0x201D, 0x22), (0x201E, 0x22), (0x2032, 0x27), (0x2035, 0x60), (0x2039, 0x3C),
+    (0x203A, 0x3E), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35),
+    (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48),
+    (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E),
+    (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A),
+    (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D),
+    (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x221E, 0x38), (0x2223, 0x7C), (0x2236, 0x3A),
+    (0x223C, 0x7E), (0x2261, 0x3D), (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B),
+    (0x301B, 0x5D), (0xFF01, 0x21), (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27),
+    (0xFF08, 0x28), (0xFF09, 0x29), (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F),
+    (0xFF10, 0x30), (0xFF11, 0x31), (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37),
+    (0xFF18, 0x38), (0xFF19, 0x39), (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F),
+    (0xFF20, 0x40), (0xFF21, 0x41), (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47),
+    (0xFF28, 0x48), (0xFF29, 0x49), (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F),
+    (0xFF30, 0x50), (0xFF31, 0x51), (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57),
+    (0xFF38, 0x58), (0xFF39, 0x59), (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F),
+    (0xFF40, 0x60), (0xFF41, 0x61), (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67),
+    (0xFF48, 0x68), (0xFF49, 0x69), (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F),
+    (0xFF50, 0x70), (0xFF51, 0x71), (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77),
+    (0xFF58, 0x78), (0xFF59, 0x79), (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT775 : &[(u16, u16)] = &[
+    (0x0102, 0x41), (0x0103, 0x61), (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0114, 0x45), (0x0115, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67),
+    (0x011E, 0x47), (0x011F, 0x67), (0x0120, 0x47), (0x0121, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A),
+    (0x0139, 0x4C), (0x013A, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0147, 0x4E), (0x0148, 0x6E), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0158, 0x52), (0x0159, 0x72), (0x015C, 0x53), (0x015D, 0x73),
+    (0x015E, 0x53), (0x015F, 0x73), (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74),
+    (0x0168, 0x55), (0x0169, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x2018, 0x27),
+    (0x201A, 0x2C), (0x2032, 0x27), (0x2035, 0x60), (0x2039, 0x3C), (0x203A, 0x3E), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31),
+    (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39),
+    (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49),
+    (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52),
+    (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65),
+    (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C),
+    (0x2217, 0x2A), (0x221E, 0x38), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E), (0x2248, 0x7E), (0x2261, 0x3D), (0x2264, 0x3D),
+    (0x2265, 0x3D), (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D),
+    (0xFF01, 0x21), (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28),
+    (0xFF09, 0x29), (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30),
+    (0xFF11, 0x31), (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38),
+    (0xFF19, 0x39), (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40),
+    (0xFF21, 0x41), (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48),
+    (0xFF29, 0x49), (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50),
+    (0xFF31, 0x51), (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58),
+    (0xFF39, 0x59), (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60),
+    (0xFF41, 0x61), (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68),
+    (0xFF49, 0x69), (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70),
+    (0xFF51, 0x71), (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78),
+    (0xFF59, 0x79), (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT850 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C), (0x013B, 0x4C),
+    (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E), (0x0145, 0x4E),
+    (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F), (0x0150, 0x4F),
+    (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72), (0x015A, 0x53),
+    (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73), (0x0162, 0x54),
+    (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75), (0x016A, 0x55),
+    (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75), (0x0172, 0x55),
+    (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A), (0x017A, 0x7A),
+    (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C), (0x01A0, 0x4F),
+    (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69), (0x01D1, 0x4F),
+    (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75), (0x01D9, 0x55),
+    (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61), (0x01E4, 0x47),
+    (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F), (0x01EC, 0x4F),
+    (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41), (0x01FB, 0x61),
+    (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45), (0x0207, 0x65),
+    (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F), (0x020F, 0x6F),
+    (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55), (0x0217, 0x75),
+    (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41), (0x0227, 0x61),
+    (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F), (0x022F, 0x6F),
+    (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27), (0x02C4, 0x5E),
+    (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E), (0x0303, 0x7E),
+    (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20), (0x2004, 0x20),
+    (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x2018, 0x27), (0x2019, 0x27),
+    (0x201A, 0x2C), (0x201C, 0x22), (0x201D, 0x22), (0x201E, 0x22), (0x2032, 0x27), (0x2035, 0x60), (0x2039, 0x3C), (0x203A, 0x3E),
+    (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36),
+    (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48),
+    (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50),
+    (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B),
+    (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F),
+    (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x221E, 0x38), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E),
+    (0x2248, 0x7E), (0x2261, 0x3D), (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C),
+    (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21), (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25),
+    (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29), (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D),
+    (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31), (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35),
+    (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39), (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D),
+    (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41), (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45),
+    (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49), (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D),
+    (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51), (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55),
+    (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59), (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D),
+    (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61), (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65),
+    (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69), (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D),
+    (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71), (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75),
+    (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79), (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D),
+    (0xFF5E, 0x7E),
+];
+
+static BESTFIT852 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x0112, 0x45), (0x0113, 0x65),
+    (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x013B, 0x4C), (0x013C, 0x6C),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F), (0x0156, 0x52), (0x0157, 0x72),
+    (0x015C, 0x53), (0x015D, 0x73), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75), (0x016A, 0x55), (0x016B, 0x75),
+    (0x016C, 0x55), (0x016D, 0x75), (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79),
+    (0x0178, 0x59), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C), (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75),
+    (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69), (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75),
+    (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75), (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75),
+    (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61), (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67),
+    (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F), (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47),
+    (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41), (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41),
+    (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45), (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49),
+    (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F), (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52),
+    (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55), (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54),
+    (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41), (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F),
+    (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F), (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59),
+    (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27), (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60),
+    (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E), (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F),
+    (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20), (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D),
+    (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x2018, 0x27), (0x2019, 0x27), (0x201A, 0x2C), (0x201C, 0x22), (0x201D, 0x22),
+    (0x201E, 0x22), (0x2032, 0x27), (0x2035, 0x60), (0x2039, 0x3C), (0x203A, 0x3E), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31),
+    (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39),
+    (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49),
+    (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52),
+    (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65),
+    (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C),
+    (0x2217, 0x2A), (0x221E, 0x38), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E), (0x2248, 0x7E), (0x2261, 0x3D), (0x2264, 0x3D),
+    (0x2265, 0x3D), (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D),
+    (0xFF01, 0x21), (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28),
+    (0xFF09, 0x29), (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30),
+    (0xFF11, 0x31), (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38),
+    (0xFF19, 0x39), (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40),
+    (0xFF21, 0x41), (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48),
+    (0xFF29, 0x49), (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50),
+    (0xFF31, 0x51), (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58),
+    (0xFF39, 0x59), (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60),
+    (0xFF41, 0x61), (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68),
+    (0xFF49, 0x69), (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70),
+    (0xFF51, 0x71), (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78),
+    (0xFF59, 0x79), (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT855 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x2018, 0x27),
+    (0x2019, 0x27), (0x201A, 0x2C), (0x201C, 0x22), (0x201D, 0x22), (0x201E, 0x22), (0x2032, 0x27), (0x2035, 0x60), (0x2039, 0x3C),
+    (0x203A, 0x3E), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35),
+    (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48),
+    (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E),
+    (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A),
+    (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D),
+    (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x221E, 0x38), (0x2223, 0x7C), (0x2236, 0x3A),
+    (0x223C, 0x7E), (0x2248, 0x7E), (0x2261, 0x3D), (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20),
+    (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21), (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24),
+    (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29), (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C),
+    (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31), (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34),
+    (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39), (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C),
+    (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41), (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44),
+    (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49), (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C),
+    (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51), (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54),
+    (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59), (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C),
+    (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61), (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64),
+    (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69), (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C),
+    (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71), (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74),
+    (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79), (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C),
+    (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT857 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x0120, 0x47), (0x0121, 0x67),
+    (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68), (0x0128, 0x49), (0x0129, 0x69),
+    (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69), (0x0134, 0x4A), (0x0135, 0x6A),
+    (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C), (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C),
+    (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E), (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E),
+    (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F), (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72),
+    (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72), (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73),
+    (0x0160, 0x53), (0x0161, 0x73), (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74),
+    (0x0168, 0x55), (0x0169, 0x75), (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75),
+    (0x0170, 0x55), (0x0171, 0x75), (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79),
+    (0x0178, 0x59), (0x0179, 0x5A), (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62),
+    (0x0197, 0x49), (0x019A, 0x6C), (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61),
+    (0x01CF, 0x49), (0x01D0, 0x69), (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75),
+    (0x01D7, 0x55), (0x01D8, 0x75), (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61),
+    (0x01E0, 0x41), (0x01E1, 0x61), (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B),
+    (0x01EA, 0x4F), (0x01EB, 0x6F), (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E),
+    (0x01F9, 0x6E), (0x01FA, 0x41), (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45),
+    (0x0205, 0x65), (0x0206, 0x45), (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F),
+    (0x020D, 0x6F), (0x020E, 0x4F), (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55),
+    (0x0215, 0x75), (0x0216, 0x55), (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48),
+    (0x021F, 0x68), (0x0226, 0x41), (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F),
+    (0x022D, 0x6F), (0x022E, 0x4F), (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27),
+    (0x02BA, 0x22), (0x02BC, 0x27), (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E),
+    (0x0300, 0x60), (0x0302, 0x5E), (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20),
+    (0x2002, 0x20), (0x2003, 0x20), (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2013, 0x2D),
+    (0x2014, 0x2D), (0x2018, 0x27), (0x2019, 0x27), (0x201A, 0x2C), (0x201C, 0x22), (0x201D, 0x22), (0x201E, 0x22), (0x2032, 0x27),
+    (0x2035, 0x60), (0x2039, 0x3C), (0x203A, 0x3E), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33),
+    (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45),
+    (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C),
+    (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52),
+    (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45),
+    (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x221E, 0x38),
+    (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E), (0x2248, 0x7E), (0x2261, 0x3D), (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C),
+    (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21), (0xFF02, 0x22),
+    (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29), (0xFF0A, 0x2A),
+    (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31), (0xFF12, 0x32),
+    (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39), (0xFF1A, 0x3A),
+    (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41), (0xFF22, 0x42),
+    (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49), (0xFF2A, 0x4A),
+    (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51), (0xFF32, 0x52),
+    (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59), (0xFF3A, 0x5A),
+    (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61), (0xFF42, 0x62),
+    (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69), (0xFF4A, 0x6A),
+    (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71), (0xFF52, 0x72),
+    (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79), (0xFF5A, 0x7A),
+    (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT858 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x2018, 0x27),
+    (0x2019, 0x27), (0x201A, 0x2C), (0x201C, 0x22), (0x201D, 0x22), (0x201E, 0x22), (0x2032, 0x27), (0x2035, 0x60), (0x2039, 0x3C),
+    (0x203A, 0x3E), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35),
+    (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48),
+    (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E),
+    (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A),
+    (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D),
+    (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x221E, 0x38), (0x2223, 0x7C), (0x2236, 0x3A),
+    (0x223C, 0x7E), (0x2248, 0x7E), (0x2261, 0x3D), (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20),
+    (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21), (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24),
+    (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29), (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C),
+    (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31), (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34),
+    (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39), (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C),
+    (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41), (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44),
+    (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49), (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C),
+    (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51), (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54),
+    (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59), (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C),
+    (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61), (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64),
+    (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69), (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C),
+    (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71), (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74),
+    (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79), (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C),
+    (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT860 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x2018, 0x27),
+    (0x2019, 0x27), (0x201A, 0x2C), (0x201C, 0x22), (0x201D, 0x22), (0x201E, 0x22), (0x2032, 0x27), (0x2035, 0x60), (0x2039, 0x3C),
+    (0x203A, 0x3E), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35),
+    (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48),
+    (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E),
+    (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A),
+    (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D),
+    (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E),
+    (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21),
+    (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29),
+    (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31),
+    (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39),
+    (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41),
+    (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49),
+    (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51),
+    (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59),
+    (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61),
+    (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69),
+    (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71),
+    (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79),
+    (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT861 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x2018, 0x27),
+    (0x2019, 0x27), (0x201A, 0x2C), (0x201C, 0x22), (0x201D, 0x22), (0x201E, 0x22), (0x2032, 0x27), (0x2035, 0x60), (0x2039, 0x3C),
+    (0x203A, 0x3E), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35),
+    (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48),
+    (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E),
+    (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A),
+    (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D),
+    (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E),
+    (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21),
+    (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29),
+    (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31),
+    (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39),
+    (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41),
+    (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49),
+    (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51),
+    (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59),
+    (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61),
+    (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69),
+    (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71),
+    (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79),
+    (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT862 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x2018, 0x27),
+    (0x2019, 0x27), (0x201A, 0x2C), (0x201C, 0x22), (0x201D, 0x22), (0x201E, 0x22), (0x2032, 0x27), (0x2035, 0x60), (0x2039, 0x3C),
+    (0x203A, 0x3E), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35),
+    (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48),
+    (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E),
+    (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A),
+    (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D),
+    (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E),
+    (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21),
+    (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29),
+    (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31),
+    (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39),
+    (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41),
+    (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49),
+    (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51),
+    (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59),
+    (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61),
+    (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69),
+    (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71),
+    (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79),
+    (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT863 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x2018, 0x27),
+    (0x2019, 0x27), (0x201A, 0x2C), (0x201C, 0x22), (0x201D, 0x22), (0x201E, 0x22), (0x2032, 0x27), (0x2035, 0x60), (0x2039, 0x3C),
+    (0x203A, 0x3E), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35),
+    (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48),
+    (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E),
+    (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A),
+    (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D),
+    (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E),
+    (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21),
+    (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29),
+    (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31),
+    (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39),
+    (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41),
+    (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49),
+    (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51),
+    (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59),
+    (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61),
+    (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69),
+    (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71),
+    (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79),
+    (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT865 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x2018, 0x27),
+    (0x2019, 0x27), (0x201A, 0x2C), (0x201C, 0x22), (0x201D, 0x22), (0x201E, 0x22), (0x2032, 0x27), (0x2035, 0x60), (0x2039, 0x3C),
+    (0x203A, 0x3E), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35),
+    (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48),
+    (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E),
+    (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A),
+    (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D),
+    (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E),
+    (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21),
+    (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29),
+    (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31),
+    (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39),
+    (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41),
+    (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49),
+    (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51),
+    (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59),
+    (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61),
+    (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69),
+    (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71),
+    (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79),
+    (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT866 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x2018, 0x27),
+    (0x2019, 0x27), (0x201A, 0x2C), (0x201C, 0x22), (0x201D, 0x22), (0x201E, 0x22), (0x2032, 0x27), (0x2035, 0x60), (0x2039, 0x3C),
+    (0x203A, 0x3E), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35),
+    (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48),
+    (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E),
+    (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A),
+    (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D),
+    (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x221E, 0x38), (0x2223, 0x7C), (0x2236, 0x3A),
+    (0x223C, 0x7E), (0x2248, 0x7E), (0x2261, 0x3D), (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20),
+    (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21), (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24),
+    (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29), (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C),
+    (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31), (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34),
+    (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39), (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C),
+    (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41), (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44),
+    (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49), (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C),
+    (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51), (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54),
+    (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59), (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C),
+    (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61), (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64),
+    (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69), (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C),
+    (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71), (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74),
+    (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79), (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C),
+    (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT869 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x201A, 0x2C),
+    (0x201C, 0x22), (0x201D, 0x22), (0x201E, 0x22), (0x2032, 0x27), (0x2035, 0x60), (0x2039, 0x3C), (0x203A, 0x3E), (0x2044, 0x2F),
+    (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37),
+    (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48),
+    (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50),
+    (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42),
+    (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D),
+    (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x221E, 0x38), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E), (0x2248, 0x7E),
+    (0x2261, 0x3D), (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E),
+    (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21), (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26),
+    (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29), (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E),
+    (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31), (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36),
+    (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39), (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E),
+    (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41), (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46),
+    (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49), (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E),
+    (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51), (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56),
+    (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59), (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E),
+    (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61), (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66),
+    (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69), (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E),
+    (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71), (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76),
+    (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79), (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT874 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x201A, 0x2C), (0x201E, 0x22), (0x2032, 0x27),
+    (0x2035, 0x60), (0x2039, 0x3C), (0x203A, 0x3E), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33),
+    (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45),
+    (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C),
+    (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52),
+    (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45),
+    (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x221E, 0x38),
+    (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E), (0x2248, 0x7E), (0x2261, 0x3D), (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C),
+    (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21), (0xFF02, 0x22),
+    (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29), (0xFF0A, 0x2A),
+    (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31), (0xFF12, 0x32),
+    (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39), (0xFF1A, 0x3A),
+    (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41), (0xFF22, 0x42),
+    (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49), (0xFF2A, 0x4A),
+    (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51), (0xFF32, 0x52),
+    (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59), (0xFF3A, 0x5A),
+    (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61), (0xFF42, 0x62),
+    (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69), (0xFF4A, 0x6A),
+    (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71), (0xFF52, 0x72),
+    (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79), (0xFF5A, 0x7A),
+    (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT1250 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x0112, 0x45), (0x0113, 0x65),
+    (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x013B, 0x4C), (0x013C, 0x6C),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F), (0x0156, 0x52), (0x0157, 0x72),
+    (0x015C, 0x53), (0x015D, 0x73), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75), (0x016A, 0x55), (0x016B, 0x75),
+    (0x016C, 0x55), (0x016D, 0x75), (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79),
+    (0x0178, 0x59), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C), (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75),
+    (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69), (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75),
+    (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75), (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75),
+    (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61), (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67),
+    (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F), (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47),
+    (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41), (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41),
+    (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45), (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49),
+    (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F), (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52),
+    (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55), (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54),
+    (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41), (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F),
+    (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F), (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59),
+    (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27), (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60),
+    (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E), (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F),
+    (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20), (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D),
+    (0x2011, 0x2D), (0x2032, 0x27), (0x2035, 0x60), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33),
+    (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45),
+    (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C),
+    (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52),
+    (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45),
+    (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x221E, 0x38),
+    (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E), (0x2248, 0x7E), (0x2261, 0x3D), (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C),
+    (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21), (0xFF02, 0x22),
+    (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29), (0xFF0A, 0x2A),
+    (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31), (0xFF12, 0x32),
+    (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39), (0xFF1A, 0x3A),
+    (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41), (0xFF22, 0x42),
+    (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49), (0xFF2A, 0x4A),
+    (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51), (0xFF32, 0x52),
+    (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59), (0xFF3A, 0x5A),
+    (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61), (0xFF42, 0x62),
+    (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69), (0xFF4A, 0x6A),
+    (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71), (0xFF52, 0x72),
+    (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79), (0xFF5A, 0x7A),
+    (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT1251 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2032, 0x27), (0x2035, 0x60), (0x2044, 0x2F),
+    (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37),
+    (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48),
+    (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50),
+    (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42),
+    (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D),
+    (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x221E, 0x38), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E), (0x2248, 0x7E),
+    (0x2261, 0x3D), (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E),
+    (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21), (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26),
+    (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29), (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E),
+    (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31), (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36),
+    (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39), (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E),
+    (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41), (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46),
+    (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49), (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E),
+    (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51), (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56),
+    (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59), (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E),
+    (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61), (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66),
+    (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69), (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E),
+    (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71), (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76),
+    (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79), (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT1252 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0162, 0x54), (0x0163, 0x74),
+    (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75), (0x016A, 0x55), (0x016B, 0x75),
+    (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75), (0x0172, 0x55), (0x0173, 0x75),
+    (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0179, 0x5A), (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A),
+    (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C), (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41),
+    (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69), (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55),
+    (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75), (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41),
+    (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61), (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B),
+    (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F), (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67),
+    (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41), (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61),
+    (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45), (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69),
+    (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F), (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72),
+    (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55), (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74),
+    (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41), (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F),
+    (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F), (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79),
+    (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27), (0x02C4, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x0300, 0x60),
+    (0x0302, 0x5E), (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20),
+    (0x2003, 0x20), (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2032, 0x27), (0x2035, 0x60),
+    (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36),
+    (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48),
+    (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50),
+    (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B),
+    (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F),
+    (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x221E, 0x38), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E),
+    (0x2248, 0x7E), (0x2261, 0x3D), (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C),
+    (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21), (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25),
+    (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29), (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D),
+    (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31), (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35),
+    (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39), (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D),
+    (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41), (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45),
+    (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49), (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D),
+    (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51), (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55),
+    (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59), (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D),
+    (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61), (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65),
+    (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69), (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D),
+    (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71), (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75),
+    (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79), (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D),
+    (0xFF5E, 0x7E),
+];
+
+static BESTFIT1253 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2032, 0x27), (0x2035, 0x60), (0x2044, 0x2F),
+    (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37),
+    (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48),
+    (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50),
+    (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42),
+    (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D),
+    (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x221E, 0x38), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E), (0x2248, 0x7E),
+    (0x2261, 0x3D), (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E),
+    (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21), (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26),
+    (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29), (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E),
+    (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31), (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36),
+    (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39), (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E),
+    (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41), (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46),
+    (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49), (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E),
+    (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51), (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56),
+    (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59), (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E),
+    (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61), (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66),
+    (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69), (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E),
+    (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71), (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76),
+    (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79), (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT1254 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x0120, 0x47), (0x0121, 0x67),
+    (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68), (0x0128, 0x49), (0x0129, 0x69),
+    (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69), (0x0134, 0x4A), (0x0135, 0x6A),
+    (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C), (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C),
+    (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E), (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E),
+    (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F), (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72),
+    (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72), (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0179, 0x5A), (0x017A, 0x7A),
+    (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C), (0x01A0, 0x4F),
+    (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69), (0x01D1, 0x4F),
+    (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75), (0x01D9, 0x55),
+    (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61), (0x01E4, 0x47),
+    (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F), (0x01EC, 0x4F),
+    (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41), (0x01FB, 0x61),
+    (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45), (0x0207, 0x65),
+    (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F), (0x020F, 0x6F),
+    (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55), (0x0217, 0x75),
+    (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41), (0x0227, 0x61),
+    (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F), (0x022F, 0x6F),
+    (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27), (0x02C4, 0x5E),
+    (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x0300, 0x60), (0x0302, 0x5E), (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F),
+    (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20), (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20),
+    (0x2010, 0x2D), (0x2011, 0x2D), (0x2032, 0x27), (0x2035, 0x60), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32),
+    (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43),
+    (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49),
+    (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52),
+    (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65),
+    (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A),
+    (0x221E, 0x38), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E), (0x2248, 0x7E), (0x2261, 0x3D), (0x2264, 0x3D), (0x2265, 0x3D),
+    (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21),
+    (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29),
+    (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31),
+    (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39),
+    (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41),
+    (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49),
+    (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51),
+    (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59),
+    (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61),
+    (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69),
+    (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71),
+    (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79),
+    (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT1255 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x0300, 0x60), (0x0302, 0x5E), (0x0303, 0x7E), (0x030E, 0x22),
+    (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20), (0x2004, 0x20), (0x2005, 0x20),
+    (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2032, 0x27), (0x2035, 0x60), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31),
+    (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39),
+    (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49),
+    (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52),
+    (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65),
+    (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C),
+    (0x2217, 0x2A), (0x221E, 0x38), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E), (0x2248, 0x7E), (0x2261, 0x3D), (0x2264, 0x3D),
+    (0x2265, 0x3D), (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D),
+    (0xFF01, 0x21), (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28),
+    (0xFF09, 0x29), (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30),
+    (0xFF11, 0x31), (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38),
+    (0xFF19, 0x39), (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40),
+    (0xFF21, 0x41), (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48),
+    (0xFF29, 0x49), (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50),
+    (0xFF31, 0x51), (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58),
+    (0xFF39, 0x59), (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60),
+    (0xFF41, 0x61), (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68),
+    (0xFF49, 0x69), (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70),
+    (0xFF51, 0x71), (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78),
+    (0xFF59, 0x79), (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT1256 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E), (0x0303, 0x7E),
+    (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20), (0x2004, 0x20),
+    (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2032, 0x27), (0x2035, 0x60), (0x2044, 0x2F), (0x2080, 0x30),
+    (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38),
+    (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68),
+    (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51),
+    (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43),
+    (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F),
+    (0x2216, 0x5C), (0x2217, 0x2A), (0x221E, 0x38), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E), (0x2248, 0x7E), (0x2261, 0x3D),
+    (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B),
+    (0x301B, 0x5D), (0xFF01, 0x21), (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27),
+    (0xFF08, 0x28), (0xFF09, 0x29), (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F),
+    (0xFF10, 0x30), (0xFF11, 0x31), (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37),
+    (0xFF18, 0x38), (0xFF19, 0x39), (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F),
+    (0xFF20, 0x40), (0xFF21, 0x41), (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47),
+    (0xFF28, 0x48), (0xFF29, 0x49), (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F),
+    (0xFF30, 0x50), (0xFF31, 0x51), (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57),
+    (0xFF38, 0x58), (0xFF39, 0x59), (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F),
+    (0xFF40, 0x60), (0xFF41, 0x61), (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67),
+    (0xFF48, 0x68), (0xFF49, 0x69), (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F),
+    (0xFF50, 0x70), (0xFF51, 0x71), (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77),
+    (0xFF58, 0x78), (0xFF59, 0x79), (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT1257 : &[(u16, u16)] = &[
+    (0x0102, 0x41), (0x0103, 0x61), (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0114, 0x45), (0x0115, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67),
+    (0x011E, 0x47), (0x011F, 0x67), (0x0120, 0x47), (0x0121, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A),
+    (0x0139, 0x4C), (0x013A, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0147, 0x4E), (0x0148, 0x6E), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0158, 0x52), (0x0159, 0x72), (0x015C, 0x53), (0x015D, 0x73),
+    (0x015E, 0x53), (0x015F, 0x73), (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74),
+    (0x0168, 0x55), (0x0169, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2032, 0x27), (0x2035, 0x60), (0x2044, 0x2F),
+    (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37),
+    (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48),
+    (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50),
+    (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42),
+    (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D),
+    (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x221E, 0x38), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E), (0x2248, 0x7E),
+    (0x2261, 0x3D), (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C), (0x232A, 0x3E), (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E),
+    (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21), (0xFF02, 0x22), (0xFF03, 0x23), (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26),
+    (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29), (0xFF0A, 0x2A), (0xFF0B, 0x2B), (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E),
+    (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31), (0xFF12, 0x32), (0xFF13, 0x33), (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36),
+    (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39), (0xFF1A, 0x3A), (0xFF1B, 0x3B), (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E),
+    (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41), (0xFF22, 0x42), (0xFF23, 0x43), (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46),
+    (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49), (0xFF2A, 0x4A), (0xFF2B, 0x4B), (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E),
+    (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51), (0xFF32, 0x52), (0xFF33, 0x53), (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56),
+    (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59), (0xFF3A, 0x5A), (0xFF3B, 0x5B), (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E),
+    (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61), (0xFF42, 0x62), (0xFF43, 0x63), (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66),
+    (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69), (0xFF4A, 0x6A), (0xFF4B, 0x6B), (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E),
+    (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71), (0xFF52, 0x72), (0xFF53, 0x73), (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76),
+    (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79), (0xFF5A, 0x7A), (0xFF5B, 0x7B), (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT1258 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63), (0x0108, 0x43), (0x0109, 0x63),
+    (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64), (0x0112, 0x45), (0x0113, 0x65),
+    (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65), (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65),
+    (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67), (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67),
+    (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68), (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69),
+    (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69), (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A),
+    (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C), (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C),
+    (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E), (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E),
+    (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F), (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72),
+    (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72), (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73),
+    (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73), (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74),
+    (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75), (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75),
+    (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75), (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77),
+    (0x0176, 0x59), (0x0177, 0x79), (0x0179, 0x5A), (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A),
+    (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69), (0x01D1, 0x4F),
+    (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75), (0x01D9, 0x55),
+    (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61), (0x01E4, 0x47),
+    (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F), (0x01EC, 0x4F),
+    (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41), (0x01FB, 0x61),
+    (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45), (0x0207, 0x65),
+    (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F), (0x020F, 0x6F),
+    (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55), (0x0217, 0x75),
+    (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41), (0x0227, 0x61),
+    (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F), (0x022F, 0x6F),
+    (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27), (0x02C4, 0x5E),
+    (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x0302, 0x5E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20),
+    (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20), (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D),
+    (0x2032, 0x27), (0x2035, 0x60), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34),
+    (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67),
+    (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C),
+    (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A),
+    (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46),
+    (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x221E, 0x38), (0x2223, 0x7C),
+    (0x2236, 0x3A), (0x223C, 0x7E), (0x2248, 0x7E), (0x2261, 0x3D), (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C), (0x232A, 0x3E),
+    (0x3000, 0x20), (0x3008, 0x3C), (0x3009, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF01, 0x21), (0xFF02, 0x22), (0xFF03, 0x23),
+    (0xFF04, 0x24), (0xFF05, 0x25), (0xFF06, 0x26), (0xFF07, 0x27), (0xFF08, 0x28), (0xFF09, 0x29), (0xFF0A, 0x2A), (0xFF0B, 0x2B),
+    (0xFF0C, 0x2C), (0xFF0D, 0x2D), (0xFF0E, 0x2E), (0xFF0F, 0x2F), (0xFF10, 0x30), (0xFF11, 0x31), (0xFF12, 0x32), (0xFF13, 0x33),
+    (0xFF14, 0x34), (0xFF15, 0x35), (0xFF16, 0x36), (0xFF17, 0x37), (0xFF18, 0x38), (0xFF19, 0x39), (0xFF1A, 0x3A), (0xFF1B, 0x3B),
+    (0xFF1C, 0x3C), (0xFF1D, 0x3D), (0xFF1E, 0x3E), (0xFF1F, 0x3F), (0xFF20, 0x40), (0xFF21, 0x41), (0xFF22, 0x42), (0xFF23, 0x43),
+    (0xFF24, 0x44), (0xFF25, 0x45), (0xFF26, 0x46), (0xFF27, 0x47), (0xFF28, 0x48), (0xFF29, 0x49), (0xFF2A, 0x4A), (0xFF2B, 0x4B),
+    (0xFF2C, 0x4C), (0xFF2D, 0x4D), (0xFF2E, 0x4E), (0xFF2F, 0x4F), (0xFF30, 0x50), (0xFF31, 0x51), (0xFF32, 0x52), (0xFF33, 0x53),
+    (0xFF34, 0x54), (0xFF35, 0x55), (0xFF36, 0x56), (0xFF37, 0x57), (0xFF38, 0x58), (0xFF39, 0x59), (0xFF3A, 0x5A), (0xFF3B, 0x5B),
+    (0xFF3C, 0x5C), (0xFF3D, 0x5D), (0xFF3E, 0x5E), (0xFF3F, 0x5F), (0xFF40, 0x60), (0xFF41, 0x61), (0xFF42, 0x62), (0xFF43, 0x63),
+    (0xFF44, 0x64), (0xFF45, 0x65), (0xFF46, 0x66), (0xFF47, 0x67), (0xFF48, 0x68), (0xFF49, 0x69), (0xFF4A, 0x6A), (0xFF4B, 0x6B),
+    (0xFF4C, 0x6C), (0xFF4D, 0x6D), (0xFF4E, 0x6E), (0xFF4F, 0x6F), (0xFF50, 0x70), (0xFF51, 0x71), (0xFF52, 0x72), (0xFF53, 0x73),
+    (0xFF54, 0x74), (0xFF55, 0x75), (0xFF56, 0x76), (0xFF57, 0x77), (0xFF58, 0x78), (0xFF59, 0x79), (0xFF5A, 0x7A), (0xFF5B, 0x7B),
+    (0xFF5C, 0x7C), (0xFF5D, 0x7D), (0xFF5E, 0x7E),
+];
+
+static BESTFIT932 : &[(u16, u16)] = &[
+    (0x00A5, 0x5C), (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43),
+    (0x0107, 0x63), (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44),
+    (0x010F, 0x64), (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45),
+    (0x0117, 0x65), (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47),
+    (0x011F, 0x67), (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48),
+    (0x0127, 0x68), (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49),
+    (0x012F, 0x69), (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C),
+    (0x013A, 0x6C), (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E),
+    (0x0144, 0x6E), (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F),
+    (0x014F, 0x6F), (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52),
+    (0x0159, 0x72), (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53),
+    (0x0161, 0x73), (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55),
+    (0x0169, 0x75), (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55),
+    (0x0171, 0x75), (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59),
+    (0x0179, 0x5A), (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49),
+    (0x019A, 0x6C), (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49),
+    (0x01D0, 0x69), (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55),
+    (0x01D8, 0x75), (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41),
+    (0x01E1, 0x61), (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F),
+    (0x01EB, 0x6F), (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E),
+    (0x01FA, 0x41), (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65),
+    (0x0206, 0x45), (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F),
+    (0x020E, 0x4F), (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75),
+    (0x0216, 0x55), (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68),
+    (0x0226, 0x41), (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F),
+    (0x022E, 0x4F), (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22),
+    (0x02BC, 0x27), (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60),
+    (0x0302, 0x5E), (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20),
+    (0x2003, 0x20), (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x201A, 0x2C),
+    (0x201E, 0x22), (0x2035, 0x60), (0x2039, 0x3C), (0x203A, 0x3E), (0x203E, 0x7E), (0x2044, 0x2F), (0x2080, 0x30), (0x2081, 0x31),
+    (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39),
+    (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49),
+    (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52),
+    (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65),
+    (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C),
+    (0x2217, 0x2A), (0x2223, 0x7C), (0x2236, 0x3A), (0x223C, 0x7E), (0x2248, 0x7E), (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C),
+    (0x232A, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D),
+];
+
+static BESTFIT936 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63), (0x0108, 0x43),
+    (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64), (0x0110, 0x44),
+    (0x0111, 0x64), (0x0112, 0x45), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65), (0x0118, 0x45), (0x0119, 0x65),
+    (0x011A, 0x45), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67), (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47),
+    (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68), (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49),
+    (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69), (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A),
+    (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C), (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C),
+    (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x014C, 0x4F), (0x014E, 0x4F),
+    (0x014F, 0x6F), (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52),
+    (0x0159, 0x72), (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53),
+    (0x0161, 0x73), (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55),
+    (0x0169, 0x75), (0x016A, 0x55), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CF, 0x49), (0x01D1, 0x4F), (0x01D3, 0x55),
+    (0x01D5, 0x55), (0x01D7, 0x55), (0x01D9, 0x55), (0x01DB, 0x55), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E), (0x0303, 0x7E),
+    (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20), (0x2004, 0x20),
+    (0x2005, 0x20), (0x2006, 0x20), (0x2011, 0x2D), (0x201A, 0x2C), (0x201E, 0x22), (0x2039, 0x3C), (0x203A, 0x3E), (0x2044, 0x2F),
+    (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37),
+    (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48),
+    (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50),
+    (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42),
+    (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D),
+    (0x2216, 0x5C), (0x2217, 0x2A), (0x223C, 0x7E), (0x2329, 0x3C), (0x232A, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D),
+];
+
+static BESTFIT949 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65), (0x0118, 0x45),
+    (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67), (0x0120, 0x47),
+    (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49),
+    (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69), (0x0130, 0x49), (0x0134, 0x4A), (0x0135, 0x6A),
+    (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C), (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C),
+    (0x0143, 0x4E), (0x0144, 0x6E), (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F),
+    (0x014E, 0x4F), (0x014F, 0x6F), (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72),
+    (0x0158, 0x52), (0x0159, 0x72), (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73),
+    (0x0160, 0x53), (0x0161, 0x73), (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02CB, 0x60), (0x02CD, 0x5F), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E),
+    (0x0303, 0x7E), (0x030E, 0x22), (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20),
+    (0x2004, 0x20), (0x2005, 0x20), (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x2013, 0x2D), (0x2014, 0x2D), (0x201A, 0x2C),
+    (0x201E, 0x22), (0x2035, 0x60), (0x2039, 0x3C), (0x203A, 0x3E), (0x2044, 0x2F), (0x2080, 0x30), (0x2085, 0x35), (0x2086, 0x36),
+    (0x2087, 0x37), (0x2088, 0x38), (0x2089, 0x39), (0x20A9, 0x5C), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48),
+    (0x210C, 0x48), (0x210D, 0x48), (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2115, 0x4E), (0x2118, 0x50),
+    (0x2119, 0x50), (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B),
+    (0x212C, 0x42), (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F),
+    (0x2212, 0x2D), (0x2215, 0x2F), (0x2216, 0x5C), (0x2217, 0x2A), (0x2223, 0x7C), (0x2236, 0x3A), (0x2248, 0x7E), (0x2329, 0x3C),
+    (0x232A, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D),
+];
+
+static BESTFIT950 : &[(u16, u16)] = &[
+    (0x0100, 0x41), (0x0101, 0x61), (0x0102, 0x41), (0x0103, 0x61), (0x0104, 0x41), (0x0105, 0x61), (0x0106, 0x43), (0x0107, 0x63),
+    (0x0108, 0x43), (0x0109, 0x63), (0x010A, 0x43), (0x010B, 0x63), (0x010C, 0x43), (0x010D, 0x63), (0x010E, 0x44), (0x010F, 0x64),
+    (0x0110, 0x44), (0x0111, 0x64), (0x0112, 0x45), (0x0113, 0x65), (0x0114, 0x45), (0x0115, 0x65), (0x0116, 0x45), (0x0117, 0x65),
+    (0x0118, 0x45), (0x0119, 0x65), (0x011A, 0x45), (0x011B, 0x65), (0x011C, 0x47), (0x011D, 0x67), (0x011E, 0x47), (0x011F, 0x67),
+    (0x0120, 0x47), (0x0121, 0x67), (0x0122, 0x47), (0x0123, 0x67), (0x0124, 0x48), (0x0125, 0x68), (0x0126, 0x48), (0x0127, 0x68),
+    (0x0128, 0x49), (0x0129, 0x69), (0x012A, 0x49), (0x012B, 0x69), (0x012C, 0x49), (0x012D, 0x69), (0x012E, 0x49), (0x012F, 0x69),
+    (0x0130, 0x49), (0x0131, 0x69), (0x0134, 0x4A), (0x0135, 0x6A), (0x0136, 0x4B), (0x0137, 0x6B), (0x0139, 0x4C), (0x013A, 0x6C),
+    (0x013B, 0x4C), (0x013C, 0x6C), (0x013D, 0x4C), (0x013E, 0x6C), (0x0141, 0x4C), (0x0142, 0x6C), (0x0143, 0x4E), (0x0144, 0x6E),
+    (0x0145, 0x4E), (0x0146, 0x6E), (0x0147, 0x4E), (0x0148, 0x6E), (0x014C, 0x4F), (0x014D, 0x6F), (0x014E, 0x4F), (0x014F, 0x6F),
+    (0x0150, 0x4F), (0x0151, 0x6F), (0x0154, 0x52), (0x0155, 0x72), (0x0156, 0x52), (0x0157, 0x72), (0x0158, 0x52), (0x0159, 0x72),
+    (0x015A, 0x53), (0x015B, 0x73), (0x015C, 0x53), (0x015D, 0x73), (0x015E, 0x53), (0x015F, 0x73), (0x0160, 0x53), (0x0161, 0x73),
+    (0x0162, 0x54), (0x0163, 0x74), (0x0164, 0x54), (0x0165, 0x74), (0x0166, 0x54), (0x0167, 0x74), (0x0168, 0x55), (0x0169, 0x75),
+    (0x016A, 0x55), (0x016B, 0x75), (0x016C, 0x55), (0x016D, 0x75), (0x016E, 0x55), (0x016F, 0x75), (0x0170, 0x55), (0x0171, 0x75),
+    (0x0172, 0x55), (0x0173, 0x75), (0x0174, 0x57), (0x0175, 0x77), (0x0176, 0x59), (0x0177, 0x79), (0x0178, 0x59), (0x0179, 0x5A),
+    (0x017A, 0x7A), (0x017B, 0x5A), (0x017C, 0x7A), (0x017D, 0x5A), (0x017E, 0x7A), (0x0180, 0x62), (0x0197, 0x49), (0x019A, 0x6C),
+    (0x01A0, 0x4F), (0x01A1, 0x6F), (0x01AF, 0x55), (0x01B0, 0x75), (0x01CD, 0x41), (0x01CE, 0x61), (0x01CF, 0x49), (0x01D0, 0x69),
+    (0x01D1, 0x4F), (0x01D2, 0x6F), (0x01D3, 0x55), (0x01D4, 0x75), (0x01D5, 0x55), (0x01D6, 0x75), (0x01D7, 0x55), (0x01D8, 0x75),
+    (0x01D9, 0x55), (0x01DA, 0x75), (0x01DB, 0x55), (0x01DC, 0x75), (0x01DE, 0x41), (0x01DF, 0x61), (0x01E0, 0x41), (0x01E1, 0x61),
+    (0x01E4, 0x47), (0x01E5, 0x67), (0x01E6, 0x47), (0x01E7, 0x67), (0x01E8, 0x4B), (0x01E9, 0x6B), (0x01EA, 0x4F), (0x01EB, 0x6F),
+    (0x01EC, 0x4F), (0x01ED, 0x6F), (0x01F0, 0x6A), (0x01F4, 0x47), (0x01F5, 0x67), (0x01F8, 0x4E), (0x01F9, 0x6E), (0x01FA, 0x41),
+    (0x01FB, 0x61), (0x0200, 0x41), (0x0201, 0x61), (0x0202, 0x41), (0x0203, 0x61), (0x0204, 0x45), (0x0205, 0x65), (0x0206, 0x45),
+    (0x0207, 0x65), (0x0208, 0x49), (0x0209, 0x69), (0x020A, 0x49), (0x020B, 0x69), (0x020C, 0x4F), (0x020D, 0x6F), (0x020E, 0x4F),
+    (0x020F, 0x6F), (0x0210, 0x52), (0x0211, 0x72), (0x0212, 0x52), (0x0213, 0x72), (0x0214, 0x55), (0x0215, 0x75), (0x0216, 0x55),
+    (0x0217, 0x75), (0x0218, 0x53), (0x0219, 0x73), (0x021A, 0x54), (0x021B, 0x74), (0x021E, 0x48), (0x021F, 0x68), (0x0226, 0x41),
+    (0x0227, 0x61), (0x0228, 0x45), (0x0229, 0x65), (0x022A, 0x4F), (0x022B, 0x6F), (0x022C, 0x4F), (0x022D, 0x6F), (0x022E, 0x4F),
+    (0x022F, 0x6F), (0x0230, 0x4F), (0x0231, 0x6F), (0x0232, 0x59), (0x0233, 0x79), (0x02B9, 0x27), (0x02BA, 0x22), (0x02BC, 0x27),
+    (0x02C4, 0x5E), (0x02C6, 0x5E), (0x02C8, 0x27), (0x02DC, 0x7E), (0x0300, 0x60), (0x0302, 0x5E), (0x0303, 0x7E), (0x030E, 0x22),
+    (0x0331, 0x5F), (0x0332, 0x5F), (0x2000, 0x20), (0x2001, 0x20), (0x2002, 0x20), (0x2003, 0x20), (0x2004, 0x20), (0x2005, 0x20),
+    (0x2006, 0x20), (0x2010, 0x2D), (0x2011, 0x2D), (0x201A, 0x2C), (0x201E, 0x22), (0x2039, 0x3C), (0x203A, 0x3E), (0x2044, 0x2F),
+    (0x2080, 0x30), (0x2081, 0x31), (0x2082, 0x32), (0x2083, 0x33), (0x2084, 0x34), (0x2085, 0x35), (0x2086, 0x36), (0x2087, 0x37),
+    (0x2088, 0x38), (0x2089, 0x39), (0x2102, 0x43), (0x2107, 0x45), (0x210A, 0x67), (0x210B, 0x48), (0x210C, 0x48), (0x210D, 0x48),
+    (0x210E, 0x68), (0x2110, 0x49), (0x2111, 0x49), (0x2112, 0x4C), (0x2113, 0x6C), (0x2115, 0x4E), (0x2118, 0x50), (0x2119, 0x50),
+    (0x211A, 0x51), (0x211B, 0x52), (0x211C, 0x52), (0x211D, 0x52), (0x2124, 0x5A), (0x2128, 0x5A), (0x212A, 0x4B), (0x212C, 0x42),
+    (0x212D, 0x43), (0x212E, 0x65), (0x212F, 0x65), (0x2130, 0x45), (0x2131, 0x46), (0x2133, 0x4D), (0x2134, 0x6F), (0x2212, 0x2D),
+    (0x2216, 0x5C), (0x2217, 0x2A), (0x2236, 0x3A), (0x223C, 0x7E), (0x2248, 0x7E), (0x2264, 0x3D), (0x2265, 0x3D), (0x2329, 0x3C),
+    (0x232A, 0x3E), (0x301A, 0x5B), (0x301B, 0x5D), (0xFF02, 0x22), (0xFF07, 0x27), (0xFF3B, 0x5B), (0xFF3D, 0x5D), (0xFF3E, 0x5E),
+    (0xFF40, 0x60),
+];
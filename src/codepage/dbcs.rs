@@ -0,0 +1,251 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+
+use super::dbcs_tables;
+
+use std::fmt::{self, Debug, Formatter};
+
+/// Marks a pair without a mapping in a [DoubleByteCodePage] table.
+pub(super) const UNDEFINED : u16 = 0xFFFF;
+
+struct Table {
+    decode: [[u16; 192]; 126],      // lead bytes `0x81..=0xFE` x trail bytes `0x40..=0xFF`
+    encode: &'static [(u16, u16)],  // `(unit, code)` sorted by `unit`, where `code <= 0xFF` is a single byte
+}
+
+macro_rules! table { ( $id:literal, $decode:path, $preferred:path ) => {{
+    const MAP : [u16; 0x10000] = encode_map($id, &$decode, $preferred);
+    Table { decode: $decode, encode: &encode_table::<{ encode_len(&MAP) }>(&MAP) }
+}}}
+
+static CP932 : Table = table!(932, dbcs_tables::CP932, dbcs_tables::CP932_PREFERRED);
+static CP936 : Table = table!(936, dbcs_tables::CP936, dbcs_tables::CP936_PREFERRED);
+static CP949 : Table = table!(949, dbcs_tables::CP949, dbcs_tables::CP949_PREFERRED);
+static CP950 : Table = table!(950, dbcs_tables::CP950, dbcs_tables::CP950_PREFERRED);
+
+static TABLES : &[(u32, &Table)] = &[(932, &CP932), (936, &CP936), (949, &CP949), (950, &CP950)];
+
+const fn lead_byte(id: u32, byte: u8) -> bool {
+    match id {
+        932 => matches!(byte, 0x81 ..= 0x9F | 0xE0 ..= 0xFC),
+        _   => matches!(byte, 0x81 ..= 0xFE),
+    }
+}
+
+const fn single_byte(id: u32, byte: u8) -> u16 {
+    match (id, byte) {
+        (_, 0x00 ..= 0x7F)      => byte as u16,
+        _ if lead_byte(id, byte) => UNDEFINED,
+        (932, 0x80)             => 0x0080,
+        (932, 0xA0)             => 0xF8F0,
+        (932, 0xA1 ..= 0xDF)    => 0xFF61 + (byte - 0xA1) as u16,
+        (932, 0xFD ..= 0xFF)    => 0xF8F1 + (byte - 0xFD) as u16,
+        (936, 0x80)             => 0x20AC,
+        (936, 0xFF)             => 0xF8F5,
+        (949, 0x80)             => 0x0080,
+        (949, 0xFF)             => 0xF8F7,
+        (950, 0x80)             => 0x0080,
+        (950, 0xFF)             => 0xF8F8,
+        _                       => UNDEFINED,
+    }
+}
+
+/// The code encoding each [u16] `>= 0x80`, or `UNDEFINED`:  the lowest code decoding to it, unless one is `preferred`.
+const fn encode_map(id: u32, decode: &[[u16; 192]; 126], preferred: &[u16]) -> [u16; 0x10000] {
+    let mut map = [UNDEFINED; 0x10000];
+    let mut code = 0x80;
+    while code <= 0xFEFF {
+        let unit = if code <= 0xFF {
+            single_byte(id, code as u8)
+        } else if lead_byte(id, (code >> 8) as u8) && (code & 0xFF) >= 0x40 {
+            decode[(code >> 8) - 0x81][(code & 0xFF) - 0x40]
+        } else {
+            UNDEFINED
+        };
+        if unit >= 0x80 && unit != UNDEFINED && map[unit as usize] == UNDEFINED { map[unit as usize] = code as u16; }
+        code += 1;
+    }
+    let mut i = 0;
+    while i < preferred.len() {
+        let code = preferred[i] as usize;
+        map[decode[(code >> 8) - 0x81][(code & 0xFF) - 0x40] as usize] = code as u16;
+        i += 1;
+    }
+    map
+}
+
+const fn encode_len(map: &[u16; 0x10000]) -> usize {
+    let (mut unit, mut len) = (0, 0);
+    while unit < map.len() {
+        if map[unit] != UNDEFINED { len += 1; }
+        unit += 1;
+    }
+    len
+}
+
+const fn encode_table<const N: usize>(map: &[u16; 0x10000]) -> [(u16, u16); N] {
+    let mut table = [(0, 0); N];
+    let (mut unit, mut len) = (0, 0);
+    while unit < map.len() {
+        if map[unit] != UNDEFINED { table[len] = (unit as u16, map[unit]); len += 1; }
+        unit += 1;
+    }
+    table
+}
+
+
+
+#[derive(Clone, Copy)] pub struct DoubleByteCodePage {
+    id:     u32,
+    table:  &'static Table,
+}
+
+impl Debug for DoubleByteCodePage {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "DoubleByteCodePage({})", self.id) }
+}
+
+impl PartialEq for DoubleByteCodePage { fn eq(&self, other: &Self) -> bool { self.id == other.id } }
+impl Eq for DoubleByteCodePage {}
+
+impl DoubleByteCodePage {
+    /// Get the code page with the Windows code page identifier `id`, or [None] if it's not a supported double byte code page.
+    pub fn new(id: u32) -> Option<Self> {
+        TABLES.iter().find(|(cp, _)| *cp == id).map(|&(id, table)| Self { id, table })
+    }
+
+    /// The identifiers of all supported double byte code pages, in ascending order.
+    pub fn ids() -> impl Iterator<Item = u32> { TABLES.iter().map(|(id, _)| *id) }
+
+    /// Returns `true` if `byte` starts a two byte sequence in this code page (like `IsDBCSLeadByteEx`.)
+    pub fn is_lead_byte(&self, byte: u8) -> bool { lead_byte(self.id, byte) }
+
+    /// Decode a single non-lead byte, or return [None] if it's a lead byte or undefined in this code page.
+    pub fn decode_byte(&self, byte: u8) -> Option<u16> { Some(single_byte(self.id, byte)).filter(|&unit| unit != UNDEFINED) }
+
+    /// Decode a lead byte and trail byte, or return [None] if the pair is undefined in this code page.
+    pub fn decode_pair(&self, lead: u8, trail: u8) -> Option<u16> {
+        if !self.is_lead_byte(lead) || trail < 0x40 { return None; }
+        Some(self.table.decode[usize::from(lead - 0x81)][usize::from(trail - 0x40)]).filter(|&unit| unit != UNDEFINED)
+    }
+
+    /// The [u16] undefined sequences decode to in [ConversionMode::Lossy].
+    pub fn default_unit(&self) -> u16 { if self.id == 932 { 0x30FB } else { u16::from(b'?') } }
+
+    /// Encode a single [u16] as a single byte (`0x00..=0xFF`) or a `lead << 8 | trail` pair, or return [None] if it can't be represented.
+    pub(super) fn encode_unit(&self, unit: u16) -> Option<u16> {
+        if unit < 0x80 { return Some(unit); }
+        let encode = self.table.encode;
+        Some(encode[encode.binary_search_by_key(&unit, |&(u, _)| u).ok()?].1)
+    }
+}
+
+impl CodePage for DoubleByteCodePage {
+    fn id(&self) -> u32 { self.id }
+
+    fn decode_into(&self, bytes: &[u8], mode: ConversionMode, units: &mut Vec<u16>) -> Result<(), CodePageError> {
+        units.reserve(bytes.len());
+        let mut index = 0;
+        while let Some(&byte) = bytes.get(index) {
+            let (unit, len) = match bytes.get(index+1) {
+                Some(&trail) if self.is_lead_byte(byte) => (self.decode_pair(byte, trail), 2),
+                _                                       => (self.decode_byte(byte), 1),
+            };
+            match (unit, mode) {
+                (Some(unit), _)                 => units.push(unit),
+                (None, ConversionMode::Lossy)   => units.push(self.default_unit()),
+                (None, ConversionMode::Strict)  => return Err(CodePageError::InvalidBytes { index }),
+            }
+            index += len;
+        }
+        Ok(())
+    }
+
+    fn encode_into(&self, units: &[u16], mode: ConversionMode, bytes: &mut Vec<u8>) -> Result<(), CodePageError> {
+        bytes.reserve(units.len());
+        for (index, &unit) in units.iter().enumerate() {
+            match (self.encode_unit(unit), mode) {
+                (Some(code), _) if code <= 0xFF => bytes.push(code as u8),
+                (Some(code), _)                 => bytes.extend_from_slice(&code.to_be_bytes()),
+                (None, ConversionMode::Lossy)   => bytes.push(b'?'),
+                (None, ConversionMode::Strict)  => return Err(CodePageError::Unmappable { index }),
+            }
+        }
+        Ok(())
+    }
+}
+
+
+
+#[test] fn tables() {
+    for id in DoubleByteCodePage::ids() {
+        let cp = DoubleByteCodePage::new(id).unwrap();
+        assert_eq!(cp.id(), id);
+        assert!(cp.table.encode.windows(2).all(|w| w[0].0 < w[1].0));
+        for byte in 0 ..= 0xFF {
+            if let Some(unit) = cp.decode_byte(byte) { assert_eq!(cp.encode_unit(unit), Some(byte.into()), "code page {} byte 0x{:02X}", id, byte); }
+        }
+        for lead in (0x81 ..= 0xFE).filter(|&b| cp.is_lead_byte(b)) {
+            for trail in 0x40 ..= 0xFF {
+                let unit = match cp.decode_pair(lead, trail) { Some(u) => u, None => continue };
+                let code = cp.encode_unit(unit).unwrap();
+                assert_eq!(cp.decode(&code.to_be_bytes()[usize::from(code <= 0xFF)..]).unwrap(), [unit], "code page {} 0x{:02X}{:02X}", id, lead, trail);
+            }
+        }
+    }
+    assert!(DoubleByteCodePage::new(1252).is_none());
+    assert_eq!(format!("{:?}", DoubleByteCodePage::new(950).unwrap()), "DoubleByteCodePage(950)");
+}
+
+#[test] fn best_fits() {
+    for id in DoubleByteCodePage::ids() { super::bestfit::check_best_fits(&DoubleByteCodePage::new(id).unwrap()); }
+}
+
+#[test] fn windows_mappings() {
+    let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
+
+    let cp = DoubleByteCodePage::new(932).unwrap();
+    assert_eq!(cp.decode(b"\\~\x81\x5F\x81\x60\x81\x7C").unwrap(), utf16("\\~＼～－"));
+    assert_eq!(cp.decode(b"\x87\x90\x81\xE0\xED\x40\xFA\x5C").unwrap(), utf16("≒≒纊纊"));
+    assert_eq!(cp.encode(&utf16("≒纊")).unwrap(), b"\x81\xE0\xFA\x5C"); // NEC row 13 and IBM extensions are preferred
+    assert_eq!(cp.decode(b"\x80\xA0\xFD\xFF\xF0\x40\xF9\xFC").unwrap(), [0x80, 0xF8F0, 0xF8F1, 0xF8F3, 0xE000, 0xE757]);
+
+    let cp = DoubleByteCodePage::new(936).unwrap();
+    assert_eq!(cp.decode(b"\x80\xC4\xE3\xBA\xC3\xAA\xA1\xA1\x40").unwrap(), [0x20AC, 0x4F60, 0x597D, 0xE000, 0xE4C6]);
+    assert_eq!(cp.encode(&utf16("€")).unwrap(), b"\x80");
+    assert_eq!(cp.decode(b"\xFF").unwrap(), [0xF8F5]);
+    assert_eq!(cp.encode(&[0xF8F5]).unwrap(), b"\xFF");
+
+    let cp = DoubleByteCodePage::new(949).unwrap();
+    assert_eq!(cp.decode(b"\xC7\xD1\xB1\xDB\x81\x41\xC9\xA1").unwrap(), [0xD55C, 0xAE00, 0xAC02, 0xE000]);
+    assert_eq!(cp.decode(b"\x81\x5B"), Err(CodePageError::InvalidBytes { index: 0 }));
+    assert_eq!(cp.decode(b"\x80\xFF").unwrap(), [0x80, 0xF8F7]);
+
+    let cp = DoubleByteCodePage::new(950).unwrap();
+    assert_eq!(cp.decode(b"\xA4\xA4\xA4\xE5\xA1\x45\xA3\xE1\xF9\xFE").unwrap(), utf16("中文‧€▓"));
+    assert_eq!(cp.decode(b"\xFA\x40\xC6\xA1").unwrap(), [0xE000, 0xF6B1]);
+    assert_eq!(cp.decode(b"\x80\xFF").unwrap(), [0x80, 0xF8F8]);
+    assert_eq!(cp.encode(&[0x80, 0xF8F8]).unwrap(), b"\x80\xFF");
+}
+
+#[test] fn strict_and_lossy() {
+    let cp = DoubleByteCodePage::new(936).unwrap();
+    assert_eq!(cp.decode_lossy(b"a\x81\x7Fb\x81"), b"a?b?".iter().map(|&b| u16::from(b)).collect::<Vec<_>>());
+    assert_eq!(cp.decode(b"ab\x81"), Err(CodePageError::InvalidBytes { index: 2 }));
+    assert_eq!(cp.decode(b"\x81\x7F"), Err(CodePageError::InvalidBytes { index: 0 }));
+    assert_eq!(cp.encode(&[b'a' as u16, 0x3042, 0xD83D, 0xDE00]).unwrap_err(), CodePageError::Unmappable { index: 2 });
+    assert_eq!(cp.encode_lossy(&[b'a' as u16, 0x3042, 0xD83D, 0xDE00]), b"a\xA4\xA2??");
+
+    let cp = DoubleByteCodePage::new(932).unwrap();
+    assert_eq!(cp.decode_lossy(b"\x81\x22\x81"), [0x30FB, 0x30FB]); // the lead byte consumes the '"'
+    assert_eq!(cp.encode_lossy(&[0x20AC, b'a' as u16]), b"?a");
+}
+
+#[cfg(windows)] #[cfg(feature = "bstr")] #[test] fn bstrings() {
+    let cp = DoubleByteCodePage::new(932).unwrap();
+    let s = cp.decode_bstring(b"\x83\x65\x83\x58\x83\x67").unwrap();
+    assert_eq!(s, "テスト");
+    assert_eq!(cp.encode_bstr(&s).unwrap(), b"\x83\x65\x83\x58\x83\x67");
+    assert_eq!(cp.decode_bstring_lossy(bstr!("a").bytes()), bstr!("a\0"));
+}
+
@@ -0,0 +1,39 @@
+#![forbid(unsafe_code)]
+
+use std::fmt::{self, Display, Formatter};
+
+
+
+/// An error converting between a code page and [u16] unicode [code unit]s in [ConversionMode::Strict](crate::ConversionMode::Strict).
+///
+/// [code unit]:    https://unicode.org/glossary/#code_unit
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] pub enum CodePageError {
+    /// The byte sequence starting at `index` isn't a valid character in the code page (`MB_ERR_INVALID_CHARS`.)
+    InvalidBytes { index: usize },
+
+    /// The [u16] unicode [code unit] at `index` can't be represented in the code page.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    Unmappable { index: usize },
+}
+
+impl CodePageError {
+    /// The index of the offending byte (when decoding) or [u16] (when encoding.)
+    pub fn index(&self) -> usize {
+        match *self {
+            CodePageError::InvalidBytes { index }   => index,
+            CodePageError::Unmappable { index }     => index,
+        }
+    }
+}
+
+impl Display for CodePageError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            CodePageError::InvalidBytes { index }   => write!(fmt, "invalid byte sequence at index {}", index),
+            CodePageError::Unmappable { index }     => write!(fmt, "code unit at index {} cannot be represented in the code page", index),
+        }
+    }
+}
+
+impl std::error::Error for CodePageError {}
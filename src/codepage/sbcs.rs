@@ -0,0 +1,145 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+use super::sbcs_tables::*;
+
+use std::fmt::{self, Debug, Formatter};
+
+/// Marks a byte without a mapping in a [SingleByteCodePage] table.
+pub(super) const UNDEFINED : u16 = 0xFFFF;
+
+static TABLES : &[(u32, &[u16; 128])] = &[
+    (437,  &CP437 ), (737,  &CP737 ), (775,  &CP775 ), (850,  &CP850 ), (852,  &CP852 ),
+    (855,  &CP855 ), (857,  &CP857 ), (858,  &CP858 ), (860,  &CP860 ), (861,  &CP861 ),
+    (862,  &CP862 ), (863,  &CP863 ), (865,  &CP865 ), (866,  &CP866 ), (869,  &CP869 ),
+    (874,  &CP874 ), (1250, &CP1250), (1251, &CP1251), (1252, &CP1252), (1253, &CP1253),
+    (1254, &CP1254), (1255, &CP1255), (1256, &CP1256), (1257, &CP1257), (1258, &CP1258),
+];
+
+
+
+/// A table-driven Windows single byte code page, such as `1252` (Western European), `1251` (Cyrillic), or `437` (OEM United States.)
+///
+/// * Bytes `0x00..=0x7F` decode to themselves.
+/// * Bytes `0x80..=0x9F` that the Windows ("ANSI") code pages leave undefined decode to the C1 control of the same value, like Windows does
+///   (e.g. `0x81` → `U+0081` in `1252`.)  They're valid in [ConversionMode::Strict], and encode back to the same byte.
+/// * Bytes that Windows decodes to private use characters (e.g. `0xAA` → `U+F8F9` in `1253`) decode to the same characters.
+///   They're valid in [ConversionMode::Strict], and encode back to the same byte.
+/// * Any other undefined bytes (e.g. `0xD5` in `857`) are invalid.  [ConversionMode::Lossy] decodes them as `U+FFFD`.
+/// * [ConversionMode::Lossy] encodes unmappable [u16]s as `'?'`, one per [u16], without best-fit mappings.
+///
+/// See [CodePage] for conversion methods.
+#[derive(Clone, Copy)] pub struct SingleByteCodePage {
+    id:     u32,
+    high:   &'static [u16; 128], // `UNDEFINED` for undefined bytes
+}
+
+impl Debug for SingleByteCodePage {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "SingleByteCodePage({})", self.id) }
+}
+
+impl PartialEq for SingleByteCodePage { fn eq(&self, other: &Self) -> bool { self.id == other.id } }
+impl Eq for SingleByteCodePage {}
+
+impl SingleByteCodePage {
+    /// Get the code page with the Windows code page identifier `id`, or [None] if it's not a supported single byte code page.
+    pub fn new(id: u32) -> Option<Self> {
+        TABLES.iter().find(|(cp, _)| *cp == id).map(|&(id, high)| Self { id, high })
+    }
+
+    /// The identifiers of all supported single byte code pages, in ascending order.
+    pub fn ids() -> impl Iterator<Item = u32> { TABLES.iter().map(|(id, _)| *id) }
+
+    /// Decode a single byte, or return [None] if it's undefined in this code page.
+    pub fn decode_byte(&self, byte: u8) -> Option<u16> {
+        if byte < 0x80 { return Some(byte.into()); }
+        Some(self.high[usize::from(byte - 0x80)]).filter(|&unit| unit != UNDEFINED)
+    }
+
+    /// Encode a single [u16], or return [None] if it can't be represented in this code page.
+    pub fn encode_unit(&self, unit: u16) -> Option<u8> {
+        if unit < 0x80 { return Some(unit as u8); }
+        if unit == UNDEFINED { return None; }
+        self.high.iter().position(|&u| u == unit).map(|i| 0x80 + i as u8)
+    }
+}
+
+impl CodePage for SingleByteCodePage {
+    fn id(&self) -> u32 { self.id }
+
+    fn decode_into(&self, bytes: &[u8], mode: ConversionMode, units: &mut Vec<u16>) -> Result<(), CodePageError> {
+        units.reserve(bytes.len());
+        for (index, &byte) in bytes.iter().enumerate() {
+            match (self.decode_byte(byte), mode) {
+                (Some(unit), _)                 => units.push(unit),
+                (None, ConversionMode::Lossy)   => units.push(0xFFFD),
+                (None, ConversionMode::Strict)  => return Err(CodePageError::InvalidBytes { index }),
+            }
+        }
+        Ok(())
+    }
+
+    fn encode_into(&self, units: &[u16], mode: ConversionMode, bytes: &mut Vec<u8>) -> Result<(), CodePageError> {
+        bytes.reserve(units.len());
+        for (index, &unit) in units.iter().enumerate() {
+            match (self.encode_unit(unit), mode) {
+                (Some(byte), _)                 => bytes.push(byte),
+                (None, ConversionMode::Lossy)   => bytes.push(b'?'),
+                (None, ConversionMode::Strict)  => return Err(CodePageError::Unmappable { index }),
+            }
+        }
+        Ok(())
+    }
+}
+
+
+
+#[test] fn tables() {
+    for id in SingleByteCodePage::ids() {
+        let cp = SingleByteCodePage::new(id).unwrap();
+        assert_eq!(cp.id(), id);
+        for byte in 0 ..= 0xFF {
+            if let Some(unit) = cp.decode_byte(byte) {
+                assert_eq!(cp.encode_unit(unit), Some(byte), "code page {} byte 0x{:02X}", id, byte);
+            }
+        }
+    }
+    assert!(SingleByteCodePage::new(65001).is_none());
+    assert!(SingleByteCodePage::ids().zip(SingleByteCodePage::ids().skip(1)).all(|(a, b)| a < b));
+}
+
+#[test] fn windows_1252() {
+    let cp = SingleByteCodePage::new(1252).unwrap();
+    for &b in &[0x81, 0x8D, 0x8F, 0x90, 0x9D] { assert_eq!(cp.decode(&[b]).unwrap(), [u16::from(b)]); }
+    assert_eq!(cp.decode(b"caf\xE9 \x93q\x94").unwrap(), "café “q”".encode_utf16().collect::<Vec<_>>());
+    assert_eq!(cp.encode(&"café “q”".encode_utf16().collect::<Vec<_>>()).unwrap(), b"caf\xE9 \x93q\x94");
+    assert_eq!(cp.encode(&[b'a' as u16, 0x3A9]), Err(CodePageError::Unmappable { index: 1 }));
+    assert_eq!(cp.encode_lossy(&[b'a' as u16, 0xD83D, 0xDE00]), b"a??");
+    assert_eq!(cp.encode(&[0xFFFF]), Err(CodePageError::Unmappable { index: 0 }));
+    assert_eq!(format!("{:?}", cp), "SingleByteCodePage(1252)");
+}
+
+#[test] fn strict_and_lossy() {
+    let cp = SingleByteCodePage::new(1253).unwrap();
+    assert_eq!(cp.decode(b"a\xAAb").unwrap(), [b'a' as u16, 0xF8F9, b'b' as u16]); // private use, like Windows
+    assert_eq!(cp.encode(&[0xF8F9, 0xF8FA, 0xF8FB]).unwrap(), b"\xAA\xD2\xFF");
+    assert_eq!(cp.decode(b"\xC1\x81").unwrap(), [0x391, 0x81]); // 'Α', U+0081
+
+    let cp = SingleByteCodePage::new(857).unwrap();
+    assert_eq!(cp.decode(b"a\xD5b"), Err(CodePageError::InvalidBytes { index: 1 }));
+    assert_eq!(cp.decode_lossy(b"a\xD5b"), [b'a' as u16, 0xFFFD, b'b' as u16]);
+
+    let cp = SingleByteCodePage::new(437).unwrap();
+    assert_eq!(cp.decode(b"\x01\xC9\xCD\xBB\xEC").unwrap(), "\u{1}╔═╗∞".encode_utf16().collect::<Vec<_>>());
+    assert_eq!(SingleByteCodePage::new(1251).unwrap().decode(b"\xCF\xF0\xE8\xE2\xE5\xF2").unwrap(), "Привет".encode_utf16().collect::<Vec<_>>());
+    assert_eq!(SingleByteCodePage::new(1250).unwrap().encode(&"Łódź".encode_utf16().collect::<Vec<_>>()).unwrap(), b"\xA3\xF3d\x9F");
+}
+
+#[cfg(windows)] #[cfg(feature = "bstr")] #[test] fn bstrings() {
+    let cp = SingleByteCodePage::new(850).unwrap();
+    let s = cp.decode_bstring(b"\x82t\x82").unwrap();
+    assert_eq!(s, "été");
+    assert_eq!(cp.encode_bstr(&s).unwrap(), b"\x82t\x82");
+    assert_eq!(cp.decode_bstring_lossy(b"a\0b"), bstr!("a\0b"));
+    assert_eq!(cp.encode_bstr_lossy(bstr!("€")), b"?");
+}
@@ -0,0 +1,212 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+use super::bestfit::best_fit;
+
+
+
+/// How to handle invalid or unmappable input when converting between a code page and [u16]s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] pub enum ConversionMode {
+    /// Fail with a [CodePageError] on the first invalid or unmappable input, like `MB_ERR_INVALID_CHARS` / `WC_ERR_INVALID_CHARS`.
+    Strict,
+
+    /// Replace invalid or unmappable input, similar to `MultiByteToWideChar` / `WideCharToMultiByte` (with `WC_NO_BEST_FIT_CHARS`.)
+    /// The replacements aren't guaranteed to match Windows' - see each code page's documentation for details.
+    /// See [CodePage::encode_with] for best fit mappings and custom default characters.
+    Lossy,
+}
+
+
+
+/// A portable, deterministic converter between a Windows code page and [u16] unicode [code unit]s.
+///
+/// Unlike `MultiByteToWideChar` / `WideCharToMultiByte`, results don't depend on the installed code pages or the machine's ACP.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// let cp1252 = SingleByteCodePage::new(1252).unwrap();
+/// let units = cp1252.decode(b"\x80 \x81").unwrap();
+/// assert_eq!(units, [0x20AC, 0x20, 0x81]); // '€', ' ', U+0081 (undefined in 1252, but Windows maps it anyways)
+/// assert_eq!(cp1252.encode(&units).unwrap(), b"\x80 \x81");
+///
+/// let units = "Ω≈".encode_utf16().collect::<Vec<_>>();
+/// assert_eq!(cp1252.encode(&units), Err(CodePageError::Unmappable { index: 0 }));
+/// assert_eq!(cp1252.encode_lossy(&units), b"??");
+/// ```
+///
+/// [code unit]:    https://unicode.org/glossary/#code_unit
+pub trait CodePage {
+    /// The Windows code page identifier (e.g. `1252` for `windows-1252`.)
+    fn id(&self) -> u32;
+
+    /// Decode `bytes`, appending [u16]s to `units`.
+    ///
+    /// In [ConversionMode::Lossy], this never fails.  On error, `units` may have been partially appended to.
+    fn decode_into(&self, bytes: &[u8], mode: ConversionMode, units: &mut Vec<u16>) -> Result<(), CodePageError>;
+
+    /// Encode `units`, appending to `bytes`.
+    ///
+    /// In [ConversionMode::Lossy], this never fails.  On error, `bytes` may have been partially appended to.
+    fn encode_into(&self, units: &[u16], mode: ConversionMode, bytes: &mut Vec<u8>) -> Result<(), CodePageError>;
+
+    /// Decode `bytes`, failing on the first invalid byte sequence (like `MB_ERR_INVALID_CHARS`.)
+    fn decode(&self, bytes: &[u8]) -> Result<Vec<u16>, CodePageError> {
+        let mut units = Vec::with_capacity(bytes.len());
+        self.decode_into(bytes, ConversionMode::Strict, &mut units)?;
+        Ok(units)
+    }
+
+    /// Decode `bytes`, replacing invalid byte sequences.
+    fn decode_lossy(&self, bytes: &[u8]) -> Vec<u16> {
+        let mut units = Vec::with_capacity(bytes.len());
+        let _ = self.decode_into(bytes, ConversionMode::Lossy, &mut units);
+        units
+    }
+
+    /// Encode `units`, failing on the first unmappable [u16].
+    fn encode(&self, units: &[u16]) -> Result<Vec<u8>, CodePageError> {
+        let mut bytes = Vec::with_capacity(units.len());
+        self.encode_into(units, ConversionMode::Strict, &mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Encode `units`, replacing unmappable [u16]s with `'?'` (see [CodePage::encode_with] for best fit.)
+    fn encode_lossy(&self, units: &[u16]) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(units.len());
+        let _ = self.encode_into(units, ConversionMode::Lossy, &mut bytes);
+        bytes
+    }
+
+    /// Encode `units` like `WideCharToMultiByte`, with explicit best fit and default character `options`,
+    /// reporting exactly which [u16]s were replaced.
+    ///
+    /// Each unmappable [u16] is replaced individually, so a surrogate pair without a mapping becomes two default characters, like Windows.
+    ///
+    /// ### Example
+    /// ```
+    /// # use winstr::*;
+    /// let cp1252 = SingleByteCodePage::new(1252).unwrap();
+    /// let units = "∞≤Ω".encode_utf16().collect::<Vec<_>>();
+    ///
+    /// let encoded = cp1252.encode_with(&units, &EncodeOptions::default());
+    /// assert_eq!(encoded.bytes, b"8=?");
+    /// assert_eq!(encoded.lossy, [
+    ///     LossyUnit::BestFit { index: 0, with: '8' as u16 },
+    ///     LossyUnit::BestFit { index: 1, with: '=' as u16 },
+    ///     LossyUnit::DefaultChar { index: 2 },
+    /// ]);
+    ///
+    /// let encoded = cp1252.encode_with(&units, &EncodeOptions { best_fit: false, default_char: Some(u16::from(b'_')) });
+    /// assert_eq!(encoded.bytes, b"___");
+    /// assert!(encoded.used_default_char());
+    /// ```
+    fn encode_with(&self, units: &[u16], options: &EncodeOptions) -> Encoded {
+        let default_char = options.default_char.and_then(|unit| self.encode(&[unit]).ok()).unwrap_or_else(|| b"?".to_vec());
+        let mut encoded = Encoded { bytes: Vec::with_capacity(units.len()), lossy: Vec::new() };
+        let mut start = 0;
+        while start < units.len() {
+            let len = encoded.bytes.len();
+            let index = match self.encode_into(&units[start..], ConversionMode::Strict, &mut encoded.bytes) {
+                Ok(())  => break,
+                Err(err) => start + err.index(),
+            };
+            encoded.bytes.truncate(len);
+            let _ = self.encode_into(&units[start..index], ConversionMode::Strict, &mut encoded.bytes);
+
+            let best_fit = best_fit(self.id(), units[index]).filter(|_| options.best_fit).and_then(|code| {
+                let bytes = &code.to_be_bytes()[usize::from(code <= 0xFF)..];
+                match self.decode(bytes).ok()?[..] { [with] => Some((code, with)), _ => None }
+            });
+            match best_fit {
+                Some((code, with)) => {
+                    encoded.bytes.extend_from_slice(&code.to_be_bytes()[usize::from(code <= 0xFF)..]);
+                    encoded.lossy.push(LossyUnit::BestFit { index, with });
+                },
+                None => {
+                    encoded.bytes.extend_from_slice(&default_char);
+                    encoded.lossy.push(LossyUnit::DefaultChar { index });
+                },
+            }
+            start = index + 1;
+        }
+        encoded
+    }
+
+    /// Decode `bytes` into a [BString], failing on the first invalid byte sequence (like `MB_ERR_INVALID_CHARS`.)
+    ///
+    /// Panics if the [BString] cannot be allocated.
+    #[cfg(windows)] #[cfg(feature = "bstr")]
+    fn decode_bstring(&self, bytes: &[u8]) -> Result<BString, CodePageError> {
+        Ok(BString::from_code_units(self.decode(bytes)?.into_iter()).unwrap())
+    }
+
+    /// Decode `bytes` into a [BString], replacing invalid byte sequences.
+    ///
+    /// Panics if the [BString] cannot be allocated.
+    #[cfg(windows)] #[cfg(feature = "bstr")]
+    fn decode_bstring_lossy(&self, bytes: &[u8]) -> BString {
+        BString::from_code_units(self.decode_lossy(bytes).into_iter()).unwrap()
+    }
+
+    /// Encode a [BStr], failing on the first unmappable [u16].
+    #[cfg(windows)] #[cfg(feature = "bstr")]
+    fn encode_bstr(&self, s: &BStr) -> Result<Vec<u8>, CodePageError> { self.encode(s.units()) }
+
+    /// Encode a [BStr], replacing unmappable [u16]s.
+    #[cfg(windows)] #[cfg(feature = "bstr")]
+    fn encode_bstr_lossy(&self, s: &BStr) -> Vec<u8> { self.encode_lossy(s.units()) }
+
+    /// Encode a [BStr] like `WideCharToMultiByte` - see [CodePage::encode_with].
+    #[cfg(windows)] #[cfg(feature = "bstr")]
+    fn encode_bstr_with(&self, s: &BStr, options: &EncodeOptions) -> Encoded { self.encode_with(s.units(), options) }
+}
+
+
+
+#[test] fn encode_with() {
+    let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
+    let cp = SingleByteCodePage::new(1252).unwrap();
+
+    let units = utf16("a\u{1F600}／b");
+    let encoded = cp.encode_with(&units, &EncodeOptions::default());
+    assert_eq!(encoded.bytes, b"a??/b");
+    assert_eq!(encoded.lossy, [LossyUnit::DefaultChar { index: 1 }, LossyUnit::DefaultChar { index: 2 }, LossyUnit::BestFit { index: 3, with: u16::from(b'/') }]);
+    assert_eq!(encoded.lossy.iter().map(|l| l.index()).collect::<Vec<_>>(), [1, 2, 3]);
+
+    let encoded = cp.encode_with(&units, &EncodeOptions::no_best_fit());
+    assert_eq!(encoded.bytes, cp.encode_lossy(&units));
+    assert_eq!(encoded.bytes, b"a???b");
+
+    let encoded = cp.encode_with(&utf16("Āé"), &EncodeOptions::default());
+    assert_eq!(encoded.bytes, b"A\xE9");
+    assert!(!encoded.used_default_char() && !encoded.is_lossless());
+
+    let encoded = cp.encode_with(&utf16("café"), &EncodeOptions { best_fit: true, default_char: Some(u16::from(b'_')) });
+    assert!(encoded.is_lossless());
+    assert_eq!(cp.encode_with(&utf16("日本"), &EncodeOptions { best_fit: true, default_char: Some(u16::from(b'_')) }).bytes, b"__");
+    assert_eq!(cp.encode_with(&utf16("日本"), &EncodeOptions { best_fit: true, default_char: Some(0xE9) }).bytes, b"\xE9\xE9"); // 'é'
+    assert_eq!(cp.encode_with(&utf16("日本"), &EncodeOptions { best_fit: true, default_char: Some(0x2248) }).bytes, b"??"); // '≈' only best fits ('~')
+    assert_eq!(cp.encode_with(&utf16("日本"), &EncodeOptions { best_fit: true, default_char: Some(0xD800) }).bytes, b"??");
+
+    let cp = SingleByteCodePage::new(1251).unwrap();
+    assert_eq!(cp.encode_with(&utf16("Ā∞Ж"), &EncodeOptions::default()).bytes, b"A8\xC6");
+
+    let encoded = Utf7::Standard.encode_with(&utf16("∞"), &EncodeOptions::default());
+    assert_eq!(encoded.bytes, b"+Ih4-");
+    assert!(encoded.is_lossless());
+}
+
+#[cfg(feature = "codepage-dbcs")] #[test] fn encode_with_dbcs() {
+    let cp = DoubleByteCodePage::new(932).unwrap();
+    let units = "¥1∞€".encode_utf16().collect::<Vec<_>>();
+    assert_eq!(cp.encode_with(&units, &EncodeOptions::default()).bytes, b"\\1\x81\x87?");
+    assert_eq!(cp.encode_with(&units, &EncodeOptions { best_fit: false, default_char: Some(0x30FB) }).bytes, b"\x81\x451\x81\x87\x81\x45"); // '・'
+    assert_eq!(DoubleByteCodePage::new(949).unwrap().encode_with(&[0x20A9], &EncodeOptions::default()).bytes, b"\\"); // '₩'
+}
+
+#[cfg(windows)] #[cfg(feature = "bstr")] #[test] fn encode_bstr_with() {
+    let encoded = SingleByteCodePage::new(437).unwrap().encode_bstr_with(bstr!("x≤y"), &EncodeOptions::default());
+    assert_eq!(encoded.bytes, b"x\xF3y");
+    assert!(encoded.is_lossless());
+}
@@ -0,0 +1,243 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+
+const BASE64 : &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+
+
+/// [UTF-7] (`CP_UTF7`, code page `65000`), or the IMAP modified UTF-7 used for mailbox names.
+///
+/// [Utf7::Standard] matches Windows' `CP_UTF7` byte for byte:
+/// * Encoding writes `A-Z a-z 0-9 ' ( ) , - . / : ?`, space, tab, CR, LF, and `'\0'` directly, and `'+'` as `"+-"`.
+///   Everything else - including the "optionally direct" characters like `'!'` - is base64 encoded.
+/// * Base64 runs are always terminated with `'-'`, even when that's optional.
+/// * In [ConversionMode::Lossy], decoding never fails:  bytes `0x80..` decode to `U+0080..`, a trailing `'+'` is
+///   dropped, and leftover bits at the end of a base64 run are discarded.
+///
+/// [Utf7::Imap] is [RFC 3501]'s modified UTF-7:
+/// * `'&'` starts base64 runs (`"&-"` is a literal `'&'`), and `','` replaces `'/'` in the base64 alphabet.
+/// * Printable ASCII (`0x20..=0x7E`) other than `'&'` is written directly, everything else is base64 encoded.
+///
+/// [ConversionMode::Strict] rejects what [RFC 2152] (or [RFC 3501]) don't allow:  non-ASCII (or for [Utf7::Imap],
+/// non-printable) bytes outside of base64, empty or (for [Utf7::Imap]) unterminated base64 runs, and base64 runs with
+/// leftover bits.  Unpaired surrogates are allowed.  Encoding never fails.
+///
+/// Both variants report [CodePage::id] `65000`, although Windows has no IMAP variant.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// let units = "Hi Mom -☺-!".encode_utf16().collect::<Vec<_>>();
+/// assert_eq!(Utf7::Standard.encode(&units).unwrap(), b"Hi Mom -+Jjo--+ACE-");
+/// assert_eq!(Utf7::Standard.decode(b"Hi Mom -+Jjo--+ACE-").unwrap(), units);
+///
+/// let units = "~peter/mail/台北/日本語".encode_utf16().collect::<Vec<_>>();
+/// assert_eq!(Utf7::Imap.encode(&units).unwrap(), b"~peter/mail/&U,BTFw-/&ZeVnLIqe-");
+/// ```
+///
+/// [UTF-7]:    https://datatracker.ietf.org/doc/html/rfc2152
+/// [RFC 2152]: https://datatracker.ietf.org/doc/html/rfc2152
+/// [RFC 3501]: https://datatracker.ietf.org/doc/html/rfc3501#section-5.1.3
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] pub enum Utf7 {
+    /// [RFC 2152](https://datatracker.ietf.org/doc/html/rfc2152) UTF-7, as written by Windows' `CP_UTF7`.
+    Standard,
+
+    /// [RFC 3501](https://datatracker.ietf.org/doc/html/rfc3501#section-5.1.3) modified UTF-7, as used for IMAP mailbox names.
+    Imap,
+}
+
+impl Utf7 {
+    fn shift(self) -> u8 { match self { Utf7::Standard => b'+', Utf7::Imap => b'&' } }
+
+    /// Returns `true` if `unit` is written as part of a base64 run.
+    fn is_encoded(self, unit: u16) -> bool {
+        match self {
+            Utf7::Standard  => !matches!(unit, 0x00 | 0x09 | 0x0A | 0x0D | 0x20 | 0x27 ..= 0x29 | 0x2C ..= 0x3A | 0x3F | 0x41 ..= 0x5A | 0x61 ..= 0x7A),
+            Utf7::Imap      => !matches!(unit, 0x20 ..= 0x7E),
+        }
+    }
+
+    /// Returns `true` if `byte` is allowed outside of base64 runs in [ConversionMode::Strict].
+    fn is_direct_byte(self, byte: u8) -> bool {
+        match self {
+            Utf7::Standard  => byte < 0x80,
+            Utf7::Imap      => matches!(byte, 0x20 ..= 0x7E),
+        }
+    }
+
+    fn sextet(self, byte: u8) -> Option<u32> {
+        let sextet = match (self, byte) {
+            (_, b'A' ..= b'Z')      => byte - b'A',
+            (_, b'a' ..= b'z')      => byte - b'a' + 26,
+            (_, b'0' ..= b'9')      => byte - b'0' + 52,
+            (_, b'+')               => 62,
+            (Utf7::Standard, b'/')  => 63,
+            (Utf7::Imap, b',')      => 63,
+            _                       => return None,
+        };
+        Some(sextet.into())
+    }
+
+    fn sextet_byte(self, sextet: u32) -> u8 {
+        match (self, sextet) {
+            (Utf7::Imap, 63)    => b',',
+            _                   => BASE64[sextet as usize],
+        }
+    }
+}
+
+impl CodePage for Utf7 {
+    fn id(&self) -> u32 { 65000 }
+
+    fn decode_into(&self, bytes: &[u8], mode: ConversionMode, units: &mut Vec<u16>) -> Result<(), CodePageError> {
+        let strict = mode == ConversionMode::Strict;
+        let shift = self.shift();
+        units.reserve(bytes.len());
+        let mut index = 0;
+        while let Some(&byte) = bytes.get(index) {
+            if byte != shift {
+                if strict && !self.is_direct_byte(byte) { return Err(CodePageError::InvalidBytes { index }); }
+                units.push(byte.into());
+                index += 1;
+                continue;
+            }
+
+            let start = index;
+            index += 1;
+            match bytes.get(index) {
+                None if strict  => return Err(CodePageError::InvalidBytes { index: start }),
+                None            => break,
+                Some(b'-')      => { units.push(shift.into()); index += 1; continue; },
+                Some(_)         => {},
+            }
+
+            let (mut bits, mut nbits, mut terminated) = (0u32, 0, false);
+            while let Some(&byte) = bytes.get(index) {
+                if byte == b'-' { index += 1; terminated = true; break; }
+                let sextet = match self.sextet(byte) { Some(s) => s, None => break };
+                bits = (bits << 6) | sextet;
+                nbits += 6;
+                if nbits >= 16 {
+                    nbits -= 16;
+                    units.push((bits >> nbits) as u16);
+                    bits &= (1 << nbits) - 1;
+                }
+                index += 1;
+            }
+
+            let empty = index == start + 1 + usize::from(terminated);
+            if strict && (empty || nbits >= 6 || bits != 0 || (*self == Utf7::Imap && !terminated)) {
+                return Err(CodePageError::InvalidBytes { index: start });
+            }
+        }
+        Ok(())
+    }
+
+    fn encode_into(&self, units: &[u16], _mode: ConversionMode, bytes: &mut Vec<u8>) -> Result<(), CodePageError> {
+        let shift = self.shift();
+        bytes.reserve(units.len());
+        let mut index = 0;
+        while let Some(&unit) = units.get(index) {
+            index += 1;
+            if unit == u16::from(shift) {
+                bytes.extend_from_slice(&[shift, b'-']);
+                continue;
+            } else if !self.is_encoded(unit) {
+                bytes.push(unit as u8);
+                continue;
+            }
+
+            bytes.push(shift);
+            let (mut bits, mut nbits) = (u32::from(unit), 16);
+            loop {
+                while nbits >= 6 {
+                    nbits -= 6;
+                    bytes.push(self.sextet_byte((bits >> nbits) & 0x3F));
+                }
+                match units.get(index) {
+                    Some(&unit) if self.is_encoded(unit) => { bits = (bits << 16) | u32::from(unit); nbits += 16; index += 1; },
+                    _ => break,
+                }
+            }
+            if nbits > 0 { bytes.push(self.sextet_byte((bits << (6 - nbits)) & 0x3F)); }
+            bytes.push(b'-');
+        }
+        Ok(())
+    }
+}
+
+
+
+#[test] fn windows_corpus() {
+    let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
+    let corpus : &[(&[u16], &[u8])] = &[
+        (&[],                                           b""),
+        (&utf16("+"),                                   b"+-"),
+        (&utf16("a+b"),                                 b"a+-b"),
+        (&utf16("+!"),                                  b"+-+ACE-"),
+        (&utf16("!+"),                                  b"+ACEAKw-"),
+        (&utf16("Hello, World!"),                       b"Hello, World+ACE-"),
+        (&utf16("A≢Α."),                                b"A+ImIDkQ-."),
+        (&utf16("Hi Mom -☺-!"),                         b"Hi Mom -+Jjo--+ACE-"),
+        (&utf16("日本語"),                               b"+ZeVnLIqe-"),
+        (&utf16("\0\t\r\n '(),-./:?~\\"),               b"\0\t\r\n '(),-./:?+AH4AXA-"),
+        (&utf16("\u{1F600}"),                           b"+2D3eAA-"),
+        (&[0xD800, b'a' as u16],                        b"+2AA-a"),
+        (&[0x80, 0xFF],                                 b"+AIAA/w-"),
+    ];
+    for &(units, bytes) in corpus {
+        assert_eq!(Utf7::Standard.encode(units).unwrap(), bytes, "{:?}", String::from_utf16_lossy(units));
+        assert_eq!(Utf7::Standard.decode(bytes).unwrap(), units, "{:?}", String::from_utf8_lossy(bytes));
+    }
+    for unit in 0 ..= 0xFFFF {
+        assert_eq!(Utf7::Standard.decode(&Utf7::Standard.encode(&[unit]).unwrap()).unwrap(), [unit]);
+        assert_eq!(Utf7::Imap    .decode(&Utf7::Imap    .encode(&[unit]).unwrap()).unwrap(), [unit]);
+    }
+}
+
+#[test] fn lenient_decoding() {
+    let lossy = |bytes: &[u8]| String::from_utf16_lossy(&Utf7::Standard.decode_lossy(bytes));
+    assert_eq!(lossy(b"+"),             "");
+    assert_eq!(lossy(b"a+"),            "a");
+    assert_eq!(lossy(b"+!"),            "!");
+    assert_eq!(lossy(b"+T2B-"),         "你");          // 2 leftover bits
+    assert_eq!(lossy(b"+T2BZ"),         "你");          // unterminated, 8 leftover bits
+    assert_eq!(lossy(b"+T2BZfQ"),       "你好");
+    assert_eq!(lossy(b"+T2BZfQ!x"),     "你好!x");
+    assert_eq!(lossy(b"\x80\xFF"),      "\u{80}\u{FF}");
+
+    assert_eq!(Utf7::Standard.decode(b"a+"),        Err(CodePageError::InvalidBytes { index: 1 }));
+    assert_eq!(Utf7::Standard.decode(b"a+!"),       Err(CodePageError::InvalidBytes { index: 1 }));
+    assert_eq!(Utf7::Standard.decode(b"a+T2BZ-"),   Err(CodePageError::InvalidBytes { index: 1 }));
+    assert_eq!(Utf7::Standard.decode(b"a+T2C-"),    Err(CodePageError::InvalidBytes { index: 1 }));
+    assert_eq!(Utf7::Standard.decode(b"a\x80"),     Err(CodePageError::InvalidBytes { index: 1 }));
+    assert_eq!(Utf7::Standard.decode(b"+T2BZfQ!x").unwrap(), "你好!x".encode_utf16().collect::<Vec<_>>());
+}
+
+#[test] fn imap() {
+    let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
+    let corpus : &[(&str, &[u8])] = &[
+        ("INBOX",                       b"INBOX"),
+        ("Tom & Jerry",                 b"Tom &- Jerry"),
+        ("~peter/mail/台北/日本語",      b"~peter/mail/&U,BTFw-/&ZeVnLIqe-"),
+        ("Entwürfe",                    b"Entw&APw-rfe"),
+        ("a+b!",                        b"a+b!"),
+        ("\u{1F600}\t",                 b"&2D3eAAAJ-"),
+    ];
+    for &(s, bytes) in corpus {
+        assert_eq!(Utf7::Imap.encode(&utf16(s)).unwrap(), bytes, "{:?}", s);
+        assert_eq!(Utf7::Imap.decode(bytes).unwrap(), utf16(s), "{:?}", s);
+    }
+    assert_eq!(Utf7::Imap.decode(b"&U,BTFw"),       Err(CodePageError::InvalidBytes { index: 0 }));
+    assert_eq!(Utf7::Imap.decode(b"a\tb"),          Err(CodePageError::InvalidBytes { index: 1 }));
+    assert_eq!(Utf7::Imap.decode(b"&U/BTFw-"),     Err(CodePageError::InvalidBytes { index: 0 }));
+    assert_eq!(Utf7::Imap.decode_lossy(b"&U,BTFw"), utf16("台北"));
+}
+
+#[cfg(windows)] #[cfg(feature = "bstr")] #[test] fn bstrings() {
+    let s = Utf7::Standard.decode_bstring(b"+ZeVnLIqe-").unwrap();
+    assert_eq!(s, "日本語");
+    assert_eq!(Utf7::Standard.encode_bstr(&s).unwrap(), b"+ZeVnLIqe-");
+    assert_eq!(Utf7::Imap.encode_bstr(bstr!("&")).unwrap(), b"&-");
+}
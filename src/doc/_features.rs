@@ -2,12 +2,16 @@
 //!
 //! ### Default Features
 //!
-//! * `bstr` - enables [BStr], [BString], [AsBStrPtr], [AsOptBStrPtr], [IntoBStr], and [IntoOptBStr]
+//! * `bstr` - enables [BStr], [BString], [BStringMut], [SharedBString], [SecureBString], [BStrBuf], [SmallBString], [BStrInterner], [AsBStrPtr], [AsOptBStrPtr], [IntoBStr], [IntoSmallBStr], and [IntoOptBStr]
+//! * `wstr` - enables [wstr!], [WStr], [WString], [MultiWStr], [MultiWString], [EnvBlock], [split_command_line], [join_command_line], [WPath], [WBuf], [fill_wstring], [CoTaskWString], [LocalWString], [LocalWStrArray], [AllocWString], [ResourceName], [decode_reg_sz], [decode_reg_multi_sz], [encode_reg_sz], [encode_reg_multi_sz], [RegValueError], [AsWStrPtr], [AsOptWStrPtr], [IntoWStr], [IntoOptWStr], and [IntoResourceName]
+//! * `ustr` - enables [ustr!], [UnicodeStr], [UnicodeString], [AnsiStr], [AnsiString], [CountedStr], and [CountedString]
+//! * `hstr` - enables [hstr!], [HStr], [HString], [HStringBuffer], and [HStringRef]
+//! * `codepage` - enables [CodePage], [SingleByteCodePage], [Utf7], [ConversionMode], [EncodeOptions], [Encoded], [LossyUnit], and [CodePageError]
 //! * `display` - enables [Display](std::fmt::Display) on strings, despite the
 //! fact that they may contain invalid unicode which might be lossily converted.
 //!
 //! ### Opt-in Features
 //!
-//! * none yet
+//! * `codepage-dbcs` - enables [DoubleByteCodePage] for code pages 932, 936, 949, and 950, embedding ~460 KiB of conversion tables
 
 #[allow(unused_imports)] use crate::*;
@@ -0,0 +1,215 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Formatter};
+use std::iter::FromIterator;
+
+
+
+/// A sorted, case-insensitive set of environment variables, as used by `CreateProcessW(..., CREATE_UNICODE_ENVIRONMENT, lpEnvironment, ...)`
+/// and returned by `GetEnvironmentStringsW`.
+///
+/// ### Block Format
+///
+/// An environment block is a multi-string of `"NAME=VALUE"` entries, followed by an extra `0u16`.
+/// * Names are case-insensitive.  Duplicates keep the first entry when parsing, and the first name's casing when inserting.
+/// * Entries are sorted by name, comparing upcased [code unit]s without regard to locale (like `RtlCompareUnicodeString`.)
+/// * Names may start with `'='` - `cmd.exe` tracks per-drive working directories with entries like `"=C:=C:\Windows"`.
+/// * Names and values are arbitrary [u16]s: unpaired surrogates are preserved, not replaced.
+/// * An empty block is `"\0\0"`, not just `"\0"`.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// let mut env = EnvBlock::new();
+/// env.insert("Path",  "C:\\Windows");
+/// env.insert("=C:",   "C:\\Windows");
+/// env.insert("PATH",  "C:\\Windows\\System32"); // replaces "Path"'s value
+///
+/// assert_eq!(env.get("path").unwrap(), "C:\\Windows\\System32");
+/// assert_eq!(env.to_units00(), "=C:=C:\\Windows\0Path=C:\\Windows\\System32\0\0".encode_utf16().collect::<Vec<_>>());
+/// // CreateProcessW(..., CREATE_UNICODE_ENVIRONMENT, env.to_units00().as_ptr().cast(), ...)
+/// ```
+///
+/// [code unit]:    https://unicode.org/glossary/#code_unit
+#[derive(Clone, Default, PartialEq, Eq, Hash)] pub struct EnvBlock {
+    vars: Vec<(WString, WString)>, // sorted by `cmp_names`, no case-insensitive duplicates
+}
+
+impl Debug for EnvBlock {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { fmt.debug_map().entries(self.iter()).finish() }
+}
+
+impl<N: IntoWStr, V: IntoWStr> FromIterator<(N, V)> for EnvBlock {
+    fn from_iter<I: IntoIterator<Item = (N, V)>>(iter: I) -> Self { let mut env = Self::new(); env.extend(iter); env }
+}
+
+impl<N: IntoWStr, V: IntoWStr> Extend<(N, V)> for EnvBlock {
+    fn extend<I: IntoIterator<Item = (N, V)>>(&mut self, iter: I) { for (name, value) in iter { self.insert(name, value); } }
+}
+
+impl From<&MultiWStr> for EnvBlock { fn from(value: &MultiWStr) -> Self { Self::from_multiwstr(value) } }
+
+impl EnvBlock {
+    /// Create an empty environment block.
+    pub fn new() -> Self { Self::default() }
+
+    /// Parse a well formed environment block, such as one returned by `GetEnvironmentStringsW`.
+    ///
+    /// Entries without a `'='` (after the first [code unit]) are ignored.
+    /// Entries with a name matching a previous entry (case-insensitively) are ignored.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn from_multiwstr(block: &MultiWStr) -> Self {
+        let mut env = Self::new();
+        for entry in block.iter() {
+            let entry = entry.units();
+            let eq = match entry.iter().skip(1).position(|&u| u == u16::from(b'=')) { Some(eq) => eq + 1, None => continue };
+            let (name, value) = (&entry[..eq], &entry[eq+1..]);
+            if let Err(i) = env.search(name.iter().copied()) {
+                let name  = WString::from_code_units(name .iter().copied()).unwrap();
+                let value = WString::from_code_units(value.iter().copied()).unwrap();
+                env.vars.insert(i, (name, value));
+            }
+        }
+        env
+    }
+
+    /// Parse a (possibly malformed) environment block - see [MultiWStr::from_units_lossy] and [EnvBlock::from_multiwstr].
+    pub fn from_units_lossy(units: &[u16]) -> Self { Self::from_multiwstr(&MultiWStr::from_units_lossy(units)) }
+
+    /// The number of variables in the block.
+    pub fn len(&self) -> usize { self.vars.len() }
+
+    /// Returns `true` if the block contains no variables.
+    pub fn is_empty(&self) -> bool { self.vars.is_empty() }
+
+    /// Iterate over the `(name, value)`s of the block, in sorted order.
+    pub fn iter(&self) -> impl Iterator<Item = (&WStr, &WStr)> + '_ { self.vars.iter().map(|(n, v)| (&**n, &**v)) }
+
+    /// Get the value of the variable `name` (case-insensitive.)
+    pub fn get(&self, name: &(impl Utf16Hash + ?Sized)) -> Option<&WStr> {
+        let i = self.search(name.utf16_units()).ok()?;
+        Some(&self.vars[i].1)
+    }
+
+    /// Set the variable `name` to `value`, returning the previous value if any.
+    /// If the variable already exists, the original casing of `name` is kept.
+    ///
+    /// ### Panics
+    /// * If `name` is empty
+    /// * If `name` contains `'='`, except as the first character (e.g. `"=C:"`)
+    /// * If `name` or `value` contain `'\0'`
+    pub fn insert(&mut self, name: impl IntoWStr, value: impl IntoWStr) -> Option<WString> {
+        let name    = IntoWStr::into(name);
+        let name    = name.as_ref();
+        let value   = WString::from_wstr(IntoWStr::into(value).as_ref());
+        let units   = name.units();
+        assert!(!units.is_empty(), "EnvBlock::insert: environment variable names cannot be empty");
+        assert!(!units[1..].contains(&u16::from(b'=')), "EnvBlock::insert: environment variable names cannot contain '=' except as the first character");
+
+        match self.search(units.iter().copied()) {
+            Ok(i)   => Some(std::mem::replace(&mut self.vars[i].1, value)),
+            Err(i)  => { self.vars.insert(i, (WString::from_wstr(name), value)); None },
+        }
+    }
+
+    /// Remove the variable `name` (case-insensitive), returning its value if any.
+    pub fn remove(&mut self, name: &(impl Utf16Hash + ?Sized)) -> Option<WString> {
+        let i = self.search(name.utf16_units()).ok()?;
+        Some(self.vars.remove(i).1)
+    }
+
+    /// Encode the block as `"NAME=VALUE\0...\0\0"`, suitable for `CreateProcessW`'s `lpEnvironment` (with `CREATE_UNICODE_ENVIRONMENT`.)
+    ///
+    /// An empty block is encoded as `"\0\0"`.
+    pub fn to_units00(&self) -> Vec<u16> {
+        let mut units00 = Vec::new();
+        for (name, value) in self.vars.iter() {
+            units00.extend_from_slice(name.units());
+            units00.push(u16::from(b'='));
+            units00.extend_from_slice(value.units0());
+        }
+        if units00.is_empty() { units00.push(0); }
+        units00.push(0);
+        units00
+    }
+
+    fn search(&self, name: impl Iterator<Item = u16> + Clone) -> Result<usize, usize> {
+        self.vars.binary_search_by(|(n, _)| cmp_names(n.units().iter().copied(), name.clone()))
+    }
+}
+
+/// Compare environment variable names case-insensitively, without regard to locale.
+fn cmp_names(a: impl Iterator<Item = u16>, b: impl Iterator<Item = u16>) -> Ordering { a.map(upcase).cmp(b.map(upcase)) }
+
+/// Upcase a single [u16] unicode [code unit] with [simple_uppercase].  Surrogates and characters without a BMP uppercase mapping are unchanged.
+///
+/// [code unit]:    https://unicode.org/glossary/#code_unit
+fn upcase(unit: u16) -> u16 {
+    let ch = match std::char::from_u32(unit.into()) { Some(ch) => ch, None => return unit };
+    match simple_uppercase(ch) as u32 {
+        upper @ 0 ..= 0xFFFF    => upper as u16,
+        _                       => unit,
+    }
+}
+
+
+
+#[test] fn build() {
+    let mut env = EnvBlock::new();
+    assert_eq!(env.to_units00(), [0, 0]);
+    assert!(env.is_empty());
+
+    assert_eq!(env.insert("b", "2"), None);
+    assert_eq!(env.insert("A", "1"), None);
+    assert_eq!(env.insert("=C:", "C:\\"), None);
+    assert_eq!(env.insert("_", "3"), None);
+    assert_eq!(env.insert("B", "two").unwrap(), "2");
+    assert_eq!(env.len(), 4);
+
+    // '_' (0x5F) sorts after upcased letters, even though it sorts before lowercase ones
+    assert_eq!(env.to_units00(), "=C:=C:\\\0A=1\0b=two\0_=3\0\0".encode_utf16().collect::<Vec<_>>());
+    assert_eq!(env.iter().map(|(n, _)| n.to_string_lossy()).collect::<Vec<_>>(), ["=C:", "A", "b", "_"]);
+    assert_eq!(env.get("a").unwrap(), "1");
+    assert_eq!(env.get(&[b'B' as u16][..]).unwrap(), "two");
+    assert!(env.get("C:").is_none());
+
+    assert_eq!(env.remove("=c:").unwrap(), "C:\\");
+    assert_eq!(env.remove("=c:"), None);
+    assert_eq!(format!("{:?}", env), r#"{"A": "1", "b": "two", "_": "3"}"#);
+
+    let env = vec![("x", "1"), ("X", "2"), ("y", "")].into_iter().collect::<EnvBlock>();
+    assert_eq!(env.to_units00(), "x=2\0y=\0\0".encode_utf16().collect::<Vec<_>>());
+
+    let surrogate = [0xD800, b'=' as u16, 0xDC00, 0, 0];
+    let env = EnvBlock::from_units_lossy(&surrogate);
+    assert_eq!(env.to_units00(), surrogate);
+}
+
+#[test] #[should_panic] fn insert_bad_name() { EnvBlock::new().insert("A=B", "C"); }
+
+#[test] fn parse() {
+    let parse = |s: &str| EnvBlock::from_units_lossy(&s.encode_utf16().collect::<Vec<_>>());
+
+    let env = parse("=::=::\\\0=C:=C:\\Windows\0Path=C:\\Windows\0PATH=ignored\0NoEquals\0Empty=\0\0");
+    assert_eq!(env.len(), 4);
+    assert_eq!(env.get("=::").unwrap(), "::\\");
+    assert_eq!(env.get("=C:").unwrap(), "C:\\Windows");
+    assert_eq!(env.get("PATH").unwrap(), "C:\\Windows");
+    assert_eq!(env.get("empty").unwrap(), "");
+    assert!(env.get("NoEquals").is_none());
+
+    // unsorted input is sorted
+    let env = parse("b=2\0a=1\0");
+    assert_eq!(env.to_units00(), "a=1\0b=2\0\0".encode_utf16().collect::<Vec<_>>());
+    assert_eq!(env, EnvBlock::from(&*MultiWString::from_entries(vec!["a=1", "b=2"]).unwrap()));
+
+    assert!(parse("").is_empty());
+    assert!(parse("\0\0").is_empty());
+    assert_eq!(upcase(u16::from(b'a')), u16::from(b'A'));
+    assert_eq!(upcase(0xDF), 0xDF); // 'ß' upcases to "SS"
+    assert_eq!(upcase(0xD800), 0xD800);
+}
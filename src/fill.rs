@@ -0,0 +1,183 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+
+use std::io;
+
+const ERROR_INSUFFICIENT_BUFFER : u32 = 122;
+const ERROR_MORE_DATA           : u32 = 234;
+
+/// The largest buffer [fill_wstring] will try, in [u16]s:  `0x7FFF_FFFF` bytes.
+const MAX_FILL_LEN              : usize = 0x7FFF_FFFF / 2;
+
+
+
+/// The result of a single attempt to fill a buffer - see [fill_wstring].
+#[derive(Debug)] pub enum FillResult {
+    /// The buffer was large enough, and the string is the first `len` [u16] unicode [code unit]s of the buffer.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    Done(usize),
+
+    /// The buffer was too small, and at least `len` [u16] unicode [code unit]s are needed (e.g. `GetEnvironmentVariableW`)
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    Needed(usize),
+
+    /// The buffer was too small, but the required size is unknown (e.g. `GetModuleFileNameW`, `ERROR_INSUFFICIENT_BUFFER`)
+    TooSmall,
+
+    /// Filling the buffer failed for some other reason
+    Err(io::Error),
+}
+
+impl FillResult {
+    /// Interpret the return value of APIs like `GetEnvironmentVariableW`, `GetCurrentDirectoryW`, or `GetTempPathW`,
+    /// which return the string length on success, or the required buffer size (including the terminal `0u16`) if the buffer was too small.
+    ///
+    /// Note that `0` is returned by such APIs on error *and* for empty strings - check `GetLastError` first if that distinction matters.
+    pub fn from_len_or_needed(ret: u32, buf: &[u16]) -> Self {
+        let ret = ret as usize;
+        if ret < buf.len() { FillResult::Done(ret) } else { FillResult::Needed(ret) }
+    }
+
+    /// Interpret the return value of APIs like `GetModuleFileNameW`, which silently truncate the string, returning
+    /// the buffer size when they do so.
+    pub fn from_truncated_len(ret: u32, buf: &[u16]) -> Self {
+        let ret = ret as usize;
+        if ret < buf.len() { FillResult::Done(ret) } else { FillResult::TooSmall }
+    }
+
+    /// Interpret a Win32 error code (e.g. from `GetLastError` or `RegQueryValueExW`.)
+    /// `ERROR_INSUFFICIENT_BUFFER` and `ERROR_MORE_DATA` become [FillResult::TooSmall], anything else becomes [FillResult::Err].
+    pub fn from_win32_error(err: u32) -> Self {
+        match err {
+            ERROR_INSUFFICIENT_BUFFER | ERROR_MORE_DATA => FillResult::TooSmall,
+            err => FillResult::Err(io::Error::from_raw_os_error(err as i32)),
+        }
+    }
+}
+
+
+
+/// Repeatedly call `fill` with larger and larger buffers until the string fits, then copy it into a [WString].
+///
+/// * The first attempt uses a [MAX_PATH] sized stack buffer, so most calls don't allocate until the final [WString].
+/// * [FillResult::Needed] retries with exactly that many [code unit]s, [FillResult::TooSmall] retries with twice as many.
+/// * A single trailing `0u16` within [FillResult::Done]'s length is ignored, for APIs that count the terminator.
+/// * Interior `0u16`s result in an [io::ErrorKind::InvalidData] error.
+/// * Buffers are never grown past `0x7FFF_FFFF` bytes, and failing to allocate one is an error rather than an abort.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// # let long_path = format!(r"\\?\C:\{}\app.exe", "dir".repeat(100));
+/// # #[allow(non_snake_case)] let GetModuleFileNameW = |buf: &mut [u16]| {
+/// #     let path = long_path.encode_utf16().collect::<Vec<_>>();
+/// #     let n = path.len().min(buf.len());
+/// #     buf[..n].copy_from_slice(&path[..n]);
+/// #     n as u32
+/// # };
+/// let path = fill_wstring(|buf| FillResult::from_truncated_len(GetModuleFileNameW(buf), buf)).unwrap();
+/// assert_eq!(path, long_path);
+/// ```
+///
+/// [code unit]:    https://unicode.org/glossary/#code_unit
+pub fn fill_wstring(fill: impl FnMut(&mut [u16]) -> FillResult) -> io::Result<WString> {
+    fill_with(fill, |units| WString::from_code_units(units.iter().copied()).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "string contains interior '\\0'")))
+}
+
+/// Repeatedly call `fill` with larger and larger buffers until the string fits, then copy it into a [BString].
+///
+/// Like [fill_wstring], except interior `0u16`s are allowed.
+#[cfg(windows)] #[cfg(feature = "bstr")]
+pub fn fill_bstring(fill: impl FnMut(&mut [u16]) -> FillResult) -> io::Result<BString> {
+    fill_with(fill, |units| BString::from_code_units(units.iter().copied()).ok_or_else(|| io::Error::other("unable to allocate BString")))
+}
+
+fn fill_with<T>(mut fill: impl FnMut(&mut [u16]) -> FillResult, finish: impl FnOnce(&[u16]) -> io::Result<T>) -> io::Result<T> {
+    let mut stack = [0u16; MAX_PATH];
+    let mut heap = Vec::new();
+    loop {
+        let buf = if heap.is_empty() { &mut stack[..] } else { &mut heap[..] };
+        let len = buf.len();
+        let next = match fill(buf) {
+            FillResult::Done(n) if n > len  => return Err(io::Error::new(io::ErrorKind::InvalidData, "filled length exceeds buffer length")),
+            FillResult::Done(n)             => {
+                let units = &buf[..n];
+                let units = if units.last() == Some(&0) { &units[..n-1] } else { units };
+                return finish(units);
+            },
+            FillResult::Needed(n) if n > len    => n,
+            FillResult::Needed(_)               => (len * 2).min(MAX_FILL_LEN).max(len + 1),
+            FillResult::TooSmall                => (len * 2).min(MAX_FILL_LEN).max(len + 1),
+            FillResult::Err(err)                => return Err(err),
+        };
+        if next > MAX_FILL_LEN { return Err(io::Error::other("required buffer size exceeds 0x7FFF_FFFF bytes")); }
+        heap = Vec::new();
+        heap.try_reserve_exact(next).map_err(|_| io::Error::other("unable to allocate buffer"))?;
+        heap.resize(next, 0);
+    }
+}
+
+
+
+#[test] fn conventions() {
+    fn source(n: usize) -> Vec<u16> { (0 .. n).map(|i| b'a' as u16 + (i % 26) as u16).collect() }
+
+    for &n in &[0, 1, MAX_PATH-1, MAX_PATH, MAX_PATH+1, 10000] {
+        let s = source(n);
+
+        // GetEnvironmentVariableW style
+        let mut calls = 0;
+        let r = fill_wstring(|buf| {
+            calls += 1;
+            if s.len() < buf.len() { buf[..s.len()].copy_from_slice(&s); buf[s.len()] = 0; FillResult::from_len_or_needed(s.len() as u32, buf) }
+            else { FillResult::from_len_or_needed(s.len() as u32 + 1, buf) }
+        }).unwrap();
+        assert_eq!(r.units(), &s[..]);
+        assert_eq!(calls, if n < MAX_PATH { 1 } else { 2 });
+
+        // GetModuleFileNameW style
+        let r = fill_wstring(|buf| {
+            let len = s.len().min(buf.len());
+            buf[..len].copy_from_slice(&s[..len]);
+            FillResult::from_truncated_len(len as u32, buf)
+        }).unwrap();
+        assert_eq!(r.units(), &s[..]);
+
+        // QueryFullProcessImageNameW style
+        let r = fill_wstring(|buf| {
+            if s.len() >= buf.len() { return FillResult::from_win32_error(ERROR_INSUFFICIENT_BUFFER); }
+            buf[..s.len()].copy_from_slice(&s);
+            FillResult::Done(s.len())
+        }).unwrap();
+        assert_eq!(r.units(), &s[..]);
+
+        // ExpandEnvironmentStringsW style (counts the terminator)
+        let r = fill_wstring(|buf| {
+            if s.len() >= buf.len() { return FillResult::Needed(s.len() + 1); }
+            buf[..s.len()].copy_from_slice(&s);
+            buf[s.len()] = 0;
+            FillResult::Done(s.len() + 1)
+        }).unwrap();
+        assert_eq!(r.units(), &s[..]);
+    }
+}
+
+#[test] fn errors() {
+    assert_eq!(fill_wstring(|_| FillResult::from_win32_error(5)).unwrap_err().raw_os_error(), Some(5));
+    assert_eq!(fill_wstring(|buf| FillResult::Done(buf.len() + 1)).unwrap_err().kind(), io::ErrorKind::InvalidData);
+    assert_eq!(fill_wstring(|buf| { buf[0] = 0; buf[1] = 1; FillResult::Done(2) }).unwrap_err().kind(), io::ErrorKind::InvalidData);
+    assert_eq!(fill_wstring(|_| FillResult::Needed(u32::MAX as usize + 1)).unwrap_err().kind(), io::ErrorKind::Other);
+    assert_eq!(fill_wstring(|_| FillResult::Needed(MAX_FILL_LEN + 1)).unwrap_err().kind(), io::ErrorKind::Other);
+
+    let mut sizes = Vec::new();
+    let _ = fill_wstring(|buf| { sizes.push(buf.len()); if sizes.len() < 3 { FillResult::Needed(0) } else { FillResult::Done(0) } });
+    assert_eq!(sizes, [MAX_PATH, MAX_PATH * 2, MAX_PATH * 4]); // misreported sizes still make progress
+}
+
+#[cfg(windows)] #[cfg(feature = "bstr")] #[test] fn bstring() {
+    let r = fill_bstring(|buf| { buf[..3].copy_from_slice(&[b'a' as u16, 0, b'b' as u16]); FillResult::Done(3) }).unwrap();
+    assert_eq!(r.units(), [b'a' as u16, 0, b'b' as u16]);
+}
@@ -0,0 +1,19 @@
+#[cfg(doc)]
+#[macro_export]
+/// Create a &amp;'static [HStringRef] literal (a static, fast-pass `HSTRING` header) at compile time
+macro_rules! hstr {
+    ( $string:literal ) => {
+        $crate::winstr_macros::hstr_impl!(($crate) $string)
+    };
+}
+
+#[cfg(not(doc))] // use wildcards for better error messages from proc macro
+#[macro_export]
+macro_rules! hstr {
+    ( $($tt:tt)+ ) => {
+        $crate::winstr_macros::hstr_impl!(($crate) $($tt)+)
+    };
+}
+
+mod danger; pub use danger::*;
+mod safer;
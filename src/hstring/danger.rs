@@ -0,0 +1,495 @@
+// The dangerous bits of the HSTRING wrappers.  Only code within this module has
+// access to `self.0`, hopefully making it simpler to audit access / verify
+// struct invariants are held.
+
+#![allow(unsafe_code)]
+
+#[cfg(windows)] use winapi::winrt::hstring::HSTRING;
+#[cfg(not(windows))] #[allow(clippy::upper_case_acronyms)] type HSTRING = *mut std::ffi::c_void;
+
+use std::convert::{TryFrom, TryInto};
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::ops::{Deref, Drop};
+use std::ptr::{null_mut, NonNull};
+use std::sync::atomic::{fence, AtomicI32, Ordering};
+
+
+
+/// ### Header Invariants
+/// An `HSTRING` is a pointer to a header - either a fast-pass header (`HSTRING_HEADER`, flagged as a reference, pointing
+/// at borrowed data) or the start of a refcounted heap allocation:
+/// * The header contains the length of the string in [code unit]s, excluding the terminal `0u16`
+/// * The header contains a pointer to the string's [code unit]s, which are always followed by a terminal `0u16`
+/// * Strings may contain interior `0u16`s
+/// * Heap allocated strings are followed by a 32-bit refcount, and then the string's [code unit]s
+/// * `HSTRING`s are immutable once created
+///
+/// ### Pointer Invariants
+/// * Null `HSTRING`s are valid, and equivalent to empty strings.
+/// * **Rust's &[HStr]s are never null** (empty &[HStr]s point to a static, empty, fast-pass header.)
+/// * Rust's [HString]s *can* be null (an empty [HString] doesn't allocate.)
+///
+/// [code unit]:    https://unicode.org/glossary/#code_unit
+mod invariants {}
+
+/// `HSTRING_HEADER_INTERNAL` - matches the layout `combase.dll` uses (and `HSTRING_HEADER`'s size.)
+#[repr(C)] struct Header {
+    flags:      u32,
+    len:        u32,
+    padding1:   u32,
+    padding2:   u32,
+    data:       *const u16,
+}
+
+/// `STRING_OPAQUE` - the start of a heap allocated `HSTRING`.  The string's [u16] code units immediately follow `count`.
+#[repr(C)] struct Shared {
+    header:     Header,
+    count:      AtomicI32,
+}
+
+const REFERENCE_FLAG : u32 = 1;
+const BUFFER_OFFSET : usize = size_of::<Header>() + size_of::<AtomicI32>();
+
+static EMPTY_UNITS0 : [u16; 1] = [0];
+static EMPTY : HStr = HStr(Header { flags: REFERENCE_FLAG, len: 0, padding1: 0, padding2: 0, data: &EMPTY_UNITS0 as *const [u16; 1] as *const u16 });
+
+fn alloc_size(len: u32) -> usize { BUFFER_OFFSET + (len as usize + 1) * size_of::<u16>() }
+
+/// Allocate a refcounted `HSTRING` of `len` code units, initialized to `0u16`s, with a refcount of 1.
+fn alloc(len: usize) -> Option<NonNull<Shared>> {
+    let len : u32 = len.try_into().ok().filter(|&len| len < u32::MAX)?;
+    let size = alloc_size(len);
+
+    #[cfg(windows)] let shared = unsafe {
+        use winapi::um::heapapi::{GetProcessHeap, HeapAlloc};
+        use winapi::um::winnt::HEAP_ZERO_MEMORY;
+        // `combase.dll` frees `HSTRING`s with the process heap - we must allocate with it too.
+        NonNull::new(HeapAlloc(GetProcessHeap(), HEAP_ZERO_MEMORY, size) as *mut Shared)?
+    };
+
+    #[cfg(not(windows))] let shared = unsafe {
+        NonNull::new(std::alloc::alloc_zeroed(std::alloc::Layout::from_size_align(size, std::mem::align_of::<Shared>()).ok()?) as *mut Shared)?
+    };
+
+    unsafe {
+        // Don't `ptr::write` a whole `Shared`:  it's tail padding overlaps the first code unit at `BUFFER_OFFSET`.
+        let data = (shared.as_ptr() as *mut u8).add(BUFFER_OFFSET) as *mut u16;
+        std::ptr::write(std::ptr::addr_of_mut!((*shared.as_ptr()).header), Header { flags: 0, len, padding1: 0, padding2: 0, data });
+        std::ptr::write(std::ptr::addr_of_mut!((*shared.as_ptr()).count),  AtomicI32::new(1));
+    }
+    Some(shared)
+}
+
+/// ### Safety
+/// * `shared` must have been allocated by [alloc], and be unreferenced.
+unsafe fn free(shared: NonNull<Shared>) {
+    #[cfg(windows)] {
+        use winapi::um::heapapi::{GetProcessHeap, HeapFree};
+        HeapFree(GetProcessHeap(), 0, shared.as_ptr().cast());
+    }
+
+    #[cfg(not(windows))] {
+        let size = alloc_size((*shared.as_ptr()).header.len);
+        std::alloc::dealloc(shared.as_ptr().cast(), std::alloc::Layout::from_size_align_unchecked(size, std::mem::align_of::<Shared>()));
+    }
+}
+
+
+
+/// `HString` is an owned, immutable, refcounted [HSTRING] (32-bit length prefixed [UTF-16]ish string.)
+///
+/// Cloning an `HString` only increments a refcount, just like `WindowsDuplicateString`.
+///
+/// [HSTRING]:  https://docs.microsoft.com/en-us/windows/win32/winrt/hstring
+/// [UTF-16]:   https://en.wikipedia.org/wiki/UTF-16
+#[repr(transparent)] pub struct HString(Option<NonNull<Shared>>);
+
+/// `HString`'s refcount is atomic (`combase.dll` uses `InterlockedIncrement`/`InterlockedDecrement`), and it's contents
+/// are immutable, so sending or sharing `HString`s between threads is fine.
+unsafe impl Send for HString {}
+unsafe impl Sync for HString {}
+
+impl Clone for HString {
+    fn clone(&self) -> Self { self.deref().duplicate() }
+}
+
+impl Deref for HString {
+    type Target = HStr;
+    fn deref(&self) -> &HStr {
+        match self.0 {
+            Some(shared)    => unsafe { &*(shared.as_ptr() as *const HStr) },
+            None            => &EMPTY,
+        }
+    }
+}
+
+impl Drop for HString {
+    fn drop(&mut self) {
+        let shared = match self.0 { Some(s) => s, None => return };
+        let s = unsafe { shared.as_ref() };
+        if s.header.flags & REFERENCE_FLAG != 0 { return; } // Not ours to free
+        if s.count.fetch_sub(1, Ordering::Release) != 1 { return; }
+        fence(Ordering::Acquire);
+        unsafe { free(shared) };
+    }
+}
+
+impl HString {
+    /// Create an empty `HString` (a null `HSTRING`.)  This doesn't allocate.
+    pub const fn new() -> Self { Self(None) }
+
+    /// Create an owned, refcounted `HString` from 0 or more [u16] unicode code points.  Interior `0u16`s are allowed.
+    ///
+    /// If `code_units` misreports its [ExactSizeIterator::len], the string is padded with `0u16`s or truncated to that length.
+    ///
+    /// Returns [None] if allocation fails, or if `code_units` contains `u32::MAX` or more code units.
+    pub fn from_code_units(mut code_units: impl ExactSizeIterator<Item = u16>) -> Option<HString> {
+        let len = code_units.len();
+        if len == 0 { return Some(Self::new()); }
+        let shared = alloc(len)?;
+        let hstring = Self(Some(shared)); // free on panic
+
+        let data = unsafe { (*shared.as_ptr()).header.data as *mut u16 };
+        for i in 0..len {
+            // Safe: `alloc` reserved `len` code units (+ a terminal `0u16`) at `data`
+            unsafe { *data.add(i) = code_units.next().unwrap_or(0u16); }
+        }
+        Some(hstring)
+    }
+
+    /// Takes ownership of an [HSTRING].  `hstring` may be null (an empty string.)
+    ///
+    /// ### Safety
+    ///
+    /// * `hstring` must be null, or a valid `HSTRING` that the caller owns a reference to (e.g. from `WindowsCreateString`
+    ///   or an `HSTRING*` out parameter.)
+    /// * `hstring`'s heap allocation layout must match `combase.dll`'s, which is true for all `HSTRING`s created by
+    ///   `combase.dll` or this crate.
+    ///
+    /// [HSTRING]:  https://docs.microsoft.com/en-us/windows/win32/winrt/hstring
+    pub unsafe fn from_hstring(hstring: HSTRING) -> Self { Self(NonNull::new(hstring as *mut Shared)) }
+
+    /// Releases ownership of the underlying [HSTRING] (e.g. to return it via an `HSTRING*` out parameter.)
+    ///
+    /// [HSTRING]:  https://docs.microsoft.com/en-us/windows/win32/winrt/hstring
+    pub fn into_hstring(self) -> HSTRING {
+        let hstring = self.0.map_or(null_mut(), |s| s.as_ptr());
+        std::mem::forget(self);
+        hstring as HSTRING
+    }
+}
+
+
+
+/// `&HStr` is a non-null, borrowed, [HSTRING] - a pointer to either a refcounted heap allocation, or a fast-pass
+/// [HStringRef] header.  Unlike &[str], this is **not** a [DST]!
+///
+/// [DST]:      https://doc.rust-lang.org/nomicon/exotic-sizes.html#dynamically-sized-types-dsts
+/// [HSTRING]:  https://docs.microsoft.com/en-us/windows/win32/winrt/hstring
+#[repr(transparent)] pub struct HStr(Header);
+
+/// `&HStr`s are immutable, and reading them from multiple threads is fine.
+unsafe impl Send for HStr {}
+unsafe impl Sync for HStr {}
+
+impl HStr {
+    /// Converts a &amp;[HSTRING] into a &amp;[HStr].  A null `HSTRING` is an empty `&HStr`.
+    /// By requiring a reference, this API [bounds] &amp;[HStr]'s lifetime, helping avoid bugs.
+    ///
+    /// ### Safety
+    ///
+    /// * `hstring` must be null, or a valid `HSTRING` for the duration of `&HStr`'s lifetime.
+    ///
+    /// [HSTRING]:  https://docs.microsoft.com/en-us/windows/win32/winrt/hstring
+    /// [bounds]:   https://doc.rust-lang.org/nomicon/unbounded-lifetimes.html
+    pub unsafe fn from_hstring(hstring: &HSTRING) -> &HStr { Self::from_hstring_unbounded(*hstring) }
+
+    /// Converts an [HSTRING] into a &amp;[HStr].  A null `HSTRING` is an empty `&HStr`.
+    ///
+    /// ### Safety
+    ///
+    /// * `hstring` must be null, or a valid `HSTRING` for the duration of `&HStr`'s lifetime.
+    /// * <span style="color: red">**&HStr's lifetime is [unbounded], an easy source of bugs.  Prefer [from_hstring]!**</span>
+    ///
+    /// [HSTRING]:      https://docs.microsoft.com/en-us/windows/win32/winrt/hstring
+    /// [unbounded]:    https://doc.rust-lang.org/nomicon/unbounded-lifetimes.html
+    /// [from_hstring]: #method.from_hstring
+    pub unsafe fn from_hstring_unbounded<'h>(hstring: HSTRING) -> &'h HStr {
+        (hstring as *const HStr).as_ref().unwrap_or(&EMPTY)
+    }
+
+    /// The `&HStr` as a winapi-friendly [HSTRING].  Empty strings are returned as null.
+    ///
+    /// ### Safety
+    ///
+    /// * It is **not** safe to modify the contents of the string through the returned handle!
+    ///
+    /// [HSTRING]:  https://docs.microsoft.com/en-us/windows/win32/winrt/hstring
+    pub fn as_hstring(&self) -> HSTRING {
+        if self.0.len == 0 { null_mut() } else { self as *const HStr as HSTRING }
+    }
+
+    /// Returns `true` if this is a fast-pass string (an [HStringRef] header, rather than a refcounted allocation.)
+    pub fn is_fast_pass(&self) -> bool { self.0.flags & REFERENCE_FLAG != 0 }
+
+    /// 32-bit length in [u16] unicode [code unit]s, excluding the terminal `0u16`
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn len32(&self) -> u32 { self.0.len }
+
+    /// The [u16] unicode [code unit]s of the string, including the terminal `0u16`
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn units0(&self) -> &[u16] { unsafe { std::slice::from_raw_parts(self.0.data, self.0.len as usize + 1) } }
+
+    /// Create an owned [HString] from this `&HStr`, like `WindowsDuplicateString`:  refcounted strings merely have their
+    /// refcount incremented, fast-pass strings are copied.
+    pub fn duplicate(&self) -> HString {
+        if self.0.len == 0 { return HString::new(); }
+        if self.is_fast_pass() { return HString::from_code_units(self.units0()[..self.0.len as usize].iter().copied()).expect("out of memory"); }
+        let shared = self as *const HStr as *mut Shared;
+        unsafe { &*shared }.count.fetch_add(1, Ordering::Relaxed);
+        HString(NonNull::new(shared))
+    }
+}
+
+
+
+/// `HStringRef` is a fast-pass [HSTRING] header referencing borrowed, `0u16`-terminated, [UTF-16]ish data - the
+/// equivalent of `WindowsCreateStringReference`, without any allocation or fallible system calls.
+///
+/// The header must outlive any `HSTRING` derived from it - pass `s.as_hstring()` to functions directly, but don't stash it.
+///
+/// [HSTRING]:  https://docs.microsoft.com/en-us/windows/win32/winrt/hstring
+/// [UTF-16]:   https://en.wikipedia.org/wiki/UTF-16
+#[repr(transparent)] pub struct HStringRef<'s>(HStr, PhantomData<&'s [u16]>);
+
+impl Deref for HStringRef<'_> {
+    type Target = HStr;
+    fn deref(&self) -> &HStr { &self.0 }
+}
+
+impl<'s> HStringRef<'s> {
+    #[doc(hidden)]
+    pub const fn hstr_macro_impl_detail(data: &'static [u16]) -> HStringRef<'static> {
+        if data.is_empty() || data[data.len()-1] != 0 || data.len() > u32::MAX as usize { panic!("`data` was supposed to be `\\0`-terminated"); }
+        HStringRef(HStr(Header { flags: REFERENCE_FLAG, len: data.len() as u32 - 1, padding1: 0, padding2: 0, data: data.as_ptr() }), PhantomData)
+    }
+
+    /// Reference `units0` with a fast-pass header.  Interior `0u16`s are allowed.
+    ///
+    /// Returns [None] unless `units0` ends with a `0u16`, or if `units0` is `u32::MAX` or more code units long.
+    pub fn from_units0(units0: &'s [u16]) -> Option<Self> {
+        let (&last, units) = units0.split_last()?;
+        if last != 0 { return None; }
+        let len : u32 = units.len().try_into().ok()?;
+        Some(HStringRef(HStr(Header { flags: REFERENCE_FLAG, len, padding1: 0, padding2: 0, data: units0.as_ptr() }), PhantomData))
+    }
+}
+
+
+
+/// `HStringBuffer` is an owned, mutable, preallocated [HSTRING] - the equivalent of `WindowsPreallocateStringBuffer`.
+///
+/// Fill it in via [units_mut](Self::units_mut), then [promote](Self::promote) it into an immutable [HString] without
+/// copying (`WindowsPromoteStringBuffer`.)  Dropping an unpromoted buffer frees it (`WindowsDeleteStringBuffer`.)
+///
+/// This mirrors [BStringMut](crate::BStringMut) / [BString](crate::BString):
+///
+/// ```
+/// # use winstr::*;
+/// let mut s = HStringBuffer::new(7).unwrap();
+/// s.units_mut().copy_from_slice(&"mutable".encode_utf16().collect::<Vec<_>>());
+/// s.units_mut()[0] = b'M' as u16;
+/// let s : HString = s.promote();
+/// assert_eq!(s, "Mutable");
+/// ```
+///
+/// Until promoted, a buffer can't be borrowed as an [HStr] - [HStr::duplicate] would share contents that can still change:
+///
+/// ```compile_fail
+/// # use winstr::*;
+/// let mut b = HStringBuffer::new(3).unwrap();
+/// let d = b.duplicate();
+/// let s = d.units();
+/// b.units_mut()[0] = 1;
+/// assert_eq!(s[0], 1);
+/// ```
+///
+/// [HSTRING]:  https://docs.microsoft.com/en-us/windows/win32/winrt/hstring
+#[repr(transparent)] pub struct HStringBuffer(HString);
+
+// HStringBuffer is Send and Sync like the HString it wraps:  all mutation goes through `&mut self`, and the buffer is
+// never shared until promoted.  This is also why it doesn't `Deref` to `HStr`:  `HStr::duplicate` would share it.
+
+impl From<HStringBuffer> for HString {
+    fn from(value: HStringBuffer) -> Self { value.0 }
+}
+
+/// Converts an [HString] back into a buffer without copying, if it's the only reference to a refcounted allocation.
+/// Returns the original string otherwise (shared or fast-pass strings must never be mutated.)
+impl TryFrom<HString> for HStringBuffer {
+    type Error = HString;
+    fn try_from(value: HString) -> Result<Self, HString> {
+        let unique = match value.0 {
+            None            => true, // empty
+            Some(shared)    => {
+                let s = unsafe { shared.as_ref() };
+                s.header.flags & REFERENCE_FLAG == 0 && s.count.load(Ordering::Acquire) == 1
+            },
+        };
+        if unique { Ok(Self(value)) } else { Err(value) }
+    }
+}
+
+impl HStringBuffer {
+    /// Allocate an [HStringBuffer] of `len` `0u16`s, to be filled in via [units_mut](Self::units_mut).
+    ///
+    /// Returns [None] if allocation fails, or if `len` is `u32::MAX` or larger.
+    pub fn new(len: usize) -> Option<Self> {
+        if len == 0 { return Some(Self(HString::new())); }
+        alloc(len).map(|shared| Self(HString(Some(shared))))
+    }
+
+    /// The [u16] unicode [code unit]s of the string, excluding the terminal `0u16`
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn units_mut(&mut self) -> &mut [u16] {
+        let shared = match (self.0).0 { Some(s) => s, None => return &mut [] };
+        // Safe: `self` has exclusive ownership of the refcounted allocation, terminal `0u16` excluded
+        unsafe {
+            let header = &(*shared.as_ptr()).header;
+            std::slice::from_raw_parts_mut(header.data as *mut u16, header.len as usize)
+        }
+    }
+
+    /// The [u16] unicode [code unit]s of the string, including the terminal `0u16`
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn units0(&self) -> &[u16] { self.0.units0() }
+
+    /// The buffer as a winapi-friendly [HSTRING].  Empty buffers are returned as null.
+    ///
+    /// ### Safety
+    ///
+    /// * It is **not** safe to modify the contents of the string through the returned handle!
+    /// * It is **not** safe to duplicate the returned handle (e.g. with `WindowsDuplicateString`) - promote the buffer first.
+    ///
+    /// [HSTRING]:  https://docs.microsoft.com/en-us/windows/win32/winrt/hstring
+    pub fn as_hstring(&self) -> HSTRING { self.0.as_hstring() }
+
+    /// Convert into an immutable [HString] without copying, like `WindowsPromoteStringBuffer`.
+    pub fn promote(self) -> HString { self.0 }
+}
+
+
+
+#[test] fn layout() {
+    use std::mem::align_of;
+
+    // HSTRING_HEADER: union { PVOID Reserved1; char Reserved2[20 or 24]; }
+    let ptr = size_of::<*const u16>();
+    assert_eq!(size_of::<HStr>(),           16 + ptr);
+    assert_eq!(align_of::<HStr>(),          align_of::<*const u16>());
+    assert_eq!(size_of::<HStringRef>(),     size_of::<HStr>());
+    assert_eq!(size_of::<HString>(),        ptr);
+    assert_eq!(size_of::<Option<&HStr>>(),  ptr);
+    assert_eq!(BUFFER_OFFSET,               20 + ptr);
+    #[cfg(windows)] assert_eq!(size_of::<HStr>(),   size_of::<winapi::winrt::hstring::HSTRING_HEADER>());
+    #[cfg(windows)] assert_eq!(align_of::<HStr>(),  align_of::<winapi::winrt::hstring::HSTRING_HEADER>());
+
+    let units0 = [b'a' as u16, b'b' as u16, 0];
+    let r = HStringRef::from_units0(&units0).unwrap();
+    let base = &r.0 as *const HStr as usize;
+    assert_eq!(&r.0 .0.flags    as *const u32 as usize - base, 0);
+    assert_eq!(&r.0 .0.len      as *const u32 as usize - base, 4);
+    assert_eq!(&r.0 .0.data     as *const *const u16 as usize - base, 16);
+    assert_eq!((r.0 .0.flags, r.0 .0.len, r.0 .0.data), (REFERENCE_FLAG, 2, units0.as_ptr()));
+
+    let h = HString::from_code_units([b'a' as u16, b'b' as u16].iter().copied()).unwrap();
+    let shared = h.0.unwrap().as_ptr() as usize;
+    let hs : &HStr = &h;
+    assert_eq!(hs.0.flags, 0);
+    assert_eq!(hs.0.data as usize, shared + BUFFER_OFFSET);
+    assert_eq!(unsafe { *((shared + size_of::<Header>()) as *const i32) }, 1); // refcount
+    assert_eq!(h.units0(), &units0[..]);
+}
+
+#[test] fn refcounting() {
+    let count = |h: &HString| unsafe { h.0.unwrap().as_ref() }.count.load(Ordering::Relaxed);
+
+    let a = HString::from_code_units([b'a' as u16, b'b' as u16, b'c' as u16].iter().copied()).unwrap();
+    let b = a.clone();
+    assert_eq!(a.as_hstring(), b.as_hstring());
+    assert_eq!(count(&a), 2);
+    let c = a.duplicate();
+    assert_eq!(count(&a), 3);
+    drop(b);
+    drop(c);
+    assert_eq!(count(&a), 1);
+
+    let raw = a.into_hstring();
+    let a = unsafe { HString::from_hstring(raw) };
+    assert_eq!(a.units0(), &[b'a' as u16, b'b' as u16, b'c' as u16, 0]);
+
+    let units0 = [b'x' as u16, 0];
+    let r = HStringRef::from_units0(&units0).unwrap();
+    let d = r.duplicate(); // fast-pass strings are copied
+    assert_ne!(d.as_hstring(), r.as_hstring());
+    assert!(!d.is_fast_pass());
+    assert_eq!(d.units0(), &units0);
+
+    let e = HString::new();
+    assert!(e.as_hstring().is_null());
+    assert!(e.clone().as_hstring().is_null());
+    assert_eq!(e.units0(), &[0]);
+    assert!(unsafe { HStr::from_hstring(&null_mut()) }.units0() == [0]);
+    assert!(HString::from_code_units(std::iter::empty()).unwrap().as_hstring().is_null());
+}
+
+#[test] fn thread_safety() {
+    let a = HString::from_code_units([b'a' as u16, b'b' as u16, b'c' as u16].iter().copied()).unwrap();
+    let threads = (0..4).map(|_| { let a = a.clone(); std::thread::spawn(move || for _ in 0..1000 { drop(a.clone()); }) }).collect::<Vec<_>>();
+    for t in threads { t.join().unwrap(); }
+    assert_eq!(unsafe { a.0.unwrap().as_ref() }.count.load(Ordering::Relaxed), 1);
+}
+
+#[test] fn misreported_len() {
+    struct Liar(usize, std::ops::Range<u16>);
+    impl Iterator for Liar { type Item = u16; fn next(&mut self) -> Option<u16> { self.1.next() } }
+    impl ExactSizeIterator for Liar { fn len(&self) -> usize { self.0 } }
+
+    assert_eq!(HString::from_code_units(Liar(4, 1..3)).unwrap().units0(), &[1, 2, 0, 0, 0]);
+    assert_eq!(HString::from_code_units(Liar(1, 1..3)).unwrap().units0(), &[1, 0]);
+    assert!(HString::from_code_units(Liar(0, 1..3)).unwrap().as_hstring().is_null());
+}
+
+#[test] fn buffer() {
+    let mut a = HStringBuffer::new(3).unwrap();
+    assert_eq!(a.units0(), &[0, 0, 0, 0]);
+    a.units_mut().copy_from_slice(&[b'a' as u16, b'b' as u16, b'c' as u16]);
+    assert_eq!(a.units0(), &[b'a' as u16, b'b' as u16, b'c' as u16, 0]);
+    let ptr = a.as_hstring();
+    let a = a.promote();
+    assert_eq!(a.as_hstring(), ptr);
+    assert!(!a.is_fast_pass());
+    assert_eq!(a.units0(), &[b'a' as u16, b'b' as u16, b'c' as u16, 0]);
+
+    let b = a.clone();
+    let a = HStringBuffer::try_from(a).err().unwrap(); // shared
+    drop(b);
+    let mut a = HStringBuffer::try_from(a).ok().unwrap(); // unique
+    a.units_mut()[0] = b'A' as u16;
+    assert_eq!(HString::from(a).units0(), &[b'A' as u16, b'b' as u16, b'c' as u16, 0]);
+
+    let mut b = HStringBuffer::new(3).unwrap();
+    let c = b.clone(); // copies, never shares
+    b.units_mut()[0] = 1;
+    assert_eq!((b.units0(), c.units0()), (&[1, 0, 0, 0][..], &[0, 0, 0, 0][..]));
+    assert_ne!(b.as_hstring(), c.as_hstring());
+
+    assert_eq!(HStringBuffer::new(0).unwrap().units_mut(), &[]);
+    assert!(HStringBuffer::new(0).unwrap().promote().as_hstring().is_null());
+    drop(HStringBuffer::new(5).unwrap()); // discarded unpromoted
+}
@@ -0,0 +1,304 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+
+use std::borrow::{Borrow, Cow};
+use std::convert::TryFrom;
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+#[cfg(windows)] use std::ffi::{OsStr, OsString};
+#[cfg(windows)] use std::os::windows::ffi::{OsStrExt, OsStringExt};
+#[cfg(windows)] use std::path::{Path, PathBuf};
+
+
+
+#[cfg(feature = "display")]
+impl Display                for HString { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&**self, fmt) } }
+impl Debug                  for HString { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(&**self, fmt) } }
+impl AsRef<HStr>            for HString { fn as_ref(&self) -> &HStr { self } }
+impl AsRef<[u16]>           for HString { fn as_ref(&self) -> &[u16] { self.units() } }
+impl Borrow<HStr>           for HString { fn borrow(&self) -> &HStr { self } }
+impl Borrow<[u16]>          for HString { fn borrow(&self) -> &[u16] { self.units() } }
+impl Default                for HString { fn default() -> Self { Self::new() } }
+impl From<&HStr>            for HString { fn from(value: &HStr      ) -> Self { value.duplicate() } }
+impl From<&str>             for HString { fn from(value: &str       ) -> Self { Self::from_str(value).unwrap() } }
+impl From<&String>          for HString { fn from(value: &String    ) -> Self { Self::from_str(value).unwrap() } }
+impl From< String>          for HString { fn from(value:  String    ) -> Self { Self::from_str(&value).unwrap() } }
+#[cfg(windows)] impl From<&OsStr>           for HString { fn from(value: &OsStr     ) -> Self { Self::from_osstr(value).unwrap() } }
+#[cfg(windows)] impl From<&OsString>        for HString { fn from(value: &OsString  ) -> Self { Self::from_osstr(value).unwrap() } }
+#[cfg(windows)] impl From< OsString>        for HString { fn from(value:  OsString  ) -> Self { Self::from_osstr(&value).unwrap() } }
+#[cfg(windows)] #[cfg(feature = "bstr")] impl From<&BStr> for HString { fn from(value: &BStr) -> Self { Self::from_bstr(value).unwrap() } }
+#[cfg(windows)] #[cfg(feature = "bstr")] impl From<&HStr> for BString { fn from(value: &HStr) -> Self { Self::from_code_units(value.units().iter().copied()).unwrap() } }
+#[cfg(feature = "wstr")] impl From<&WStr>   for HString { fn from(value: &WStr      ) -> Self { Self::from_wstr(value).unwrap() } }
+impl Eq                     for HString {}
+impl Ord                    for HString { fn cmp(&self, other: &HString) -> Ordering { self.units().cmp(other.units()) } }
+impl Hash                   for HString { fn hash<H: Hasher>(&self, state: &mut H) { self.units().hash(state) } }
+
+#[cfg(feature = "display")]
+impl Display                for HStringBuffer { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&self.to_string_lossy(), fmt) } }
+impl Debug                  for HStringBuffer { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { debug_utf16(self.units(), fmt) } }
+impl AsRef<[u16]>           for HStringBuffer { fn as_ref(&self) -> &[u16] { self.units() } }
+impl AsMut<[u16]>           for HStringBuffer { fn as_mut(&mut self) -> &mut [u16] { self.units_mut() } }
+impl Borrow<[u16]>          for HStringBuffer { fn borrow(&self) -> &[u16] { self.units() } }
+impl Clone                  for HStringBuffer { fn clone(&self) -> Self { Self::from_units(self.units()).unwrap() } }
+impl From<&HStr>            for HStringBuffer { fn from(value: &HStr      ) -> Self { Self::from_hstr(value).unwrap() } }
+impl From<&str>             for HStringBuffer { fn from(value: &str       ) -> Self { Self::try_from(HString::from(value)).unwrap() } }
+impl From<&String>          for HStringBuffer { fn from(value: &String    ) -> Self { Self::try_from(HString::from(value)).unwrap() } }
+impl From< String>          for HStringBuffer { fn from(value:  String    ) -> Self { Self::try_from(HString::from(value)).unwrap() } }
+impl Eq                     for HStringBuffer {}
+impl Ord                    for HStringBuffer { fn cmp(&self, other: &HStringBuffer) -> Ordering { self.units().cmp(other.units()) } }
+impl Hash                   for HStringBuffer { fn hash<H: Hasher>(&self, state: &mut H) { self.units().hash(state) } }
+
+#[cfg(feature = "display")]
+impl Display                for HStr    { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&self.to_string_lossy(), fmt) } }
+impl Debug                  for HStr    { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { debug_utf16(self.units(), fmt) } }
+impl AsRef<HStr>            for HStr    { fn as_ref(&self) -> &HStr { self } }
+impl AsRef<[u16]>           for HStr    { fn as_ref(&self) -> &[u16] { self.units() } }
+impl Borrow<[u16]>          for HStr    { fn borrow(&self) -> &[u16] { self.units() } }
+impl ToOwned                for HStr    { type Owned = HString; fn to_owned(&self) -> HString { self.duplicate() } }
+impl Eq                     for &HStr   {}
+impl Ord                    for &HStr   { fn cmp(&self, other: &&HStr) -> Ordering { self.units().cmp(other.units()) } }
+impl Hash                   for &HStr   { fn hash<H: Hasher>(&self, state: &mut H) { self.units().hash(state) } }
+
+#[cfg(feature = "display")]
+impl Display                for HStringRef<'_> { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&**self, fmt) } }
+impl Debug                  for HStringRef<'_> { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(&**self, fmt) } }
+impl AsRef<HStr>            for HStringRef<'_> { fn as_ref(&self) -> &HStr { self } }
+impl AsRef<[u16]>           for HStringRef<'_> { fn as_ref(&self) -> &[u16] { self.units() } }
+impl Borrow<HStr>           for HStringRef<'_> { fn borrow(&self) -> &HStr { self } }
+impl Eq                     for HStringRef<'_> {}
+impl Ord                    for HStringRef<'_> { fn cmp(&self, other: &Self) -> Ordering { self.units().cmp(other.units()) } }
+impl Hash                   for HStringRef<'_> { fn hash<H: Hasher>(&self, state: &mut H) { self.units().hash(state) } }
+
+peo!(&HStr,             &HStr           );
+peo!(HString,           HString         );
+peo!(HStringRef<'_>,    HStringRef<'_>  );
+
+peo!(HString,           &HStr  ); peo!(&HStr,   HString         );
+peo!(HStringRef<'_>,    &HStr  ); peo!(&HStr,   HStringRef<'_>  );
+peo!(&?[u16],           &HStr  ); peo!(&HStr,   &?[u16]         );
+peo!(&?str,             &HStr  ); peo!(&HStr,   &?str           );
+peo!(String,            &HStr  ); peo!(&HStr,   String          );
+peo!(Cow<'_, [u16]>,    &HStr  ); peo!(&HStr,   Cow<'_, [u16]>  );
+peo!(Cow<'_, str>,      &HStr  ); peo!(&HStr,   Cow<'_, str>    );
+
+//peo!(&HStr,           HString); peo!(HString, &HStr           ); // already covered
+peo!(HStringRef<'_>,    HString); peo!(HString, HStringRef<'_>  );
+peo!(&?str,             HString); peo!(HString, &?str           );
+peo!(&?[u16],           HString); peo!(HString, &?[u16]         );
+peo!(String,            HString); peo!(HString, String          );
+peo!(Cow<'_, [u16]>,    HString); peo!(HString, Cow<'_, [u16]>  );
+peo!(Cow<'_, str>,      HString); peo!(HString, Cow<'_, str>    );
+
+peo!(HStringBuffer,     HStringBuffer   );
+peo!(HStringBuffer,     &HStr  ); peo!(&HStr,   HStringBuffer   );
+peo!(HStringBuffer,     HString); peo!(HString, HStringBuffer   );
+peo!(&?str,             HStringBuffer); peo!(HStringBuffer, &?str       );
+peo!(&?[u16],           HStringBuffer); peo!(HStringBuffer, &?[u16]     );
+peo!(String,            HStringBuffer); peo!(HStringBuffer, String      );
+
+peo!(&?str,             HStringRef<'_>); peo!(HStringRef<'_>, &?str     );
+peo!(&?[u16],           HStringRef<'_>); peo!(HStringRef<'_>, &?[u16]   );
+peo!(String,            HStringRef<'_>); peo!(HStringRef<'_>, String    );
+
+#[cfg(feature = "wstr")] mod wstr_peo {
+    use super::*;
+    peo!(&WStr,             &HStr  ); peo!(&HStr,   &WStr           );
+    peo!(WString,           &HStr  ); peo!(&HStr,   WString         );
+    peo!(&WStr,             HString); peo!(HString, &WStr           );
+    peo!(WString,           HString); peo!(HString, WString         );
+}
+
+#[cfg(windows)] mod windows_peo {
+    use super::*;
+    peo!(&?OsStr,           &HStr  ); peo!(&HStr,   &?OsStr         );
+    peo!(OsString,          &HStr  ); peo!(&HStr,   OsString        );
+    peo!(&?Path,            &HStr  ); peo!(&HStr,   &?Path          );
+    peo!(PathBuf,           &HStr  ); peo!(&HStr,   PathBuf         );
+    peo!(Cow<'_, OsStr>,    &HStr  ); peo!(&HStr,   Cow<'_, OsStr>  );
+    peo!(Cow<'_, Path>,     &HStr  ); peo!(&HStr,   Cow<'_, Path>   );
+
+    peo!(&?OsStr,           HString); peo!(HString, &?OsStr         );
+    peo!(OsString,          HString); peo!(HString, OsString        );
+    peo!(&?Path,            HString); peo!(HString, &?Path          );
+    peo!(PathBuf,           HString); peo!(HString, PathBuf         );
+    peo!(Cow<'_, OsStr>,    HString); peo!(HString, Cow<'_, OsStr>  );
+    peo!(Cow<'_, Path>,     HString); peo!(HString, Cow<'_, Path>   );
+
+    #[cfg(feature = "bstr")] peo!(&BStr,    &HStr  ); #[cfg(feature = "bstr")] peo!(&HStr,   &BStr   );
+    #[cfg(feature = "bstr")] peo!(BString,  &HStr  ); #[cfg(feature = "bstr")] peo!(&HStr,   BString );
+    #[cfg(feature = "bstr")] peo!(&BStr,    HString); #[cfg(feature = "bstr")] peo!(HString, &BStr   );
+    #[cfg(feature = "bstr")] peo!(BString,  HString); #[cfg(feature = "bstr")] peo!(HString, BString );
+}
+
+
+
+impl<'s> UTF16ish<'s> for HStr {
+    type Iter = std::iter::Copied<std::slice::Iter<'s, u16>>;
+    fn utf16ish(&'s self) -> Self::Iter { self.units().iter().copied() }
+}
+
+impl<'s> UTF16ish<'s> for HString {
+    type Iter = std::iter::Copied<std::slice::Iter<'s, u16>>;
+    fn utf16ish(&'s self) -> Self::Iter { self.units().iter().copied() }
+}
+
+impl<'s> UTF16ish<'s> for HStringBuffer {
+    type Iter = std::iter::Copied<std::slice::Iter<'s, u16>>;
+    fn utf16ish(&'s self) -> Self::Iter { self.units().iter().copied() }
+}
+
+impl<'s> UTF16ish<'s> for HStringRef<'_> {
+    type Iter = std::iter::Copied<std::slice::Iter<'s, u16>>;
+    fn utf16ish(&'s self) -> Self::Iter { self.units().iter().copied() }
+}
+
+impl Utf16Hash for HStr             { fn utf16_units(&self) -> Utf16Units<'_> { self.units().into() } }
+impl Utf16Hash for HString          { fn utf16_units(&self) -> Utf16Units<'_> { self.units().into() } }
+impl Utf16Hash for HStringBuffer    { fn utf16_units(&self) -> Utf16Units<'_> { self.units().into() } }
+impl Utf16Hash for HStringRef<'_>   { fn utf16_units(&self) -> Utf16Units<'_> { self.units().into() } }
+
+
+
+impl HString {
+    /// Create an [HString] from a [str]
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: impl AsRef<str>) -> Option<Self> { Self::from_code_units(ExactSize::new(s.as_ref().encode_utf16())) }
+
+    /// Create an [HString] from a [OsStr]
+    #[cfg(windows)]
+    pub fn from_osstr(s: impl AsRef<OsStr>) -> Option<Self> { Self::from_code_units(ExactSize::new(s.as_ref().encode_wide())) }
+
+    /// Create an [HString] from a [BStr]
+    #[cfg(windows)] #[cfg(feature = "bstr")]
+    pub fn from_bstr(s: impl AsRef<BStr>) -> Option<Self> { Self::from_code_units(s.as_ref().units().iter().copied()) }
+
+    /// Create an [HString] from a [WStr]
+    #[cfg(feature = "wstr")]
+    pub fn from_wstr(s: impl AsRef<WStr>) -> Option<Self> { Self::from_code_units(s.as_ref().units().iter().copied()) }
+}
+
+impl HStringBuffer {
+    /// Create an [HStringBuffer] containing a copy of `s`
+    pub fn from_hstr(s: impl AsRef<HStr>) -> Option<Self> { Self::from_units(s.as_ref().units()) }
+
+    /// Create an [HStringBuffer] containing a copy of `units`
+    pub fn from_units(units: &[u16]) -> Option<Self> {
+        let mut b = Self::new(units.len())?;
+        b.units_mut().copy_from_slice(units);
+        Some(b)
+    }
+
+    /// Length in [u16] unicode [code unit]s, excluding the terminal `0u16`
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn len(&self) -> usize { self.units0().len() - 1 }
+
+    /// Returns `true` if the string is empty
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// The [u16] unicode [code unit]s of the string, excluding the terminal `0u16`
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn units(&self) -> &[u16] { let u = self.units0(); &u[..u.len()-1] }
+
+    /// Decode the string as UTF-16, replacing invalid data (unpaired surrogates) with [U+FFFD](std::char::REPLACEMENT_CHARACTER)
+    pub fn to_string_lossy(&self) -> String { String::from_utf16_lossy(self.units()) }
+}
+
+impl<'s> HStringRef<'s> {
+    /// Reference a &amp;[WStr] with a fast-pass header, without copying.
+    ///
+    /// Returns [None] if `s` is `u32::MAX` or more code units long.
+    #[cfg(feature = "wstr")]
+    pub fn from_wstr(s: &'s WStr) -> Option<Self> { Self::from_units0(s.units0()) }
+
+    /// Reference a &amp;[BStr] with a fast-pass header, without copying.  Interior `0u16`s are preserved.
+    #[cfg(windows)] #[cfg(feature = "bstr")]
+    pub fn from_bstr(s: &'s BStr) -> Self { Self::from_units0(s.units0()).expect("BSTR lengths always fit in 32 bits") }
+}
+
+impl HStr {
+    /// Length in [u16] unicode [code unit]s, excluding the terminal `0u16`
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn len(&self) -> usize { self.len32() as usize }
+
+    /// Length in [u16] unicode [code unit]s, including the terminal `0u16`
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn len0(&self) -> usize { self.len() + 1 }
+
+    /// Returns `true` if the string is empty
+    pub fn is_empty(&self) -> bool { self.len32() == 0 }
+
+    /// The [u16] unicode [code unit]s of the string, excluding the terminal `0u16`
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn units(&self) -> &[u16] { let u = self.units0(); &u[..u.len()-1] }
+
+    /// Decode the string as UTF-16, replacing invalid data (unpaired surrogates) with [U+FFFD](std::char::REPLACEMENT_CHARACTER)
+    pub fn to_string_lossy(&self) -> String { String::from_utf16_lossy(self.units()) }
+
+    /// Convert the string into an [OsString]
+    #[cfg(windows)]
+    pub fn to_os_string(&self) -> OsString { OsString::from_wide(self.units()) }
+}
+
+
+
+#[test] fn comparisons() {
+    let a = HString::from("abc");
+    let b : &HStr = &a;
+    let c = hstr!("abc");
+    assert_eq!(a, "abc");
+    assert_eq!(b, "abc");
+    assert_eq!(*c, "abc");
+    assert_eq!("abc", a);
+    assert_eq!(String::from("abc"), b);
+    assert_eq!(a, &[b'a' as u16, b'b' as u16, b'c' as u16][..]);
+    assert_eq!(a, &**c);
+    assert!(a < *"abd");
+    assert!(b > "ab");
+    assert_eq!(b.to_owned(), a);
+    assert_eq!(a.to_string_lossy(), "abc");
+    assert_eq!(format!("{:?}", HString::from_code_units([b'a' as u16, 0, 0xD800].iter().copied()).unwrap()), "\"a\\0\\u{d800}\"");
+    #[cfg(feature = "display")] assert_eq!(format!("{}", c), "abc");
+    #[cfg(feature = "wstr")] assert_eq!(HString::from(wstr!("abc")), a);
+    #[cfg(feature = "wstr")] assert_eq!(HStringRef::from_wstr(wstr!("abc")).unwrap(), "abc");
+}
+
+#[test] fn buffer_comparisons() {
+    let mut a = HStringBuffer::from("abc");
+    a.as_mut()[2] = b'C' as u16;
+    assert_eq!(a, "abC");
+    assert_eq!(a.clone(), a);
+    assert!(a < *"abc");
+    assert_eq!(format!("{:?}", a), "\"abC\"");
+    let b = a.promote();
+    assert_eq!(HStringBuffer::from(&*b), b);
+}
+
+#[test] fn macro_headers() {
+    let a = hstr!("a\0b");
+    assert!(a.is_fast_pass());
+    assert_eq!(a.units0(), &[b'a' as u16, 0, b'b' as u16, 0]);
+    assert_eq!(a.len(), 3);
+    assert!(!a.as_hstring().is_null());
+    assert!(hstr!("").as_hstring().is_null());
+    assert!(hstr!("").is_empty());
+    let owned = HStr::to_owned(a);
+    assert!(!owned.is_fast_pass());
+    assert_eq!(owned, &**a);
+}
+
+#[cfg(windows)] #[cfg(feature = "bstr")] #[test] fn bstr_conversions() {
+    let a = bstr!("a\0c");
+    let b = HStringRef::from_bstr(a);
+    assert_eq!(b.units().as_ptr(), a.as_lpcwstr());
+    assert_eq!(a, &*b);
+    assert_eq!(HString::from(a), a);
+    assert_eq!(BString::from(&*b), a);
+    assert_eq!(b.to_os_string(), OsStr::new("a\0c"));
+}
@@ -9,7 +9,34 @@
     pub mod _features;
 }
 
-#[cfg(windows)] mod utf16ish;
-#[cfg(windows)] use utf16ish::*;
+#[macro_use] mod utf16ish;
+#[allow(unused_imports)] use utf16ish::*; // unused with `--no-default-features`
+mod utf16key; pub use utf16key::*;
 #[cfg(windows)] #[cfg(feature = "bstr")] #[path="bstring/_bstring.rs"] mod bstring;
 #[cfg(windows)] #[cfg(feature = "bstr")] pub use bstring::*;
+#[cfg(feature = "wstr")] #[path="wstring/_wstring.rs"] mod wstring;
+#[cfg(feature = "wstr")] pub use wstring::*;
+#[cfg(feature = "wstr")] #[path="multiwstring/_multiwstring.rs"] mod multiwstring;
+#[cfg(feature = "wstr")] pub use multiwstring::*;
+#[cfg(feature = "wstr")] mod envblock;
+#[cfg(feature = "wstr")] pub use envblock::*;
+#[cfg(feature = "wstr")] mod cmdline;
+#[cfg(feature = "wstr")] pub use cmdline::*;
+#[cfg(feature = "wstr")] mod wpath;
+#[cfg(feature = "wstr")] pub use wpath::*;
+#[cfg(feature = "wstr")] #[path="wbuf/_wbuf.rs"] mod wbuf;
+#[cfg(feature = "wstr")] pub use wbuf::*;
+#[cfg(feature = "wstr")] mod fill;
+#[cfg(feature = "wstr")] pub use fill::*;
+#[cfg(feature = "wstr")] #[path="allocwstring/_allocwstring.rs"] mod allocwstring;
+#[cfg(feature = "wstr")] pub use allocwstring::*;
+#[cfg(feature = "wstr")] #[path="resourcename/_resourcename.rs"] mod resourcename;
+#[cfg(feature = "wstr")] pub use resourcename::*;
+#[cfg(feature = "wstr")] mod regvalue;
+#[cfg(feature = "wstr")] pub use regvalue::*;
+#[cfg(feature = "ustr")] #[path="ustring/_ustring.rs"] mod ustring;
+#[cfg(feature = "ustr")] pub use ustring::*;
+#[cfg(feature = "hstr")] #[path="hstring/_hstring.rs"] mod hstring;
+#[cfg(feature = "hstr")] pub use hstring::*;
+#[cfg(feature = "codepage")] #[path="codepage/_codepage.rs"] mod codepage;
+#[cfg(feature = "codepage")] pub use codepage::*;
@@ -0,0 +1,186 @@
+// The dangerous bits of the multi-string wrappers.  Only code within this module has
+// access to `self.0`, hopefully making it simpler to audit access / verify
+// struct invariants are held.
+
+#![allow(unsafe_code)]
+
+#[cfg(windows)] use winapi::um::winnt::PCZZWSTR;
+#[cfg(not(windows))] #[allow(clippy::upper_case_acronyms)] type PCZZWSTR = *const u16;
+
+use crate::*;
+
+use std::ops::Deref;
+
+
+
+/// ### Terminator Invariants
+/// A valid multi-string (`PCZZWSTR`, `REG_MULTI_SZ`) is a list of `0u16`-terminated entries, followed by another `0u16`:
+/// * `"a\0b\0c\0\0"` contains 3 entries: `"a"`, `"b"`, and `"c"`
+/// * An empty list is just the final `0u16`: `"\0"`
+/// * Entries cannot be empty - an empty entry is indistinguishable from the end of the list!
+/// * Entries cannot contain `0u16`s - they would split the entry in two
+///
+/// ### Malformed Buffers
+/// Registry values and API output buffers are frequently malformed - missing the final `0u16` (or both), having trailing
+/// garbage past `"\0\0"`, or an odd number of bytes.  Rust's &[MultiWStr]s and [MultiWString]s are **always** well formed:
+/// use [MultiWStr::from_units_lossy] or [MultiWString::from_bytes_lossy] to parse (and if necessary, repair) such buffers.
+///
+/// ### Pointer Invariants
+/// * Win32 `PCZZWSTR`s may be null.
+/// * **Rust's [MultiWString]s and &[MultiWStr]s are never null** (instead, use [Option]&lt;MultiWString&gt; or [Option]&lt;&amp;MultiWStr&gt;).
+mod invariants {}
+
+static EMPTY : [u16; 1] = [0];
+
+fn is_units00(units00: &[u16]) -> bool {
+    let (&last, entries) = match units00.split_last() { Some(s) => s, None => return false };
+    if last != 0 { return false; }
+    if entries.is_empty() { return true; }
+    entries.last() == Some(&0) && entries[0] != 0 && !entries.windows(2).any(|w| w == [0, 0])
+}
+
+
+
+/// `MultiWString` is an owned, well formed, `"\0\0"`-terminated list of [UTF-16]ish strings (`PCZZWSTR` / `REG_MULTI_SZ`.)
+///
+/// [UTF-16]:   https://en.wikipedia.org/wiki/UTF-16
+pub struct MultiWString(Box<[u16]>);
+
+// MultiWString is implicitly Send and Sync, just like the Box<[u16]> it wraps.
+
+impl Deref for MultiWString {
+    type Target = MultiWStr;
+    fn deref(&self) -> &MultiWStr { MultiWStr::from_units00_unchecked(&self.0) }
+}
+
+impl MultiWString {
+    /// Create a `MultiWString` from a list of entries.
+    ///
+    /// Returns [None] if any entry is empty (which would terminate the list early), or contains an interior `'\0'` (which would split the entry in two.)
+    pub fn from_entries<I>(entries: I) -> Option<Self> where I: IntoIterator, I::Item: Utf16Hash {
+        let mut units00 = Vec::new();
+        for entry in entries {
+            let start = units00.len();
+            units00.extend(entry.utf16_units());
+            let units = &units00[start..];
+            if units.is_empty() || units.contains(&0) { return None; }
+            units00.push(0);
+        }
+        units00.push(0);
+        Some(Self(units00.into_boxed_slice()))
+    }
+
+    /// Take ownership of `units00`.  Returns `units00` back if it isn't a well formed multi-string.
+    pub fn from_units00(units00: Vec<u16>) -> Result<Self, Vec<u16>> {
+        if is_units00(&units00) { Ok(Self(units00.into_boxed_slice())) } else { Err(units00) }
+    }
+}
+
+
+
+/// `&MultiWStr` is a non-null, borrowed, well formed, `"\0\0"`-terminated list of [UTF-16]ish strings
+/// (`PCZZWSTR` / `REG_MULTI_SZ`.)  Iterate over it to get each entry as a &[WStr].
+///
+/// [UTF-16]:   https://en.wikipedia.org/wiki/UTF-16
+#[repr(transparent)] pub struct MultiWStr([u16]);
+
+// &MultiWStr is implicitly Send and Sync, just like the [u16] it wraps.
+
+impl MultiWStr {
+    fn from_units00_unchecked(units00: &[u16]) -> &MultiWStr {
+        debug_assert!(is_units00(units00));
+        unsafe { &*(units00 as *const [u16] as *const MultiWStr) }
+    }
+
+    /// An empty multi-string (`"\0"`)
+    pub fn empty() -> &'static MultiWStr { Self::from_units00_unchecked(&EMPTY) }
+
+    /// Borrows `units00` as a &amp;[MultiWStr].
+    ///
+    /// Returns [None] unless `units00` is exactly a well formed multi-string - see [from_units_lossy](Self::from_units_lossy)
+    /// for a more forgiving alternative.
+    pub fn from_units00(units00: &[u16]) -> Option<&MultiWStr> {
+        if is_units00(units00) { Some(Self::from_units00_unchecked(units00)) } else { None }
+    }
+
+    /// Converts a &amp;[PCZZWSTR] into an Option&lt;&amp;[MultiWStr]&gt;.
+    /// By requiring a reference, this API [bounds] &amp;[MultiWStr]'s lifetime, helping avoid bugs.
+    ///
+    /// ### Safety
+    ///
+    /// * `pczzwstr` must be null, or a valid `"\0\0"`-terminated string for the duration of `&MultiWStr`'s lifetime.
+    /// * A leading `0u16` is treated as an empty list, as `GetLogicalDriveStringsW` etc. would.
+    ///
+    /// [PCZZWSTR]: https://docs.microsoft.com/en-us/windows/win32/winprog/windows-data-types
+    /// [bounds]:   https://doc.rust-lang.org/nomicon/unbounded-lifetimes.html
+    pub unsafe fn from_pczzwstr(pczzwstr: &PCZZWSTR) -> Option<&MultiWStr> { Self::from_pczzwstr_unbounded(*pczzwstr) }
+
+    /// Converts a [PCZZWSTR] into an Option&lt;&amp;[MultiWStr]&gt;.
+    ///
+    /// ### Safety
+    ///
+    /// * `pczzwstr` must be null, or a valid `"\0\0"`-terminated string for the duration of `&MultiWStr`'s lifetime.
+    /// * <span style="color: red">**&MultiWStr's lifetime is [unbounded], an easy source of bugs.  Prefer [from_pczzwstr]!**</span>
+    ///
+    /// [PCZZWSTR]:         https://docs.microsoft.com/en-us/windows/win32/winprog/windows-data-types
+    /// [unbounded]:        https://doc.rust-lang.org/nomicon/unbounded-lifetimes.html
+    /// [from_pczzwstr]:    #method.from_pczzwstr
+    pub unsafe fn from_pczzwstr_unbounded<'m>(pczzwstr: PCZZWSTR) -> Option<&'m MultiWStr> {
+        if pczzwstr.is_null() { return None; }
+        if *pczzwstr == 0 { return Some(Self::from_units00_unchecked(std::slice::from_raw_parts(pczzwstr, 1))); }
+        let mut len00 = 2;
+        while *pczzwstr.add(len00-2) != 0 || *pczzwstr.add(len00-1) != 0 { len00 += 1; }
+        Some(Self::from_units00_unchecked(std::slice::from_raw_parts(pczzwstr, len00)))
+    }
+
+    /// The `&MultiWStr` as a winapi-friendly `PCZZWSTR`.
+    ///
+    /// ### Safety
+    ///
+    /// * `s.as_pczzwstr()` is guaranteed to be non-null and `"\0\0"`-terminated (or just `"\0"` if empty)
+    /// * It is **not** safe to modify the contents of the string through the returned pointer!
+    pub fn as_pczzwstr(&self) -> PCZZWSTR { self.0.as_ptr() }
+
+    /// The [u16] unicode [code unit]s of the string, including every entry's terminal `0u16`, and the final `0u16`.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn units00(&self) -> &[u16] { &self.0 }
+}
+
+
+
+#[test] fn layout() {
+    use std::mem::align_of;
+    use std::mem::size_of;
+
+    assert_eq!(align_of::<&MultiWStr>(),    align_of::<&[u16]>());
+    assert_eq!(size_of::<&MultiWStr>(),     size_of::<&[u16]>());
+    assert_eq!(size_of::<MultiWString>(),   size_of::<Box<[u16]>>());
+}
+
+#[test] fn core_apis() {
+    let a = [b'a' as u16, 0, b'b' as u16, 0, 0];
+    let m = MultiWStr::from_units00(&a).unwrap();
+    assert_eq!(m.units00(), &a);
+    assert_eq!(m.as_pczzwstr(), a.as_ptr());
+    assert_eq!(unsafe { MultiWStr::from_pczzwstr(&m.as_pczzwstr()) }.unwrap().units00(), &a);
+    assert_eq!(unsafe { MultiWStr::from_pczzwstr_unbounded([0u16, 0xFFFF].as_ptr()) }.unwrap().units00(), &[0]);
+    assert!(unsafe { MultiWStr::from_pczzwstr(&(std::ptr::null::<u16>())) }.is_none());
+
+    assert!(MultiWStr::from_units00(&[0]).is_some());
+    assert!(MultiWStr::from_units00(&[]).is_none());
+    assert!(MultiWStr::from_units00(&[0, 0]).is_none());                            // empty entry
+    assert!(MultiWStr::from_units00(&[b'a' as u16, 0]).is_none());                  // missing final `0u16`
+    assert!(MultiWStr::from_units00(&[b'a' as u16, 0, 0, b'b' as u16, 0, 0]).is_none()); // trailing data
+    assert_eq!(MultiWStr::empty().units00(), &[0]);
+
+    let s = MultiWString::from_entries(vec!["C:\\", "D:\\"]).unwrap();
+    assert_eq!(s.units00(), &"C:\\\0D:\\\0\0".encode_utf16().collect::<Vec<_>>()[..]);
+    assert!(MultiWString::from_entries(vec!["a", "", "b"]).is_none());
+    assert!(MultiWString::from_entries(vec!["a", "b\0c"]).is_none());
+    assert!(MultiWString::from_entries(vec![String::from("\0")]).is_none());
+    assert!(MultiWString::from_entries(vec![&[b'a' as u16, 0][..]]).is_none());
+    assert_eq!(MultiWString::from_entries(Vec::<&str>::new()).unwrap().units00(), &[0]);
+    assert!(MultiWString::from_units00(vec![b'a' as u16, 0, 0]).is_ok());
+    assert_eq!(MultiWString::from_units00(vec![b'a' as u16, 0]).err(), Some(vec![b'a' as u16, 0]));
+}
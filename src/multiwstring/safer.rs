@@ -0,0 +1,179 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+
+use std::borrow::{Borrow, Cow};
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
+
+
+
+impl Debug                  for MultiWString    { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(&**self, fmt) } }
+impl Default                for MultiWString    { fn default() -> Self { Self::from_multiwstr(MultiWStr::empty()) } }
+impl AsRef<MultiWStr>       for MultiWString    { fn as_ref(&self) -> &MultiWStr { self } }
+impl Borrow<MultiWStr>      for MultiWString    { fn borrow(&self) -> &MultiWStr { self } }
+impl Clone                  for MultiWString    { fn clone(&self) -> Self { Self::from_multiwstr(self) } }
+impl From<&MultiWStr>       for MultiWString    { fn from(value: &MultiWStr) -> Self { Self::from_multiwstr(value) } }
+impl Eq                     for MultiWString    {}
+impl Ord                    for MultiWString    { fn cmp(&self, other: &MultiWString) -> Ordering { self.units00().cmp(other.units00()) } }
+impl PartialEq              for MultiWString    { fn eq(&self, other: &MultiWString) -> bool { self.units00() == other.units00() } }
+impl PartialOrd             for MultiWString    { fn partial_cmp(&self, other: &MultiWString) -> Option<Ordering> { Some(self.cmp(other)) } }
+impl Hash                   for MultiWString    { fn hash<H: Hasher>(&self, state: &mut H) { self.units00().hash(state) } }
+
+impl Debug                  for MultiWStr       { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { fmt.debug_list().entries(self.iter()).finish() } }
+impl Default                for &MultiWStr      { fn default() -> Self { MultiWStr::empty() } }
+impl AsRef<MultiWStr>       for MultiWStr       { fn as_ref(&self) -> &MultiWStr { self } }
+impl ToOwned                for MultiWStr       { type Owned = MultiWString; fn to_owned(&self) -> MultiWString { MultiWString::from_multiwstr(self) } }
+impl Eq                     for MultiWStr       {}
+impl Ord                    for MultiWStr       { fn cmp(&self, other: &MultiWStr) -> Ordering { self.units00().cmp(other.units00()) } }
+impl PartialEq              for MultiWStr       { fn eq(&self, other: &MultiWStr) -> bool { self.units00() == other.units00() } }
+impl PartialOrd             for MultiWStr       { fn partial_cmp(&self, other: &MultiWStr) -> Option<Ordering> { Some(self.cmp(other)) } }
+impl Hash                   for MultiWStr       { fn hash<H: Hasher>(&self, state: &mut H) { self.units00().hash(state) } }
+
+impl PartialEq<MultiWStr>   for MultiWString    { fn eq(&self, other: &MultiWStr) -> bool { self.units00() == other.units00() } }
+impl PartialEq<MultiWString> for MultiWStr      { fn eq(&self, other: &MultiWString) -> bool { self.units00() == other.units00() } }
+impl PartialEq<&MultiWStr>  for MultiWString    { fn eq(&self, other: &&MultiWStr) -> bool { self.units00() == other.units00() } }
+impl PartialEq<MultiWString> for &MultiWStr     { fn eq(&self, other: &MultiWString) -> bool { self.units00() == other.units00() } }
+
+impl<'m> IntoIterator for &'m MultiWStr     { type Item = &'m WStr; type IntoIter = MultiWStrIter<'m>; fn into_iter(self) -> MultiWStrIter<'m> { self.iter() } }
+impl<'m> IntoIterator for &'m MultiWString  { type Item = &'m WStr; type IntoIter = MultiWStrIter<'m>; fn into_iter(self) -> MultiWStrIter<'m> { self.iter() } }
+
+
+
+/// An iterator over the entries of a &amp;[MultiWStr], each as a &amp;[WStr].
+#[derive(Clone)] pub struct MultiWStrIter<'m> {
+    remaining: &'m [u16],
+}
+
+impl<'m> Iterator for MultiWStrIter<'m> {
+    type Item = &'m WStr;
+    fn next(&mut self) -> Option<&'m WStr> {
+        let nul = self.remaining.iter().position(|&u| u == 0)?;
+        if nul == 0 { return None; }
+        let (units0, remaining) = self.remaining.split_at(nul + 1);
+        self.remaining = remaining;
+        WStr::from_units0(units0)
+    }
+}
+
+impl FusedIterator for MultiWStrIter<'_> {}
+
+impl Debug for MultiWStrIter<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { fmt.debug_list().entries(self.clone()).finish() }
+}
+
+
+
+impl MultiWString {
+    /// Create a `MultiWString` from a well formed &amp;[MultiWStr].
+    pub fn from_multiwstr(multi: &MultiWStr) -> Self { Self::from_units00(multi.units00().to_vec()).unwrap() }
+
+    /// Parse a (possibly malformed) little endian `REG_MULTI_SZ` style byte buffer:
+    ///
+    /// * A trailing odd byte is ignored
+    /// * Everything from the first empty entry (`"\0\0"`) onwards is ignored
+    /// * Missing terminal `0u16`s are added
+    ///
+    /// ### Example
+    /// ```
+    /// # use winstr::*;
+    /// let bytes = b"a\0\0\0b\0\0\0c\0\0"; // missing terminators, odd byte count
+    /// let multi = MultiWString::from_bytes_lossy(bytes);
+    /// assert_eq!(multi.iter().collect::<Vec<_>>(), ["a", "b", "c"]);
+    /// ```
+    pub fn from_bytes_lossy(bytes: &[u8]) -> Self {
+        let units = bytes.chunks_exact(2).map(|b| u16::from_le_bytes([b[0], b[1]])).collect::<Vec<_>>();
+        MultiWStr::from_units_lossy(&units).into_owned()
+    }
+}
+
+impl MultiWStr {
+    /// Parse a (possibly malformed) multi-string, borrowing `units` when possible:
+    ///
+    /// * Everything from the first empty entry (`"\0\0"`) onwards is ignored, as Windows would
+    /// * Missing terminal `0u16`s are added (requiring a copy)
+    ///
+    /// ### Example
+    /// ```
+    /// # use winstr::*;
+    /// # use std::borrow::Cow;
+    /// let units = "C:\\\0D:\\\0\0garbage".encode_utf16().collect::<Vec<_>>();
+    /// let multi = MultiWStr::from_units_lossy(&units);
+    /// assert!(matches!(multi, Cow::Borrowed(_)));
+    /// assert_eq!(multi.iter().collect::<Vec<_>>(), ["C:\\", "D:\\"]);
+    ///
+    /// let units = "C:\\\0D:\\".encode_utf16().collect::<Vec<_>>();
+    /// let multi = MultiWStr::from_units_lossy(&units);
+    /// assert!(matches!(multi, Cow::Owned(_)));
+    /// assert_eq!(multi.iter().collect::<Vec<_>>(), ["C:\\", "D:\\"]);
+    /// ```
+    pub fn from_units_lossy(units: &[u16]) -> Cow<'_, MultiWStr> {
+        let end = units.iter().enumerate().position(|(i, &u)| u == 0 && (i == 0 || units[i-1] == 0));
+        if let Some(end) = end { return Cow::Borrowed(MultiWStr::from_units00(&units[..=end]).unwrap()); }
+        if units.is_empty() { return Cow::Borrowed(MultiWStr::empty()); }
+
+        let mut units00 = units.to_vec();
+        if units00.last() != Some(&0) { units00.push(0); }
+        units00.push(0);
+        Cow::Owned(MultiWString::from_units00(units00).unwrap())
+    }
+
+    /// Iterate over the entries of the multi-string, each as a &amp;[WStr].
+    pub fn iter(&self) -> MultiWStrIter<'_> { MultiWStrIter { remaining: self.units00() } }
+
+    /// The number of entries in the multi-string (O(N).)
+    pub fn count(&self) -> usize { self.units00().iter().filter(|&&u| u == 0).count() - 1 }
+
+    /// Returns `true` if the multi-string contains no entries.
+    pub fn is_empty(&self) -> bool { self.units00().len() == 1 }
+
+    /// The [u16] unicode [code unit]s of the string, including every entry's terminal `0u16`, but excluding the final `0u16`.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn units0(&self) -> &[u16] { let u = self.units00(); &u[..u.len()-1] }
+
+    /// Convert the multi-string into a list of [String]s, replacing invalid UTF-16 sequences with [U+FFFD REPLACEMENT CHARACTER](https://doc.rust-lang.org/std/char/constant.REPLACEMENT_CHARACTER.html).
+    pub fn to_strings_lossy(&self) -> Vec<String> { self.iter().map(|e| e.to_string_lossy()).collect() }
+}
+
+
+
+#[test] fn iteration() {
+    let multi = MultiWString::from_entries(vec!["C:\\", "D:\\", "E:\\"]).unwrap();
+    assert_eq!(multi.iter().collect::<Vec<_>>(), ["C:\\", "D:\\", "E:\\"]);
+    assert_eq!(multi.count(), 3);
+    assert!(!multi.is_empty());
+    assert_eq!(multi.to_strings_lossy(), ["C:\\", "D:\\", "E:\\"]);
+    assert_eq!(format!("{:?}", multi), "[\"C:\\\\\", \"D:\\\\\", \"E:\\\\\"]");
+    assert_eq!((&multi).into_iter().count(), 3);
+
+    let empty = MultiWString::default();
+    assert_eq!(empty.iter().count(), 0);
+    assert_eq!(empty.count(), 0);
+    assert!(empty.is_empty());
+    assert_eq!(empty.units0(), &[]);
+    assert_eq!(empty, *MultiWStr::empty());
+    assert_eq!(multi.clone(), multi);
+    assert_eq!(&*multi.to_owned(), &*multi);
+}
+
+#[test] fn malformed() {
+    fn lossy(units: &str) -> Vec<String> { MultiWStr::from_units_lossy(&units.encode_utf16().collect::<Vec<_>>()).to_strings_lossy() }
+    assert_eq!(lossy(""),               Vec::<String>::new());
+    assert_eq!(lossy("\0"),             Vec::<String>::new());
+    assert_eq!(lossy("\0a\0\0"),        Vec::<String>::new());   // leading empty entry
+    assert_eq!(lossy("a"),              ["a"]);                 // missing both terminators
+    assert_eq!(lossy("a\0"),            ["a"]);                 // missing final terminator
+    assert_eq!(lossy("a\0\0"),          ["a"]);
+    assert_eq!(lossy("a\0\0b\0\0"),     ["a"]);                 // embedded empty entry
+    assert_eq!(lossy("a\0bc\0\0\0\0"),  ["a", "bc"]);           // extra terminators
+
+    fn bytes(b: &[u8]) -> Vec<String> { MultiWString::from_bytes_lossy(b).to_strings_lossy() }
+    assert_eq!(bytes(b""),              Vec::<String>::new());
+    assert_eq!(bytes(b"a"),             Vec::<String>::new());   // odd byte
+    assert_eq!(bytes(b"a\0"),           ["a"]);
+    assert_eq!(bytes(b"a\0\0\0b\0\0"),  ["a", "b"]);            // odd byte count, missing final terminator
+    assert_eq!(bytes(b"a\0\0\0b\0\0\0\0\0"), ["a", "b"]);
+}
@@ -0,0 +1,233 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+
+use std::fmt::{self, Display, Formatter};
+
+
+
+/// An error decoding or encoding `REG_SZ`, `REG_EXPAND_SZ`, or `REG_MULTI_SZ` value bytes - see [decode_reg_sz], [decode_reg_multi_sz], and [encode_reg_sz].
+///
+/// Indices are in bytes, from the start of the value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] pub enum RegValueError {
+    /// The value has an odd number of bytes, so the last byte isn't part of any [u16].
+    OddLength { len: usize },
+
+    /// A `REG_SZ` / `REG_EXPAND_SZ` value contains a `0u16` at `index` that's followed by more text.
+    ///
+    /// When encoding, any `'\0'` is an error, and `index` is where its bytes would have started.
+    InteriorNul { index: usize },
+
+    /// A `REG_MULTI_SZ` value contains more text after the `"\0\0"` ending the list (or an empty entry), starting at `index`.
+    TrailingData { index: usize },
+}
+
+impl RegValueError {
+    /// The byte index of the error.
+    pub fn index(&self) -> usize {
+        match *self {
+            RegValueError::OddLength { len }        => len - 1,
+            RegValueError::InteriorNul { index }    => index,
+            RegValueError::TrailingData { index }   => index,
+        }
+    }
+}
+
+impl Display for RegValueError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            RegValueError::OddLength { len }        => write!(fmt, "registry string value has an odd length ({} bytes)", len),
+            RegValueError::InteriorNul { index }    => write!(fmt, "registry string value contains an interior '\\0' at byte {}", index),
+            RegValueError::TrailingData { index }   => write!(fmt, "registry multi-string value contains data after the end of the list at byte {}", index),
+        }
+    }
+}
+
+impl std::error::Error for RegValueError {}
+
+
+
+/// Decode the bytes of a `REG_SZ` or `REG_EXPAND_SZ` value, as returned by `RegQueryValueExW` or read from an offline hive.
+///
+/// `RegQueryValueExW` documents that string values "may not have been stored with the proper terminating null characters",
+/// so this applies the same fixups `RegGetValueW` does:
+/// * A missing terminal `0u16` is added.  An empty (0 byte) value is an empty string.
+/// * Extra terminal `0u16`s (e.g. from values stored with an overestimated size) are ignored.
+/// * `REG_EXPAND_SZ` `%VARIABLES%` are **not** expanded.
+///
+/// Errors:
+/// * [RegValueError::OddLength] if `bytes` has an odd length.
+/// * [RegValueError::InteriorNul] if a `0u16` is followed by anything other than more `0u16`s.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// assert_eq!(decode_reg_sz(b"a\0b\0\0\0").unwrap(), "ab");
+/// assert_eq!(decode_reg_sz(b"a\0b\0").unwrap(), "ab"); // unterminated
+/// assert_eq!(decode_reg_sz(b"a\0\0\0\0\0").unwrap(), "a");
+/// assert_eq!(decode_reg_sz(b"a\0\0\0b\0"), Err(RegValueError::InteriorNul { index: 2 }));
+/// assert_eq!(decode_reg_sz(b"a\0b"), Err(RegValueError::OddLength { len: 3 }));
+/// ```
+pub fn decode_reg_sz(bytes: &[u8]) -> Result<WString, RegValueError> {
+    let units = reg_sz_units(bytes)?;
+    Ok(WString::from_code_units(units.into_iter()).unwrap())
+}
+
+/// Decode the bytes of a `REG_SZ` or `REG_EXPAND_SZ` value into a [BString] - see [decode_reg_sz].
+///
+/// Panics if the [BString] cannot be allocated.
+#[cfg(windows)] #[cfg(feature = "bstr")]
+pub fn decode_reg_sz_bstring(bytes: &[u8]) -> Result<BString, RegValueError> {
+    let units = reg_sz_units(bytes)?;
+    Ok(BString::from_code_units(units.into_iter()).unwrap())
+}
+
+/// Decode the bytes of a `REG_MULTI_SZ` value, as returned by `RegQueryValueExW` or read from an offline hive.
+///
+/// Like [decode_reg_sz], missing terminators are tolerated:
+/// * An empty list may be stored as 0, 2, or 4 bytes (`""`, `"\0"`, or `"\0\0"`.)
+/// * A missing final `0u16`, or missing terminators for both the last entry and the list, are added.
+/// * Extra terminal `0u16`s are ignored.
+///
+/// Errors:
+/// * [RegValueError::OddLength] if `bytes` has an odd length.
+/// * [RegValueError::TrailingData] if text follows an empty entry (which Windows treats as the end of the list.)
+///
+/// Use [MultiWString::from_bytes_lossy] instead to silently discard odd bytes and trailing data.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// let multi = decode_reg_multi_sz(b"a\0\0\0b\0\0\0\0\0").unwrap();
+/// assert_eq!(multi.iter().collect::<Vec<_>>(), ["a", "b"]);
+/// assert_eq!(decode_reg_multi_sz(b"a\0\0\0b\0").unwrap(), multi); // unterminated
+/// assert!(decode_reg_multi_sz(b"").unwrap().is_empty());
+/// assert_eq!(decode_reg_multi_sz(b"a\0\0\0\0\0b\0"), Err(RegValueError::TrailingData { index: 6 }));
+/// ```
+pub fn decode_reg_multi_sz(bytes: &[u8]) -> Result<MultiWString, RegValueError> {
+    let mut units = units(bytes)?;
+    if let Some(end) = units.iter().enumerate().position(|(i, &u)| u == 0 && (i == 0 || units[i-1] == 0)) {
+        if let Some(index) = units[end..].iter().position(|&u| u != 0) { return Err(RegValueError::TrailingData { index: 2 * (end + index) }); }
+        units.truncate(if end == 0 { 0 } else { end - 1 });
+    }
+    while units.last() == Some(&0) { units.pop(); }
+    if !units.is_empty() { units.push(0); }
+    units.push(0);
+    Ok(MultiWString::from_units00(units).unwrap())
+}
+
+/// Encode a `REG_SZ` or `REG_EXPAND_SZ` value as `0u16`-terminated little endian bytes, suitable for `RegSetValueExW`.
+///
+/// Returns [RegValueError::InteriorNul] if `s` contains any `'\0'`.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// let bytes = encode_reg_sz("C:\\").unwrap();
+/// assert_eq!(bytes, b"C\0:\0\\\0\0\0");
+/// // RegSetValueExW(hkey, name, 0, REG_SZ, bytes.as_ptr(), bytes.len() as u32)
+/// assert_eq!(encode_reg_sz("").unwrap(), b"\0\0");
+/// assert_eq!(encode_reg_sz("a\0b"), Err(RegValueError::InteriorNul { index: 2 }));
+/// ```
+pub fn encode_reg_sz(s: &(impl Utf16Hash + ?Sized)) -> Result<Vec<u8>, RegValueError> {
+    let mut bytes = Vec::new();
+    for unit in s.utf16_units() {
+        if unit == 0 { return Err(RegValueError::InteriorNul { index: bytes.len() }); }
+        bytes.extend_from_slice(&unit.to_le_bytes());
+    }
+    bytes.extend_from_slice(&[0, 0]);
+    Ok(bytes)
+}
+
+/// Encode a [BStr] as a `REG_SZ` or `REG_EXPAND_SZ` value - see [encode_reg_sz].
+///
+/// Returns [RegValueError::InteriorNul] if `s` contains any `'\0'`.
+#[cfg(windows)] #[cfg(feature = "bstr")]
+pub fn encode_reg_sz_bstr(s: &BStr) -> Result<Vec<u8>, RegValueError> {
+    if let Some(nul) = s.units().iter().position(|&u| u == 0) { return Err(RegValueError::InteriorNul { index: 2 * nul }); }
+    Ok(bytes(s.units0()))
+}
+
+/// Encode a `REG_MULTI_SZ` value as `"\0\0"`-terminated little endian bytes, suitable for `RegSetValueExW`.
+///
+/// An empty list is encoded as `"\0\0"` (4 bytes), so the value is always double terminated.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// let multi = MultiWString::from_entries(vec!["a", "b"]).unwrap();
+/// assert_eq!(encode_reg_multi_sz(&multi), b"a\0\0\0b\0\0\0\0\0");
+/// assert_eq!(encode_reg_multi_sz(MultiWStr::empty()), b"\0\0\0\0");
+/// ```
+pub fn encode_reg_multi_sz(multi: &MultiWStr) -> Vec<u8> {
+    if multi.is_empty() { return vec![0; 4]; }
+    bytes(multi.units00())
+}
+
+fn units(bytes: &[u8]) -> Result<Vec<u16>, RegValueError> {
+    if bytes.len() & 1 == 1 { return Err(RegValueError::OddLength { len: bytes.len() }); }
+    Ok(bytes.chunks_exact(2).map(|b| u16::from_le_bytes([b[0], b[1]])).collect())
+}
+
+fn reg_sz_units(bytes: &[u8]) -> Result<Vec<u16>, RegValueError> {
+    let mut units = units(bytes)?;
+    if let Some(nul) = units.iter().position(|&u| u == 0) {
+        if units[nul..].iter().any(|&u| u != 0) { return Err(RegValueError::InteriorNul { index: 2 * nul }); }
+        units.truncate(nul);
+    }
+    Ok(units)
+}
+
+fn bytes(units: &[u16]) -> Vec<u8> { units.iter().flat_map(|u| u.to_le_bytes()).collect() }
+
+
+
+#[test] fn reg_sz() {
+    assert_eq!(decode_reg_sz(b"").unwrap(), "");
+    assert_eq!(decode_reg_sz(b"\0\0").unwrap(), "");
+    assert_eq!(decode_reg_sz(b"\0\0\0\0").unwrap(), "");
+    assert_eq!(decode_reg_sz(b"a\0").unwrap(), "a");
+    assert_eq!(decode_reg_sz(b"a\0\0\0").unwrap(), "a");
+    assert_eq!(decode_reg_sz(b"\0\xD8").unwrap().units(), [0xD800]);
+    assert_eq!(decode_reg_sz(b"a"), Err(RegValueError::OddLength { len: 1 }));
+    assert_eq!(decode_reg_sz(b"a\0\0"), Err(RegValueError::OddLength { len: 3 }));
+    assert_eq!(decode_reg_sz(b"\0\0a\0"), Err(RegValueError::InteriorNul { index: 0 }));
+    assert_eq!(decode_reg_sz(b"a\0\0\0\0\0b\0"), Err(RegValueError::InteriorNul { index: 2 }));
+    assert_eq!(decode_reg_sz(b"\0\0a\0").unwrap_err().to_string(), "registry string value contains an interior '\\0' at byte 0");
+
+    for s in &["", "a", "C:\\Windows\\System32", "%SystemRoot%\\system32"] {
+        let bytes = encode_reg_sz(*s).unwrap();
+        assert_eq!(bytes.len(), 2 * (s.len() + 1));
+        assert_eq!(decode_reg_sz(&bytes).unwrap(), *s);
+    }
+    assert_eq!(encode_reg_sz("\0"),                                Err(RegValueError::InteriorNul { index: 0 }));
+    assert_eq!(encode_reg_sz("ab\0"),                              Err(RegValueError::InteriorNul { index: 4 }));
+    assert_eq!(encode_reg_sz(&[b'a' as u16, 0, b'b' as u16][..]), Err(RegValueError::InteriorNul { index: 2 }));
+    assert_eq!(encode_reg_sz(&[b'a' as u16][..]).unwrap(), b"a\0\0\0");
+}
+
+#[test] fn reg_multi_sz() {
+    let entries = |bytes: &[u8]| decode_reg_multi_sz(bytes).unwrap().to_strings_lossy();
+    assert_eq!(entries(b""),                    Vec::<String>::new());
+    assert_eq!(entries(b"\0\0"),                Vec::<String>::new());
+    assert_eq!(entries(b"\0\0\0\0"),            Vec::<String>::new());
+    assert_eq!(entries(b"\0\0\0\0\0\0"),        Vec::<String>::new());
+    assert_eq!(entries(b"a\0"),                 ["a"]);
+    assert_eq!(entries(b"a\0\0\0"),             ["a"]);
+    assert_eq!(entries(b"a\0\0\0\0\0"),         ["a"]);
+    assert_eq!(entries(b"a\0\0\0b\0"),          ["a", "b"]);
+    assert_eq!(decode_reg_multi_sz(b"a\0\0"),               Err(RegValueError::OddLength { len: 3 }));
+    assert_eq!(decode_reg_multi_sz(b"\0\0a\0\0\0\0\0"),     Err(RegValueError::TrailingData { index: 2 }));
+    assert_eq!(decode_reg_multi_sz(b"a\0\0\0\0\0b\0\0\0"),  Err(RegValueError::TrailingData { index: 6 }));
+    assert_eq!(RegValueError::OddLength { len: 3 }.index(), 2);
+
+    let multi = MultiWString::from_entries(vec!["x", "yz"]).unwrap();
+    assert_eq!(decode_reg_multi_sz(&encode_reg_multi_sz(&multi)).unwrap(), multi);
+    assert_eq!(decode_reg_multi_sz(&encode_reg_multi_sz(MultiWStr::empty())).unwrap(), *MultiWStr::empty());
+}
+
+#[cfg(windows)] #[cfg(feature = "bstr")] #[test] fn bstrings() {
+    assert_eq!(decode_reg_sz_bstring(b"a\0b\0").unwrap(), "ab");
+    assert_eq!(encode_reg_sz_bstr(bstr!("ab")).unwrap(), b"a\0b\0\0\0");
+    assert_eq!(encode_reg_sz_bstr(bstr!("a\0b")), Err(RegValueError::InteriorNul { index: 2 }));
+}
@@ -0,0 +1,136 @@
+// The dangerous bits of the MAKEINTRESOURCEW wrappers.  Only code within this
+// module converts tagged pointers back into strings, hopefully making it
+// simpler to audit access / verify invariants are held.
+
+#![allow(unsafe_code)]
+
+#[cfg(windows)] use winapi::shared::ntdef::LPCWSTR;
+#[cfg(not(windows))] #[allow(clippy::upper_case_acronyms)] type LPCWSTR = *const u16;
+
+use crate::*;
+
+
+
+/// ### Tagged Pointer Invariants
+/// A resource name `LPCWSTR` is either:
+/// * An integer ID in the low 16 bits, with all other bits zero (`MAKEINTRESOURCEW(id)`, `IS_INTRESOURCE(ptr)`)
+/// * A pointer to a `0u16`-terminated string.  Such pointers are never below `0x10000`, as the first 64 KiB of address space is never mapped.
+///
+/// ### Pointer Invariants
+/// * `MAKEINTRESOURCEW(0)` is indistinguishable from a null pointer, and is treated as "no name" when decoding.
+mod invariants {}
+
+
+
+/// A resource name (or window class, etc.) that is either a 16-bit integer ID, or a string.
+///
+/// Used by `LPCWSTR` parameters of `FindResourceW`, `LoadImageW`, `LoadIconW`, `CreateWindowExW` (class atoms), `RegisterClassExW`, etc.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// let icon = ResourceName::from_id(101);
+/// let class = ResourceName::Name(wstr!("MyWindowClass"));
+/// assert_eq!(icon.as_lpcwstr() as usize, 101); // MAKEINTRESOURCEW(101)
+/// assert_eq!(class.as_lpcwstr(), wstr!("MyWindowClass").as_lpcwstr());
+///
+/// let p = class.as_lpcwstr();
+/// assert_eq!(unsafe { ResourceName::from_lpcwstr(&p) }, Some(class));
+/// assert_eq!(ResourceName::id_from_lpcwstr(icon.as_lpcwstr()), Some(101));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)] pub enum ResourceName<S> {
+    /// An integer ID, passed as `MAKEINTRESOURCEW(id)`
+    Id(u16),
+
+    /// A string name
+    Name(S),
+}
+
+impl<S: AsRef<WStr>> ResourceName<S> {
+    /// The resource name as a winapi-friendly, possibly tagged `LPCWSTR`.
+    ///
+    /// * [ResourceName::Id] becomes `MAKEINTRESOURCEW(id)` - **not** a dereferencable pointer!
+    /// * [ResourceName::Name] becomes a pointer to the `0u16`-terminated string.
+    pub fn as_lpcwstr(&self) -> LPCWSTR {
+        match self {
+            ResourceName::Id(id)    => usize::from(*id) as LPCWSTR,
+            ResourceName::Name(s)   => s.as_ref().as_lpcwstr(),
+        }
+    }
+}
+
+impl ResourceName<&WStr> {
+    /// Decode a possibly tagged `LPCWSTR` following `IS_INTRESOURCE` rules.  Returns [None] if `lpcwstr` is null.
+    /// By requiring a reference, this API [bounds] the name's lifetime, helping avoid bugs.
+    ///
+    /// ### Safety
+    ///
+    /// * If `!IS_INTRESOURCE(lpcwstr)`, `lpcwstr` must be a valid `0u16`-terminated string for the duration of the name's lifetime.
+    ///
+    /// [bounds]:       https://doc.rust-lang.org/nomicon/unbounded-lifetimes.html
+    pub unsafe fn from_lpcwstr(lpcwstr: &LPCWSTR) -> Option<ResourceName<&WStr>> {
+        if lpcwstr.is_null() { return None; }
+        match ResourceName::id_from_lpcwstr(*lpcwstr) {
+            Some(id)    => Some(ResourceName::Id(id)),
+            None        => WStr::from_lpcwstr(lpcwstr).map(ResourceName::Name),
+        }
+    }
+
+    /// Returns `IS_INTRESOURCE(lpcwstr)` - `true` if `lpcwstr` is an integer ID (or null) instead of a string pointer.
+    pub fn is_intresource(lpcwstr: LPCWSTR) -> bool { (lpcwstr as usize) >> 16 == 0 }
+
+    /// Safely decode the integer ID of a tagged `LPCWSTR`, without dereferencing it.
+    /// Returns [None] if `lpcwstr` is null, or a string pointer.
+    pub fn id_from_lpcwstr(lpcwstr: LPCWSTR) -> Option<u16> {
+        if lpcwstr.is_null() || !Self::is_intresource(lpcwstr) { return None; }
+        Some(lpcwstr as usize as u16)
+    }
+}
+
+
+
+/// Utility trait for borrowing function arguments as possibly tagged resource name [LPCWSTR]s
+///
+/// ### Safety
+///
+/// By implementing this trait, you promise that [as_resource_name_ptr] will return `MAKEINTRESOURCEW(id)`, or a valid, `0u16`-terminated [LPCWSTR].
+///
+/// [as_resource_name_ptr]: #tymethod.as_resource_name_ptr
+/// [LPCWSTR]:              https://docs.microsoft.com/en-us/windows/win32/learnwin32/working-with-strings
+pub unsafe trait AsResourceNamePtr {
+    /// Borrow `self` as a raw, possibly tagged [LPCWSTR]
+    ///
+    /// [LPCWSTR]:  https://docs.microsoft.com/en-us/windows/win32/learnwin32/working-with-strings
+    fn as_resource_name_ptr(&self) -> LPCWSTR;
+}
+
+unsafe impl<S: AsRef<WStr>> AsResourceNamePtr for ResourceName<S> {
+    fn as_resource_name_ptr(&self) -> LPCWSTR { self.as_lpcwstr() }
+}
+
+unsafe impl<W: AsRef<WStr>> AsResourceNamePtr for W {
+    fn as_resource_name_ptr(&self) -> LPCWSTR { self.as_ref().as_lpcwstr() }
+}
+
+
+
+#[test] fn tagged_pointers() {
+    let name = wstr!("NAME");
+    let p = ResourceName::Name(name).as_lpcwstr();
+    assert!(!ResourceName::is_intresource(p));
+    assert_eq!(unsafe { ResourceName::from_lpcwstr(&p) }, Some(ResourceName::Name(name)));
+    assert_eq!(ResourceName::id_from_lpcwstr(p), None);
+
+    for &id in &[1, 0x7FFF, 0xFFFF] {
+        let p = ResourceName::from_id(id).as_lpcwstr();
+        assert!(ResourceName::is_intresource(p));
+        assert_eq!(unsafe { ResourceName::from_lpcwstr(&p) }, Some(ResourceName::Id(id)));
+        assert_eq!(ResourceName::id_from_lpcwstr(p), Some(id));
+    }
+
+    let null = std::ptr::null();
+    assert!(ResourceName::is_intresource(null));
+    assert_eq!(unsafe { ResourceName::from_lpcwstr(&null) }, None);
+    assert_eq!(ResourceName::id_from_lpcwstr(null), None);
+    assert_eq!(ResourceName::id_from_lpcwstr(0x10000 as LPCWSTR), None);
+}
@@ -0,0 +1,120 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+
+#[cfg(windows)] use std::ffi::{OsStr, OsString};
+#[cfg(windows)] use std::path::{Path, PathBuf};
+
+
+
+impl<S> From<u16> for ResourceName<S> { fn from(id: u16) -> Self { ResourceName::Id(id) } }
+
+impl ResourceName<&'static WStr> {
+    /// Create a [ResourceName::Id], without having to specify the string type (`MAKEINTRESOURCEW(id)`.)
+    pub const fn from_id(id: u16) -> Self { ResourceName::Id(id) }
+}
+
+impl<S: AsRef<WStr>> ResourceName<S> {
+    /// Borrow the name as a [ResourceName]&lt;&amp;[WStr]&gt;.
+    pub fn as_name_ref(&self) -> ResourceName<&WStr> {
+        match self {
+            ResourceName::Id(id)    => ResourceName::Id(*id),
+            ResourceName::Name(s)   => ResourceName::Name(s.as_ref()),
+        }
+    }
+
+    /// Returns the integer ID, if this is a [ResourceName::Id].
+    pub fn id(&self) -> Option<u16> { match self { ResourceName::Id(id) => Some(*id), ResourceName::Name(_) => None } }
+
+    /// Returns the string name, if this is a [ResourceName::Name].
+    pub fn name(&self) -> Option<&WStr> { match self { ResourceName::Id(_) => None, ResourceName::Name(s) => Some(s.as_ref()) } }
+
+    /// Returns the integer ID, also parsing `"#123"`-style names the way `FindResourceW` does.
+    pub fn to_id(&self) -> Option<u16> {
+        let units = match self { ResourceName::Id(id) => return Some(*id), ResourceName::Name(s) => s.as_ref().units() };
+        let (&hash, digits) = units.split_first()?;
+        if hash != u16::from(b'#') || digits.is_empty() { return None; }
+        digits.iter().try_fold(0u16, |id, &u| {
+            let digit = u.checked_sub(u16::from(b'0')).filter(|&d| d < 10)?;
+            id.checked_mul(10)?.checked_add(digit)
+        })
+    }
+
+    /// Copy the name into a [ResourceName]&lt;[WString]&gt;.
+    pub fn to_owned_name(&self) -> ResourceName<WString> {
+        match self {
+            ResourceName::Id(id)    => ResourceName::Id(*id),
+            ResourceName::Name(s)   => ResourceName::Name(WString::from_wstr(s)),
+        }
+    }
+}
+
+
+
+/// Similar to `Into<ResourceName<WString>>`, except that this won't require copying `&WStr` or `&WString` arguments.
+///
+/// Conversions panic if the string contains interior `'\0'`s, which would otherwise silently truncate the string.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// fn find_resource(name: impl IntoResourceName) -> usize {
+///     let name = IntoResourceName::into(name);
+///     name.as_resource_name_ptr() as usize // FindResourceW(hmodule, name.as_resource_name_ptr(), ...)
+/// }
+///
+/// assert_eq!(find_resource(ResourceName::from_id(42)), 42);
+/// assert!(find_resource("NAME") > 0xFFFF);
+/// assert!(find_resource(wstr!("NAME")) > 0xFFFF);
+/// assert!(find_resource(ResourceName::Name("NAME")) > 0xFFFF);
+/// ```
+pub trait IntoResourceName {
+    type ResourceName : AsResourceNamePtr;
+    fn into(self) -> Self::ResourceName;
+}
+
+impl<W: AsRef<WStr>> IntoResourceName for W {
+    type ResourceName = Self;
+    fn into(self) -> Self { self }
+}
+
+impl<S: IntoWStr> IntoResourceName for ResourceName<S> {
+    type ResourceName = ResourceName<S::WStr>;
+    fn into(self) -> Self::ResourceName {
+        match self {
+            ResourceName::Id(id)    => ResourceName::Id(id),
+            ResourceName::Name(s)   => ResourceName::Name(IntoWStr::into(s)),
+        }
+    }
+}
+
+impl IntoResourceName for  String   { type ResourceName = WString; fn into(self) -> WString { WString::from_str(&self).unwrap() } }
+impl IntoResourceName for &String   { type ResourceName = WString; fn into(self) -> WString { WString::from_str( self).unwrap() } }
+impl IntoResourceName for &str      { type ResourceName = WString; fn into(self) -> WString { WString::from_str( self).unwrap() } }
+#[cfg(windows)] impl IntoResourceName for  OsString { type ResourceName = WString; fn into(self) -> WString { WString::from_osstr(&self).unwrap() } }
+#[cfg(windows)] impl IntoResourceName for &OsString { type ResourceName = WString; fn into(self) -> WString { WString::from_osstr( self).unwrap() } }
+#[cfg(windows)] impl IntoResourceName for &OsStr    { type ResourceName = WString; fn into(self) -> WString { WString::from_osstr( self).unwrap() } }
+#[cfg(windows)] impl IntoResourceName for  PathBuf  { type ResourceName = WString; fn into(self) -> WString { WString::from_osstr(&self).unwrap() } }
+#[cfg(windows)] impl IntoResourceName for &PathBuf  { type ResourceName = WString; fn into(self) -> WString { WString::from_osstr( self).unwrap() } }
+#[cfg(windows)] impl IntoResourceName for &Path     { type ResourceName = WString; fn into(self) -> WString { WString::from_osstr( self).unwrap() } }
+
+
+
+#[test] fn conversions() {
+    let name = ResourceName::Name(WString::from_str("#123").unwrap());
+    assert_eq!(name.to_id(), Some(123));
+    assert_eq!(name.id(), None);
+    assert_eq!(name.name().unwrap(), "#123");
+    assert_eq!(name.as_name_ref().to_owned_name(), name);
+    assert_eq!(ResourceName::<WString>::from(7).to_id(), Some(7));
+    assert_eq!(ResourceName::Name(wstr!("#65535")).to_id(), Some(65535));
+    assert_eq!(ResourceName::Name(wstr!("#65536")).to_id(), None);
+    assert_eq!(ResourceName::Name(wstr!("#")).to_id(), None);
+    assert_eq!(ResourceName::Name(wstr!("#1a")).to_id(), None);
+    assert_eq!(ResourceName::Name(wstr!("123")).to_id(), None);
+
+    assert_eq!(IntoResourceName::into(ResourceName::from_id(5)).as_resource_name_ptr() as usize, 5);
+    let name = IntoResourceName::into(ResourceName::Name("abc"));
+    assert_eq!(name.name().unwrap(), "abc");
+    assert_eq!(name.as_resource_name_ptr(), name.name().unwrap().as_lpcwstr());
+}
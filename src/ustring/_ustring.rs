@@ -0,0 +1,19 @@
+#[cfg(doc)]
+#[macro_export]
+/// Create a [UnicodeStr]&lt;'static&gt; literal at compile time
+macro_rules! ustr {
+    ( $string:literal ) => {
+        $crate::winstr_macros::ustr_impl!(($crate) $string)
+    };
+}
+
+#[cfg(not(doc))] // use wildcards for better error messages from proc macro
+#[macro_export]
+macro_rules! ustr {
+    ( $($tt:tt)+ ) => {
+        $crate::winstr_macros::ustr_impl!(($crate) $($tt)+)
+    };
+}
+
+mod danger; pub use danger::*;
+mod safer;
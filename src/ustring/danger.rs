@@ -0,0 +1,381 @@
+// The dangerous bits of the UNICODE_STRING / ANSI_STRING wrappers.  Only code within this module has access to the
+// header fields, hopefully making it simpler to audit access / verify struct invariants are held.
+
+#![allow(unsafe_code)]
+
+#[cfg(windows)] use winapi::shared::ntdef::{PCUNICODE_STRING, PUNICODE_STRING, ANSI_STRING, UNICODE_STRING};
+
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::ptr::null;
+
+
+
+/// ### Length Invariants
+/// `UNICODE_STRING` and `ANSI_STRING` are *counted* strings:
+/// * `Length` and `MaximumLength` are measured in **bytes**, not [code unit]s
+/// * `Length` is a multiple of the code unit size, and never exceeds `MaximumLength`
+/// * The string is **not** necessarily `0`-terminated, and may contain interior `0`s
+/// * The `u16` fields limit a `UNICODE_STRING` to 32767 [u16]s, and an `ANSI_STRING` to 65535 [u8]s
+///
+/// ### Capacity Invariants
+/// * `MaximumLength` is the size of `Buffer` - APIs writing *into* a counted string may fill up to `MaximumLength` bytes,
+///   and update `Length` to match.
+/// * Capacity past `Length` might hold a terminal `0`, or garbage - don't rely on it.
+///
+/// ### Pointer Invariants
+/// * `Buffer` may be null only if `MaximumLength` is `0`.
+///
+/// [code unit]:    https://unicode.org/glossary/#code_unit
+mod invariants {}
+
+
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for u8  {}
+    impl Sealed for u16 {}
+}
+
+/// A [code unit] of a counted string: [u16] for `UNICODE_STRING`s, [u8] for `ANSI_STRING`s.
+///
+/// [code unit]:    https://unicode.org/glossary/#code_unit
+pub trait CountedUnit : sealed::Sealed + Copy + Default + Ord + std::hash::Hash + 'static {
+    /// The maximum number of [code unit]s a counted string can hold - `0xFFFF` bytes worth.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    const MAX_LEN : usize;
+}
+
+impl CountedUnit for u8  { const MAX_LEN : usize = 0xFFFF; }
+impl CountedUnit for u16 { const MAX_LEN : usize = 0x7FFF; }
+
+/// A borrowed `UNICODE_STRING`.  See [CountedStr].
+pub type UnicodeStr<'s> = CountedStr<'s, u16>;
+
+/// A borrowed `ANSI_STRING`.  See [CountedStr].
+pub type AnsiStr<'s> = CountedStr<'s, u8>;
+
+/// An owned `UNICODE_STRING`.  See [CountedString].
+pub type UnicodeString = CountedString<u16>;
+
+/// An owned `ANSI_STRING`.  See [CountedString].
+pub type AnsiString = CountedString<u8>;
+
+
+
+/// `CountedStr` is a borrowed, length-prefixed string header - ABI compatible with `UNICODE_STRING` ([UnicodeStr]) or
+/// `ANSI_STRING` ([AnsiStr]).
+///
+/// Unlike &amp;[BStr](crate::BStr) or &amp;[WStr](crate::WStr), the header is a small value distinct from the string data,
+/// so `CountedStr` is [Copy] and borrows the data for `'s`.  Pass `&header` or [as_punicode_string](#method.as_punicode_string)
+/// to `PCUNICODE_STRING` parameters.
+#[repr(C)] pub struct CountedStr<'s, U: CountedUnit> {
+    length:         u16,
+    maximum_length: u16,
+    buffer:         *const U,
+    phantom:        PhantomData<&'s [U]>,
+}
+
+// CountedStr is a glorified &'s [U], so it should be Send and Sync just like &'s [U] is.
+unsafe impl<U: CountedUnit> Send for CountedStr<'_, U> {}
+unsafe impl<U: CountedUnit> Sync for CountedStr<'_, U> {}
+
+impl<U: CountedUnit> Clone for CountedStr<'_, U> { fn clone(&self) -> Self { *self } }
+impl<U: CountedUnit> Copy  for CountedStr<'_, U> {}
+
+impl<'s, U: CountedUnit> CountedStr<'s, U> {
+    /// Borrows `units` as a counted string, without any spare capacity (`MaximumLength == Length`.)
+    ///
+    /// Returns [None] if `units` is longer than [U::MAX_LEN](CountedUnit::MAX_LEN).
+    pub fn from_units(units: &'s [U]) -> Option<Self> { Self::from_parts(units, units.len()) }
+
+    /// Borrows `units0`, minus it's terminal `0`, as a counted string - like `RtlInitUnicodeStringEx` does.
+    /// `MaximumLength` includes the terminal `0` when it fits.  Interior `0`s are allowed.
+    ///
+    /// Returns [None] unless `units0` ends with a `0`, or if the string is longer than [U::MAX_LEN](CountedUnit::MAX_LEN).
+    pub fn from_units0(units0: &'s [U]) -> Option<Self> {
+        let (&last, units) = units0.split_last()?;
+        if last != U::default() { return None; }
+        Self::from_parts(units0, units.len())
+    }
+
+    /// Borrow the first `len` code units of `buffer`, leaving the rest as spare capacity (clamped to [U::MAX_LEN](CountedUnit::MAX_LEN).)
+    fn from_parts(buffer: &'s [U], len: usize) -> Option<Self> {
+        if len > U::MAX_LEN || len > buffer.len() { return None; }
+        let capacity = buffer.len().min(U::MAX_LEN);
+        Some(Self {
+            length:         (len        * size_of::<U>()) as u16,
+            maximum_length: (capacity   * size_of::<U>()) as u16,
+            buffer:         buffer.as_ptr(),
+            phantom:        PhantomData,
+        })
+    }
+
+    /// Borrows a raw counted string header's fields as a [CountedStr].
+    ///
+    /// Returns [None] if `length` isn't a multiple of the code unit size, exceeds `maximum_length`, or if `buffer` is
+    /// null despite a non-zero `maximum_length`.
+    ///
+    /// ### Safety
+    ///
+    /// * `buffer` must be valid for reads of `maximum_length` bytes for the duration of `'s`.
+    pub unsafe fn from_raw_parts(length: u16, maximum_length: u16, buffer: *const U) -> Option<Self> {
+        if length as usize & (size_of::<U>() - 1) != 0 || length > maximum_length || (buffer.is_null() && maximum_length != 0) { return None; }
+        Some(Self { length, maximum_length, buffer, phantom: PhantomData })
+    }
+
+    /// `Length` - the length of the string in **bytes**.
+    pub fn len_bytes(&self) -> u16 { self.length }
+
+    /// `MaximumLength` - the size of the buffer in **bytes**.
+    pub fn capacity_bytes(&self) -> u16 { self.maximum_length }
+
+    /// The [code unit]s of the string.  May contain interior `0`s, and is **not** `0`-terminated.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn units(&self) -> &'s [U] {
+        if self.length == 0 { return &[]; }
+        // Safe: `buffer` is non-null and valid for `maximum_length >= length` bytes per struct invariants
+        unsafe { std::slice::from_raw_parts(self.buffer, self.length as usize / size_of::<U>()) }
+    }
+}
+
+impl<'s> CountedStr<'s, u16> {
+    #[doc(hidden)]
+    pub fn ustr_macro_impl_detail(data: &'static [u16]) -> UnicodeStr<'static> {
+        UnicodeStr::from_units0(data).expect("`data` was supposed to be `\0`-terminated and no longer than 32767 code units")
+    }
+
+    /// Borrows a `UNICODE_STRING` as a [UnicodeStr].
+    ///
+    /// Returns [None] if `us` violates the [length or pointer invariants](#method.from_raw_parts).
+    ///
+    /// ### Safety
+    ///
+    /// * `us.Buffer` must be valid for reads of `us.MaximumLength` bytes for the duration of `'s`.
+    #[cfg(windows)]
+    pub unsafe fn from_unicode_string(us: &'s UNICODE_STRING) -> Option<Self> {
+        Self::from_raw_parts(us.Length, us.MaximumLength, us.Buffer)
+    }
+
+    /// The `UnicodeStr` as a winapi-friendly `PCUNICODE_STRING`.
+    ///
+    /// ### Safety
+    ///
+    /// * The returned pointer is only valid for as long as `self` is - which may be shorter than `'s`!
+    /// * It is **not** safe to modify the header, or the contents of the string, through the returned pointer!
+    #[cfg(windows)]
+    pub fn as_punicode_string(&self) -> PCUNICODE_STRING { self as *const Self as PCUNICODE_STRING }
+}
+
+impl<'s> CountedStr<'s, u8> {
+    /// Borrows an `ANSI_STRING` as an [AnsiStr].
+    ///
+    /// Returns [None] if `s` violates the [length or pointer invariants](#method.from_raw_parts).
+    ///
+    /// ### Safety
+    ///
+    /// * `s.Buffer` must be valid for reads of `s.MaximumLength` bytes for the duration of `'s`.
+    #[cfg(windows)]
+    pub unsafe fn from_ansi_string(s: &'s ANSI_STRING) -> Option<Self> {
+        Self::from_raw_parts(s.Length, s.MaximumLength, s.Buffer as *const u8)
+    }
+
+    /// The `AnsiStr` as a winapi-friendly `PCANSI_STRING` (`*const ANSI_STRING`.)
+    ///
+    /// ### Safety
+    ///
+    /// * The returned pointer is only valid for as long as `self` is - which may be shorter than `'s`!
+    /// * It is **not** safe to modify the header, or the contents of the string, through the returned pointer!
+    #[cfg(windows)]
+    pub fn as_pansi_string(&self) -> *const ANSI_STRING { self as *const Self as *const ANSI_STRING }
+}
+
+
+
+/// `CountedString` is an owned, fixed capacity, counted string - ABI compatible with `UNICODE_STRING`
+/// ([UnicodeString]) or `ANSI_STRING` ([AnsiString]).
+///
+/// The buffer is allocated once, up front, and never reallocated - spare capacity (`MaximumLength - Length`) can be
+/// filled in place, either by Rust code via [spare_capacity_mut](#method.spare_capacity_mut) +
+/// [set_len](#method.set_len), or by APIs writing through [as_mut_punicode_string](#method.as_mut_punicode_string).
+pub struct CountedString<U: CountedUnit> {
+    header: CountedStr<'static, U>, // `buffer` points into `units` - never expose this `'static` lifetime!
+    units:  Box<[U]>,
+}
+
+// CountedString owns it's Box<[U]>, so it should be Send and Sync just like the Box is.
+unsafe impl<U: CountedUnit> Send for CountedString<U> {}
+unsafe impl<U: CountedUnit> Sync for CountedString<U> {}
+
+impl<U: CountedUnit> CountedString<U> {
+    /// Create an empty counted string with room for `capacity` code units.
+    ///
+    /// Returns [None] if `capacity` exceeds [U::MAX_LEN](CountedUnit::MAX_LEN).
+    pub fn with_capacity(capacity: usize) -> Option<Self> {
+        if capacity > U::MAX_LEN { return None; }
+        let mut units = vec![U::default(); capacity].into_boxed_slice();
+        let header = CountedStr {
+            length:         0,
+            maximum_length: (capacity * size_of::<U>()) as u16,
+            buffer:         if capacity == 0 { null() } else { units.as_mut_ptr() }, // APIs may write through this
+            phantom:        PhantomData,
+        };
+        Some(Self { header, units })
+    }
+
+    /// Length in code units.  This is O(1).
+    pub fn len(&self) -> usize {
+        // Clamped, in case an API wrote nonsense through `as_mut_p*_string()`
+        (self.header.length as usize / size_of::<U>()).min(self.units.len())
+    }
+
+    /// Capacity in code units (`MaximumLength`, in code units instead of bytes.)  This is O(1).
+    pub fn capacity(&self) -> usize { self.units.len() }
+
+    /// Change the length of the string.  Any newly included code units come from the spare capacity, which is
+    /// always initialized (either `0`s, or whatever was last written there.)
+    ///
+    /// ### Panics
+    ///
+    /// * If `len` exceeds [capacity](#method.capacity)
+    pub fn set_len(&mut self, len: usize) {
+        assert!(len <= self.capacity(), "CountedString::set_len({}) exceeds capacity {}", len, self.capacity());
+        self.header.length = (len * size_of::<U>()) as u16;
+    }
+
+    /// The code units of the string
+    pub fn units(&self) -> &[U] { &self.units[..self.len()] }
+
+    /// The code units of the string, mutably
+    pub fn units_mut(&mut self) -> &mut [U] { let len = self.len(); &mut self.units[..len] }
+
+    /// The unused capacity following the string
+    pub fn spare_capacity_mut(&mut self) -> &mut [U] { let len = self.len(); &mut self.units[len..] }
+
+    /// Borrow the string as a [CountedStr], including it's spare capacity.
+    pub fn as_counted_str(&self) -> CountedStr<'_, U> {
+        CountedStr::from_parts(&self.units, self.len()).expect("capacity is limited to U::MAX_LEN by construction")
+    }
+
+    /// Restore `Buffer` and `MaximumLength` in case anyone scribbled over them.
+    ///
+    /// `Buffer` is re-derived from `&mut self.units`, so APIs may write through it.
+    #[cfg(any(windows, test))]
+    fn header_mut(&mut self) -> &mut CountedStr<'static, U> {
+        self.header.buffer          = if self.units.is_empty() { null() } else { self.units.as_mut_ptr() };
+        self.header.maximum_length  = (self.units.len() * size_of::<U>()) as u16;
+        self.header.length          = self.header.length.min(self.header.maximum_length);
+        &mut self.header
+    }
+}
+
+impl CountedString<u16> {
+    /// The `UnicodeString` as a winapi-friendly `PUNICODE_STRING`, for APIs that write into an existing buffer
+    /// (`RtlAppendUnicodeToString`, `RtlUpcaseUnicodeString(..., FALSE)`, `NtQueryObject`, ...)
+    ///
+    /// ### Safety
+    ///
+    /// * APIs may write up to `MaximumLength` bytes to `Buffer`, and update `Length` to match.
+    /// * APIs **must not** replace `Buffer` (e.g. `RtlUpcaseUnicodeString(..., TRUE)`) - that would leak, and be ignored.
+    #[cfg(windows)]
+    pub fn as_mut_punicode_string(&mut self) -> PUNICODE_STRING { self.header_mut() as *mut CountedStr<u16> as PUNICODE_STRING }
+}
+
+impl CountedString<u8> {
+    /// The `AnsiString` as a winapi-friendly `PANSI_STRING` (`*mut ANSI_STRING`), for APIs that write into an existing buffer
+    /// (`RtlUnicodeStringToAnsiString(..., FALSE)`, ...)
+    ///
+    /// ### Safety
+    ///
+    /// * APIs may write up to `MaximumLength` bytes to `Buffer`, and update `Length` to match.
+    /// * APIs **must not** replace `Buffer` (e.g. `RtlUnicodeStringToAnsiString(..., TRUE)`) - that would leak, and be ignored.
+    #[cfg(windows)]
+    pub fn as_mut_pansi_string(&mut self) -> *mut ANSI_STRING { self.header_mut() as *mut CountedStr<u8> as *mut ANSI_STRING }
+}
+
+
+
+#[test] fn layout() {
+    use std::mem::align_of;
+
+    // { USHORT Length; USHORT MaximumLength; PWSTR Buffer; }
+    let ptr = size_of::<*const u16>();
+    assert_eq!(size_of::<UnicodeStr>(),     2 * ptr);
+    assert_eq!(size_of::<AnsiStr>(),        2 * ptr);
+    assert_eq!(align_of::<UnicodeStr>(),    align_of::<*const u16>());
+    assert_eq!(align_of::<AnsiStr>(),       align_of::<*const u8>());
+
+    let units = [1u16, 2, 3, 0];
+    let s = UnicodeStr::from_units0(&units).unwrap();
+    let base = &s as *const UnicodeStr as usize;
+    assert_eq!(&s.length            as *const u16 as usize - base, 0);
+    assert_eq!(&s.maximum_length    as *const u16 as usize - base, 2);
+    assert_eq!(&s.buffer            as *const *const u16 as usize - base, ptr);
+    assert_eq!((s.length, s.maximum_length, s.buffer), (6, 8, units.as_ptr()));
+
+    #[cfg(windows)] {
+        assert_eq!(size_of::<UnicodeStr>(),     size_of::<UNICODE_STRING>());
+        assert_eq!(align_of::<UnicodeStr>(),    align_of::<UNICODE_STRING>());
+        assert_eq!(size_of::<AnsiStr>(),        size_of::<ANSI_STRING>());
+        assert_eq!(align_of::<AnsiStr>(),       align_of::<ANSI_STRING>());
+        let us = unsafe { &*s.as_punicode_string() };
+        assert_eq!((us.Length, us.MaximumLength, us.Buffer as *const u16), (6, 8, units.as_ptr()));
+    }
+}
+
+#[test] fn core_apis() {
+    let units = [b'a' as u16, 0, b'c' as u16, 0];
+    let a = UnicodeStr::from_units0(&units).unwrap();
+    assert_eq!(a.units(), &units[..3]);
+    assert_eq!((a.len_bytes(), a.capacity_bytes()), (6, 8));
+    let b = UnicodeStr::from_units(&units[..2]).unwrap();
+    assert_eq!((b.len_bytes(), b.capacity_bytes()), (4, 4));
+    assert!(UnicodeStr::from_units0(&units[..3]).is_none());
+    assert!(UnicodeStr::from_units0(&[]).is_none());
+    assert!(UnicodeStr::from_units0(&[0]).unwrap().units().is_empty());
+
+    let max = vec![0u16; 0x8000];
+    assert!(UnicodeStr::from_units(&max).is_none());
+    let m = UnicodeStr::from_units0(&max).unwrap(); // 32767 chars + `0` - terminator no longer fits within `MaximumLength`
+    assert_eq!((m.len_bytes(), m.capacity_bytes()), (0xFFFE, 0xFFFE));
+    assert!(AnsiStr::from_units(&[0u8; 0xFFFF]).is_some());
+    assert!(AnsiStr::from_units(&[0u8; 0x10000]).is_none());
+
+    unsafe {
+        assert!(UnicodeStr::from_raw_parts(0, 0, null()).unwrap().units().is_empty());
+        assert!(UnicodeStr::from_raw_parts(0, 2, null()).is_none());
+        assert!(UnicodeStr::from_raw_parts(3, 4, units.as_ptr()).is_none());
+        assert!(UnicodeStr::from_raw_parts(6, 4, units.as_ptr()).is_none());
+        assert_eq!(UnicodeStr::from_raw_parts(4, 8, units.as_ptr()).unwrap().units(), &units[..2]);
+        assert_eq!(AnsiStr::from_raw_parts(3, 3, b"abc".as_ptr()).unwrap().units(), b"abc");
+    }
+}
+
+#[test] fn capacity() {
+    let mut s = UnicodeString::with_capacity(4).unwrap();
+    assert_eq!((s.len(), s.capacity()), (0, 4));
+    assert!(UnicodeString::with_capacity(0x8000).is_none());
+    assert!(AnsiString::with_capacity(0xFFFF).is_some());
+
+    s.spare_capacity_mut()[..2].copy_from_slice(&[b'h' as u16, b'i' as u16]);
+    s.set_len(2);
+    assert_eq!(s.units(), &[b'h' as u16, b'i' as u16]);
+    assert_eq!(s.spare_capacity_mut().len(), 2);
+    s.units_mut()[0] = b'H' as u16;
+    let c = s.as_counted_str();
+    assert_eq!((c.len_bytes(), c.capacity_bytes()), (4, 8));
+    assert_eq!(c.units(), &[b'H' as u16, b'i' as u16]);
+
+    // Emulate an API scribbling over the header
+    s.header.length = 0xFFFE;
+    assert_eq!(s.len(), 4);
+    s.header.buffer = null();
+    let header = unsafe { &*(s.header_mut() as *const CountedStr<u16>) };
+    assert_eq!((header.length, header.maximum_length, header.buffer), (8, 8, s.units.as_ptr()));
+}
+
+#[test] #[should_panic] fn set_len_past_capacity() {
+    UnicodeString::with_capacity(4).unwrap().set_len(5);
+}
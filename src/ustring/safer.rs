@@ -0,0 +1,316 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::ffi::CStr;
+use std::fmt::{self, Debug, Display, Formatter, Write};
+use std::hash::{Hash, Hasher};
+#[cfg(windows)] use std::ffi::{OsStr, OsString};
+#[cfg(windows)] use std::os::windows::ffi::{OsStrExt, OsStringExt};
+#[cfg(windows)] use std::path::{Path, PathBuf};
+
+
+
+#[cfg(feature = "display")]
+impl Display                                for UnicodeStr<'_>      { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&self.to_string_lossy(), fmt) } }
+impl Debug                                  for UnicodeStr<'_>      { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { debug_utf16(self.units(), fmt) } }
+impl Debug                                  for AnsiStr<'_>         { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { debug_bytes(self.units(), fmt) } }
+impl<U: CountedUnit> Default                for CountedStr<'_, U>   { fn default() -> Self { Self::from_units(&[]).unwrap() } }
+impl<U: CountedUnit> AsRef<[U]>             for CountedStr<'_, U>   { fn as_ref(&self) -> &[U] { self.units() } }
+impl<U: CountedUnit> Eq                     for CountedStr<'_, U>   {}
+impl<U: CountedUnit> Ord                    for CountedStr<'_, U>   { fn cmp(&self, other: &Self) -> Ordering { self.units().cmp(other.units()) } }
+impl<U: CountedUnit> Hash                   for CountedStr<'_, U>   { fn hash<H: Hasher>(&self, state: &mut H) { self.units().hash(state) } }
+
+#[cfg(feature = "display")]
+impl Display                                for UnicodeString       { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&self.as_counted_str(), fmt) } }
+impl Debug                                  for UnicodeString       { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(&self.as_counted_str(), fmt) } }
+impl Debug                                  for AnsiString          { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(&self.as_counted_str(), fmt) } }
+impl<U: CountedUnit> Default                for CountedString<U>    { fn default() -> Self { Self::with_capacity(0).unwrap() } }
+impl<U: CountedUnit> AsRef<[U]>             for CountedString<U>    { fn as_ref(&self) -> &[U] { self.units() } }
+impl<U: CountedUnit> Clone                  for CountedString<U>    { fn clone(&self) -> Self { let mut c = Self::with_capacity(self.capacity()).unwrap(); c.spare_capacity_mut()[..self.len()].copy_from_slice(self.units()); c.set_len(self.len()); c } }
+impl<U: CountedUnit> From<CountedStr<'_, U>> for CountedString<U>   { fn from(value: CountedStr<'_, U>) -> Self { Self::from_units(value.units()).unwrap() } }
+impl<U: CountedUnit> Eq                     for CountedString<U>    {}
+impl<U: CountedUnit> Ord                    for CountedString<U>    { fn cmp(&self, other: &Self) -> Ordering { self.units().cmp(other.units()) } }
+impl<U: CountedUnit> Hash                   for CountedString<U>    { fn hash<H: Hasher>(&self, state: &mut H) { self.units().hash(state) } }
+
+impl<U: CountedUnit> PartialEq <CountedStr<'_, U>>  for CountedStr<'_, U>   { fn eq(&self, other: &CountedStr<'_, U>) -> bool { self.units() == other.units() } }
+impl<U: CountedUnit> PartialEq <CountedString<U>>   for CountedStr<'_, U>   { fn eq(&self, other: &CountedString<U>) -> bool { self.units() == other.units() } }
+impl<U: CountedUnit> PartialEq <CountedStr<'_, U>>  for CountedString<U>    { fn eq(&self, other: &CountedStr<'_, U>) -> bool { self.units() == other.units() } }
+impl<U: CountedUnit> PartialEq <CountedString<U>>   for CountedString<U>    { fn eq(&self, other: &CountedString<U>) -> bool { self.units() == other.units() } }
+impl<U: CountedUnit> PartialOrd<CountedStr<'_, U>>  for CountedStr<'_, U>   { fn partial_cmp(&self, other: &CountedStr<'_, U>) -> Option<Ordering> { self.units().partial_cmp(other.units()) } }
+impl<U: CountedUnit> PartialOrd<CountedString<U>>   for CountedStr<'_, U>   { fn partial_cmp(&self, other: &CountedString<U>) -> Option<Ordering> { self.units().partial_cmp(other.units()) } }
+impl<U: CountedUnit> PartialOrd<CountedStr<'_, U>>  for CountedString<U>    { fn partial_cmp(&self, other: &CountedStr<'_, U>) -> Option<Ordering> { self.units().partial_cmp(other.units()) } }
+impl<U: CountedUnit> PartialOrd<CountedString<U>>   for CountedString<U>    { fn partial_cmp(&self, other: &CountedString<U>) -> Option<Ordering> { Some(self.cmp(other)) } }
+
+impl PartialEq<[u8]>            for AnsiStr<'_>     { fn eq(&self, other: &[u8]         ) -> bool { self.units() == other } }
+impl PartialEq<&[u8]>           for AnsiStr<'_>     { fn eq(&self, other: &&[u8]        ) -> bool { self.units() == *other } }
+impl PartialEq<AnsiStr<'_>>     for [u8]            { fn eq(&self, other: &AnsiStr<'_>  ) -> bool { self == other.units() } }
+impl PartialEq<AnsiStr<'_>>     for &[u8]           { fn eq(&self, other: &AnsiStr<'_>  ) -> bool { *self == other.units() } }
+impl PartialEq<[u8]>            for AnsiString      { fn eq(&self, other: &[u8]         ) -> bool { self.units() == other } }
+impl PartialEq<&[u8]>           for AnsiString      { fn eq(&self, other: &&[u8]        ) -> bool { self.units() == *other } }
+impl PartialEq<AnsiString>      for [u8]            { fn eq(&self, other: &AnsiString   ) -> bool { self == other.units() } }
+impl PartialEq<AnsiString>      for &[u8]           { fn eq(&self, other: &AnsiString   ) -> bool { *self == other.units() } }
+
+peo!(&?[u16],           UnicodeStr<'_>  ); peo!(UnicodeStr<'_>, &?[u16]         );
+peo!(&?str,             UnicodeStr<'_>  ); peo!(UnicodeStr<'_>, &?str           );
+peo!(String,            UnicodeStr<'_>  ); peo!(UnicodeStr<'_>, String          );
+peo!(Cow<'_, [u16]>,    UnicodeStr<'_>  ); peo!(UnicodeStr<'_>, Cow<'_, [u16]>  );
+peo!(Cow<'_, str>,      UnicodeStr<'_>  ); peo!(UnicodeStr<'_>, Cow<'_, str>    );
+
+peo!(&?[u16],           UnicodeString   ); peo!(UnicodeString,  &?[u16]         );
+peo!(&?str,             UnicodeString   ); peo!(UnicodeString,  &?str           );
+peo!(String,            UnicodeString   ); peo!(UnicodeString,  String          );
+peo!(Cow<'_, [u16]>,    UnicodeString   ); peo!(UnicodeString,  Cow<'_, [u16]>  );
+peo!(Cow<'_, str>,      UnicodeString   ); peo!(UnicodeString,  Cow<'_, str>    );
+
+#[cfg(feature = "wstr")] mod wstr_peo {
+    use super::*;
+    peo!(&WStr,             UnicodeStr<'_>  ); peo!(UnicodeStr<'_>, &WStr           );
+    peo!(WString,           UnicodeStr<'_>  ); peo!(UnicodeStr<'_>, WString         );
+    peo!(&WStr,             UnicodeString   ); peo!(UnicodeString,  &WStr           );
+    peo!(WString,           UnicodeString   ); peo!(UnicodeString,  WString         );
+}
+
+#[cfg(windows)] mod windows_peo {
+    use super::*;
+    peo!(&?OsStr,           UnicodeStr<'_>  ); peo!(UnicodeStr<'_>, &?OsStr         );
+    peo!(OsString,          UnicodeStr<'_>  ); peo!(UnicodeStr<'_>, OsString        );
+    peo!(&?Path,            UnicodeStr<'_>  ); peo!(UnicodeStr<'_>, &?Path          );
+    peo!(PathBuf,           UnicodeStr<'_>  ); peo!(UnicodeStr<'_>, PathBuf         );
+    peo!(Cow<'_, OsStr>,    UnicodeStr<'_>  ); peo!(UnicodeStr<'_>, Cow<'_, OsStr>  );
+    peo!(Cow<'_, Path>,     UnicodeStr<'_>  ); peo!(UnicodeStr<'_>, Cow<'_, Path>   );
+
+    peo!(&?OsStr,           UnicodeString   ); peo!(UnicodeString,  &?OsStr         );
+    peo!(OsString,          UnicodeString   ); peo!(UnicodeString,  OsString        );
+    peo!(&?Path,            UnicodeString   ); peo!(UnicodeString,  &?Path          );
+    peo!(PathBuf,           UnicodeString   ); peo!(UnicodeString,  PathBuf         );
+    peo!(Cow<'_, OsStr>,    UnicodeString   ); peo!(UnicodeString,  Cow<'_, OsStr>  );
+    peo!(Cow<'_, Path>,     UnicodeString   ); peo!(UnicodeString,  Cow<'_, Path>   );
+
+    #[cfg(feature = "bstr")] peo!(&BStr,    UnicodeStr<'_>  ); #[cfg(feature = "bstr")] peo!(UnicodeStr<'_>, &BStr   );
+    #[cfg(feature = "bstr")] peo!(BString,  UnicodeStr<'_>  ); #[cfg(feature = "bstr")] peo!(UnicodeStr<'_>, BString );
+    #[cfg(feature = "bstr")] peo!(&BStr,    UnicodeString   ); #[cfg(feature = "bstr")] peo!(UnicodeString,  &BStr   );
+    #[cfg(feature = "bstr")] peo!(BString,  UnicodeString   ); #[cfg(feature = "bstr")] peo!(UnicodeString,  BString );
+}
+
+
+
+impl<'s> UTF16ish<'s> for UnicodeStr<'_> {
+    type Iter = std::iter::Copied<std::slice::Iter<'s, u16>>;
+    fn utf16ish(&'s self) -> Self::Iter { self.units().iter().copied() }
+}
+
+impl<'s> UTF16ish<'s> for UnicodeString {
+    type Iter = std::iter::Copied<std::slice::Iter<'s, u16>>;
+    fn utf16ish(&'s self) -> Self::Iter { self.units().iter().copied() }
+}
+
+impl Utf16Hash for UnicodeStr<'_>   { fn utf16_units(&self) -> Utf16Units<'_> { self.units().into() } }
+impl Utf16Hash for UnicodeString    { fn utf16_units(&self) -> Utf16Units<'_> { self.units().into() } }
+
+fn debug_bytes(bytes: &[u8], fmt: &mut Formatter) -> fmt::Result {
+    fmt.write_char('"')?;
+    for ch in bytes.iter().flat_map(|&b| std::ascii::escape_default(b)) { fmt.write_char(ch as char)?; }
+    fmt.write_char('"')
+}
+
+
+
+impl<'s> UnicodeStr<'s> {
+    /// Borrow a &amp;[WStr] as a [UnicodeStr] without copying - like `RtlInitUnicodeStringEx` does.
+    ///
+    /// Returns [None] if `s` is longer than 32767 [code unit]s.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    #[cfg(feature = "wstr")]
+    pub fn from_wstr(s: &'s WStr) -> Option<Self> { Self::from_units0(s.units0()) }
+
+    /// Borrow a &amp;[BStr] as a [UnicodeStr] without copying.  Interior `0u16`s are preserved.
+    ///
+    /// Returns [None] if `s` is longer than 32767 [code unit]s.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    #[cfg(windows)] #[cfg(feature = "bstr")]
+    pub fn from_bstr(s: &'s BStr) -> Option<Self> { Self::from_units0(s.units0()) }
+
+    /// Length in [u16] unicode [code unit]s
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn len(&self) -> usize { self.units().len() }
+
+    /// Returns `true` if the string is empty
+    pub fn is_empty(&self) -> bool { self.units().is_empty() }
+
+    /// Decode the string as UTF-16, replacing invalid data (unpaired surrogates) with [U+FFFD](std::char::REPLACEMENT_CHARACTER)
+    pub fn to_string_lossy(&self) -> String { String::from_utf16_lossy(self.units()) }
+
+    /// Convert the string into an [OsString]
+    #[cfg(windows)]
+    pub fn to_os_string(&self) -> OsString { OsString::from_wide(self.units()) }
+}
+
+impl<'s> AnsiStr<'s> {
+    /// Borrow a &amp;[CStr] as an [AnsiStr] without copying - like `RtlInitAnsiStringEx` does.
+    ///
+    /// Returns [None] if `s` is longer than 65535 bytes.
+    pub fn from_cstr(s: &'s CStr) -> Option<Self> { Self::from_units0(s.to_bytes_with_nul()) }
+
+    /// Length in bytes
+    pub fn len(&self) -> usize { self.units().len() }
+
+    /// Returns `true` if the string is empty
+    pub fn is_empty(&self) -> bool { self.units().is_empty() }
+}
+
+
+
+impl<U: CountedUnit> CountedString<U> {
+    /// Create a counted string from 0 or more code units.  Interior `0`s are allowed.
+    ///
+    /// Like `RtlCreateUnicodeString`, this reserves room for a terminal `0` after the string when it fits.
+    ///
+    /// Returns [None] if `code_units` is longer than [U::MAX_LEN](CountedUnit::MAX_LEN).
+    pub fn from_code_units(code_units: impl Iterator<Item = U>) -> Option<Self> {
+        Self::from_units(&code_units.collect::<Vec<_>>())
+    }
+
+    /// Create a counted string from a slice of code units.  See [from_code_units](#method.from_code_units).
+    pub fn from_units(units: &[U]) -> Option<Self> {
+        if units.len() > U::MAX_LEN { return None; }
+        let mut s = Self::with_capacity((units.len() + 1).min(U::MAX_LEN))?;
+        s.spare_capacity_mut()[..units.len()].copy_from_slice(units);
+        s.set_len(units.len());
+        Some(s)
+    }
+
+    /// Returns `true` if the string is empty
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Empty the string, keeping it's capacity.
+    pub fn clear(&mut self) { self.set_len(0) }
+
+    /// Shorten the string to `len` code units.  Does nothing if the string is already `len` or shorter.
+    pub fn truncate(&mut self, len: usize) { if len < self.len() { self.set_len(len) } }
+
+    /// Append `units` to the string - like `RtlAppendUnicodeStringToString` does.
+    ///
+    /// Returns `false` (leaving `self` unmodified) if there's insufficient spare capacity, where
+    /// `RtlAppendUnicodeStringToString` would return `STATUS_BUFFER_TOO_SMALL`.
+    #[must_use] pub fn push_units(&mut self, units: &[U]) -> bool {
+        let len = self.len();
+        match self.spare_capacity_mut().get_mut(..units.len()) {
+            Some(spare) => spare.copy_from_slice(units),
+            None        => return false,
+        }
+        self.set_len(len + units.len());
+        true
+    }
+}
+
+impl UnicodeString {
+    /// Create a [UnicodeString] from a [str].  Returns [None] if `s` is longer than 32767 [code unit]s.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: impl AsRef<str>) -> Option<Self> { Self::from_code_units(s.as_ref().encode_utf16()) }
+
+    /// Create a [UnicodeString] from a [OsStr].  Returns [None] if `s` is longer than 32767 [code unit]s.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    #[cfg(windows)]
+    pub fn from_osstr(s: impl AsRef<OsStr>) -> Option<Self> { Self::from_code_units(s.as_ref().encode_wide()) }
+
+    /// Create a [UnicodeString] from a [BStr].  Returns [None] if `s` is longer than 32767 [code unit]s.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    #[cfg(windows)] #[cfg(feature = "bstr")]
+    pub fn from_bstr(s: impl AsRef<BStr>) -> Option<Self> { Self::from_units(s.as_ref().units()) }
+
+    /// Create a [UnicodeString] from a [WStr].  Returns [None] if `s` is longer than 32767 [code unit]s.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    #[cfg(feature = "wstr")]
+    pub fn from_wstr(s: impl AsRef<WStr>) -> Option<Self> { Self::from_units(s.as_ref().units()) }
+
+    /// Append a [str] to the string - like `RtlAppendUnicodeToString` does.  See [push_units](#method.push_units).
+    #[must_use] pub fn push_str(&mut self, s: &str) -> bool { self.push_units(&s.encode_utf16().collect::<Vec<_>>()) }
+
+    /// Decode the string as UTF-16, replacing invalid data (unpaired surrogates) with [U+FFFD](std::char::REPLACEMENT_CHARACTER)
+    pub fn to_string_lossy(&self) -> String { String::from_utf16_lossy(self.units()) }
+
+    /// Convert the string into an [OsString]
+    #[cfg(windows)]
+    pub fn to_os_string(&self) -> OsString { OsString::from_wide(self.units()) }
+}
+
+
+
+#[test] fn comparisons() {
+    let a = UnicodeString::from_str("abc").unwrap();
+    let b = a.as_counted_str();
+    let c = ustr!("abc");
+    assert_eq!(a, "abc");
+    assert_eq!(b, "abc");
+    assert_eq!("abc", a);
+    assert_eq!(String::from("abc"), b);
+    assert_eq!(a, b);
+    assert_eq!(b, c);
+    assert_eq!(a, &[b'a' as u16, b'b' as u16, b'c' as u16][..]);
+    assert!(a < *"abd");
+    assert!(c > "ab");
+    assert!(c < ustr!("abcd"));
+    assert_eq!(UnicodeString::from(c), a);
+    assert_eq!(a.clone(), a);
+    assert_eq!(a.to_string_lossy(), "abc");
+    assert_eq!(format!("{:?}", c), "\"abc\"");
+    assert_eq!(format!("{:?}", UnicodeString::from_units(&[b'a' as u16, 0, 0xD800]).unwrap()), "\"a\\0\\u{d800}\"");
+    #[cfg(feature = "display")] assert_eq!(format!("{}", UnicodeString::from_units(&[b'a' as u16, 0xD800]).unwrap()), "a\u{FFFD}");
+    #[cfg(feature = "wstr")] assert_eq!(c, wstr!("abc"));
+    #[cfg(feature = "wstr")] assert_eq!(UnicodeStr::from_wstr(wstr!("abc")).unwrap(), c);
+
+    let d = AnsiString::from_units(b"a\0b").unwrap();
+    assert_eq!(d, &b"a\0b"[..]);
+    assert_eq!(d.as_counted_str(), AnsiStr::from_units(b"a\0b").unwrap());
+    assert_eq!(format!("{:?}", d), "\"a\\x00b\"");
+    let e = AnsiStr::from_cstr(CStr::from_bytes_with_nul(b"ab\0").unwrap()).unwrap();
+    assert_eq!((e.len(), e.capacity_bytes()), (2, 3));
+}
+
+#[test] fn construction() {
+    let a = UnicodeString::from_str("abc").unwrap();
+    assert_eq!((a.len(), a.capacity()), (3, 4)); // room for a terminal `0u16`
+    assert!(UnicodeString::from_units(&[0u16; 0x7FFF]).is_some());
+    assert!(UnicodeString::from_units(&[0u16; 0x8000]).is_none());
+    assert_eq!(UnicodeString::from_units(&[0u16; 0x7FFF]).unwrap().capacity(), 0x7FFF);
+
+    let mut b = UnicodeString::with_capacity(5).unwrap();
+    assert!(b.push_str("ab"));
+    assert!(b.push_str("cd"));
+    assert!(!b.push_str("ef"));
+    assert_eq!(b, "abcd");
+    b.truncate(1);
+    assert_eq!(b, "a");
+    b.clear();
+    assert!(b.is_empty());
+    assert_eq!(UnicodeString::default().capacity(), 0);
+    assert!(UnicodeStr::default().is_empty());
+
+    let c = ustr!("a\0b");
+    assert_eq!((c.len_bytes(), c.capacity_bytes()), (6, 8));
+    assert!(ustr!("").is_empty());
+}
+
+#[cfg(windows)] #[cfg(feature = "bstr")] #[test] fn bstr_conversions() {
+    let a = bstr!("a\0c");
+    let b = UnicodeStr::from_bstr(a).unwrap();
+    assert_eq!(b.units().as_ptr(), a.as_lpcwstr());
+    assert_eq!((b.len_bytes(), b.capacity_bytes()), (6, 8));
+    assert_eq!(a, b);
+    assert_eq!(UnicodeString::from_bstr(a).unwrap(), a);
+    assert_eq!(BString::from_code_units(b.units().iter().copied()).unwrap(), a);
+    assert_eq!(b.to_os_string(), OsStr::new("a\0c"));
+    assert!(UnicodeStr::from_bstr(&BString::from_code_units(vec![b'a' as u16; 0x7FFF].into_iter()).unwrap()).is_some());
+    assert!(UnicodeStr::from_bstr(&BString::from_code_units(vec![b'a' as u16; 0x8000].into_iter()).unwrap()).is_none());
+}
@@ -1,8 +1,63 @@
-use std::ffi::{OsStr, OsString};
-use std::path::{Path, PathBuf};
+#[cfg(windows)] use std::ffi::{OsStr, OsString};
+#[cfg(windows)] use std::path::{Path, PathBuf};
+
+
+
+/// Format [u16] unicode [code unit]s like `OsStr`'s `Debug` does on windows, even on other platforms
+///
+/// [code unit]:    https://unicode.org/glossary/#code_unit
+#[allow(dead_code)] // unused with `--no-default-features`
+pub(crate) fn debug_utf16(units: &[u16], fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+    use std::fmt::Write;
+    fmt.write_char('"')?;
+    for ch in std::char::decode_utf16(units.iter().copied()) {
+        match ch {
+            Ok(ch)      => for ch in ch.escape_debug() { fmt.write_char(ch)?; },
+            Err(err)    => write!(fmt, "\\u{{{:x}}}", err.unpaired_surrogate())?,
+        }
+    }
+    fmt.write_char('"')
+}
+
+/// Simple (1:1) Unicode uppercasing, without regard to locale:  `ch` is replaced only if it uppercases to exactly one [char].
+///
+/// Characters with multi-character uppercase mappings (e.g. `'ß'` → `"SS"`) are unchanged.
+#[allow(dead_code)] // unused with `--no-default-features`
+pub(crate) fn simple_uppercase(ch: char) -> char {
+    let mut upper = ch.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) => upper,
+        _                   => ch,
+    }
+}
 
+/// "Exact Size Iterator" adapter
+#[allow(dead_code)] // unused with `--no-default-features`
+pub(crate) struct ExactSize<I: Iterator> {
+    len:    usize,
+    iter:   I,
+}
 
+#[allow(dead_code)] // unused with `--no-default-features`
+impl<I: Iterator + Clone> ExactSize<I> {
+    pub fn new(iter: I) -> Self {
+        Self {
+            len: iter.clone().count(),
+            iter,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for ExactSize<I> {
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> { self.iter.next() }
+}
 
+impl<I: Iterator> ExactSizeIterator for ExactSize<I> {
+    fn len(&self) -> usize { self.len }
+}
+
+#[allow(dead_code)] // unused with `--no-default-features`
 pub(crate) trait UTF16ish<'s> {
     type Iter : Iterator<Item = u16> + 's;
     fn utf16ish(&'s self) -> Self::Iter;
@@ -37,22 +92,74 @@ impl<'s> UTF16ish<'s> for String {
     fn utf16ish(&'s self) -> Self::Iter { self.encode_utf16() }
 }
 
-impl<'s> UTF16ish<'s> for OsStr {
+#[cfg(windows)] impl<'s> UTF16ish<'s> for OsStr {
     type Iter = std::os::windows::ffi::EncodeWide<'s>;
     fn utf16ish(&'s self) -> Self::Iter { std::os::windows::ffi::OsStrExt::encode_wide(self) }
 }
 
-impl<'s> UTF16ish<'s> for OsString {
+#[cfg(windows)] impl<'s> UTF16ish<'s> for OsString {
     type Iter = std::os::windows::ffi::EncodeWide<'s>;
     fn utf16ish(&'s self) -> Self::Iter { std::os::windows::ffi::OsStrExt::encode_wide(&**self) }
 }
 
-impl<'s> UTF16ish<'s> for Path {
+#[cfg(windows)] impl<'s> UTF16ish<'s> for Path {
     type Iter = std::os::windows::ffi::EncodeWide<'s>;
     fn utf16ish(&'s self) -> Self::Iter { std::os::windows::ffi::OsStrExt::encode_wide(self.as_os_str()) }
 }
 
-impl<'s> UTF16ish<'s> for PathBuf {
+#[cfg(windows)] impl<'s> UTF16ish<'s> for PathBuf {
     type Iter = std::os::windows::ffi::EncodeWide<'s>;
     fn utf16ish(&'s self) -> Self::Iter { std::os::windows::ffi::OsStrExt::encode_wide(self.as_os_str()) }
 }
+
+
+
+// Okay, this is a *lot* of traits.  I'm just mimicing the stdlib here though.
+//
+// Sliceable DST rules, using `str` as an example
+// 1.   Implement `&str == &str` (used for `"foo" == "bar"`)
+// 2.   Implement `str  ==  str` (used for `"foo"[..] == "bar"[..]`)
+// 3.   Skip `&str == str` / `str == &str`
+// 4.   Implement `&str == ...`
+// 5.   Implement `str  == ...`
+// 6.   Implement `... == &str`
+// 7.   Implement `... ==  str`
+// 8.   All the above for ordering comparisons too
+//
+// `&BStr` and `&WStr` are slightly simpler than `&str` - they're not sliceable and cannot be directly used as values
+
+#[allow(unused_macros)] // unused with `--no-default-features`
+macro_rules! peo {
+    ( impl[$($g:tt)*] &? $left:ty, $($tt:tt)* ) => {
+        peo!(impl[$($g)*] & $left, $($tt)*);
+        peo!(impl[$($g)*]   $left, $($tt)*);
+    };
+    ( impl[$($g:tt)*] $left:ty, &? $($tt:tt)* ) => {
+        peo!(impl[$($g)*] $left, & $($tt)*);
+        peo!(impl[$($g)*] $left,   $($tt)*);
+    };
+    ( impl[$($g:tt)*] $left:ty, $right:ty ) => {
+        impl<$($g)*> PartialEq<$left> for $right {
+            fn eq(&self, other: &$left) -> bool {
+                self.utf16ish().eq(other.utf16ish())
+            }
+        }
+        #[allow(clippy::non_canonical_partial_ord_impl)] // consistent with `Ord` regardless
+        impl<$($g)*> PartialOrd<$left> for $right {
+            fn partial_cmp(&self, other: &$left) -> Option<Ordering> {
+                self.utf16ish().partial_cmp(other.utf16ish())
+            }
+        }
+    };
+    ( &? $left:ty, $($tt:tt)* ) => {
+        peo!(& $left, $($tt)*);
+        peo!(  $left, $($tt)*);
+    };
+    ( $left:ty, &? $($tt:tt)* ) => {
+        peo!($left, & $($tt)*);
+        peo!($left,   $($tt)*);
+    };
+    ( $left:ty, $right:ty ) => {
+        peo!(impl[] $left, $right);
+    };
+}
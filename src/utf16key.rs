@@ -0,0 +1,156 @@
+#![forbid(unsafe_code)]
+
+use std::borrow::{Borrow, Cow};
+#[cfg(windows)] use std::ffi::{OsStr, OsString};
+use std::hash::{Hash, Hasher};
+#[cfg(windows)] use std::path::{Path, PathBuf};
+
+
+
+/// Strings that can be hashed and compared as [u16] unicode [code unit]s without allocating.
+///
+/// `str` and `[u16]` hash differently, so a `HashMap<BString, V>` can't be probed with a `&str` key.  Wrap map keys in
+/// [Utf16Key] instead, and then any `&dyn Utf16Hash` - `&str`, `&OsStr`, `&[u16]`, `&BStr`, ... - can probe the same map:
+///
+/// ```rust
+/// # use winstr::*;
+/// # use std::collections::HashMap;
+/// let mut map = HashMap::new();
+/// map.insert(Utf16Key(String::from("Name")), 1);
+///
+/// let units : &[u16] = &[b'N' as u16, b'a' as u16, b'm' as u16, b'e' as u16];
+/// assert_eq!(map.get(&"Name" as &dyn Utf16Hash), Some(&1));
+/// assert_eq!(map.get(&units  as &dyn Utf16Hash), Some(&1));
+/// ```
+///
+/// Since this works through [Borrow], it's also compatible with hashbrown's `Equivalent` based lookups.
+///
+/// [code unit]:    https://unicode.org/glossary/#code_unit
+pub trait Utf16Hash {
+    /// The [u16] unicode [code unit]s of the string, excluding any terminal `0u16`
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    fn utf16_units(&self) -> Utf16Units<'_>;
+}
+
+/// Hashes each [u16] unicode [code unit] with [Hasher::write_u16], followed by [Hasher::write_usize] of the length.
+///
+/// [code unit]:    https://unicode.org/glossary/#code_unit
+impl Hash for dyn Utf16Hash + '_ {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut len = 0usize;
+        for unit in self.utf16_units() {
+            state.write_u16(unit);
+            len += 1;
+        }
+        state.write_usize(len);
+    }
+}
+
+impl PartialEq for dyn Utf16Hash + '_ { fn eq(&self, other: &Self) -> bool { self.utf16_units().eq(other.utf16_units()) } }
+impl Eq for dyn Utf16Hash + '_ {}
+
+
+
+/// A [HashMap](std::collections::HashMap) / [HashSet](std::collections::HashSet) key that hashes and compares `K` by
+/// [u16] unicode [code unit]s, and can be borrowed as any `&dyn` [Utf16Hash] for lookups.
+///
+/// Note that `Utf16Key<BString>` hashes differently than `BString` - the latter hashes the same as `[u16]`, which
+/// would require converting `&str`s into `[u16]` buffers to look up.
+///
+/// [code unit]:    https://unicode.org/glossary/#code_unit
+#[derive(Clone, Copy, Debug, Default)] pub struct Utf16Key<K>(pub K);
+
+impl<K: Utf16Hash> Utf16Key<K> {
+    /// Unwrap the key
+    pub fn into_inner(self) -> K { self.0 }
+}
+
+impl<K: Utf16Hash> Hash for Utf16Key<K> { fn hash<H: Hasher>(&self, state: &mut H) { (&self.0 as &dyn Utf16Hash).hash(state) } }
+impl<K: Utf16Hash, R: Utf16Hash> PartialEq<Utf16Key<R>> for Utf16Key<K> { fn eq(&self, other: &Utf16Key<R>) -> bool { self.0.utf16_units().eq(other.0.utf16_units()) } }
+impl<K: Utf16Hash> Eq for Utf16Key<K> {}
+impl<'a, K: Utf16Hash + 'a> Borrow<dyn Utf16Hash + 'a> for Utf16Key<K> { fn borrow(&self) -> &(dyn Utf16Hash + 'a) { &self.0 } }
+impl<K: Utf16Hash> Utf16Hash for Utf16Key<K> { fn utf16_units(&self) -> Utf16Units<'_> { self.0.utf16_units() } }
+
+
+
+/// An [Iterator] over the [u16] unicode [code unit]s of a [Utf16Hash] string.
+///
+/// [code unit]:    https://unicode.org/glossary/#code_unit
+#[derive(Clone)] pub struct Utf16Units<'s>(UnitsIter<'s>);
+
+#[derive(Clone)] enum UnitsIter<'s> {
+    Units(std::iter::Copied<std::slice::Iter<'s, u16>>),
+    Str(std::str::EncodeUtf16<'s>),
+    #[cfg(windows)] OsStr(std::os::windows::ffi::EncodeWide<'s>),
+}
+
+impl Iterator for Utf16Units<'_> {
+    type Item = u16;
+    fn next(&mut self) -> Option<u16> {
+        match &mut self.0 {
+            UnitsIter::Units(i) => i.next(),
+            UnitsIter::Str(i)   => i.next(),
+            #[cfg(windows)] UnitsIter::OsStr(i) => i.next(),
+        }
+    }
+}
+
+impl<'s> From<&'s [u16]> for Utf16Units<'s> { fn from(value: &'s [u16]) -> Self { Self(UnitsIter::Units(value.iter().copied())) } }
+impl<'s> From<&'s str  > for Utf16Units<'s> { fn from(value: &'s str  ) -> Self { Self(UnitsIter::Str(value.encode_utf16())) } }
+#[cfg(windows)] impl<'s> From<&'s OsStr> for Utf16Units<'s> { fn from(value: &'s OsStr) -> Self { Self(UnitsIter::OsStr(std::os::windows::ffi::OsStrExt::encode_wide(value))) } }
+
+
+
+impl<T: Utf16Hash + ?Sized> Utf16Hash for &T { fn utf16_units(&self) -> Utf16Units<'_> { (**self).utf16_units() } }
+impl<T: Utf16Hash + ?Sized> Utf16Hash for Box<T> { fn utf16_units(&self) -> Utf16Units<'_> { (**self).utf16_units() } }
+impl<T: Utf16Hash + ToOwned + ?Sized> Utf16Hash for Cow<'_, T> { fn utf16_units(&self) -> Utf16Units<'_> { (**self).utf16_units() } }
+
+impl Utf16Hash for [u16]    { fn utf16_units(&self) -> Utf16Units<'_> { self.into() } }
+impl Utf16Hash for Vec<u16> { fn utf16_units(&self) -> Utf16Units<'_> { self[..].into() } }
+impl Utf16Hash for str      { fn utf16_units(&self) -> Utf16Units<'_> { self.into() } }
+impl Utf16Hash for String   { fn utf16_units(&self) -> Utf16Units<'_> { self[..].into() } }
+#[cfg(windows)] impl Utf16Hash for OsStr    { fn utf16_units(&self) -> Utf16Units<'_> { self.into() } }
+#[cfg(windows)] impl Utf16Hash for OsString { fn utf16_units(&self) -> Utf16Units<'_> { self[..].into() } }
+#[cfg(windows)] impl Utf16Hash for Path     { fn utf16_units(&self) -> Utf16Units<'_> { self.as_os_str().into() } }
+#[cfg(windows)] impl Utf16Hash for PathBuf  { fn utf16_units(&self) -> Utf16Units<'_> { self.as_os_str().into() } }
+
+
+
+#[test] fn lookup() {
+    use std::collections::HashMap;
+
+    let units = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+
+    let mut map = HashMap::new();
+    map.insert(Utf16Key(String::from("Name")),  1);
+    map.insert(Utf16Key(String::from("Value")), 2);
+    map.insert(Utf16Key(String::from("\u{10000}")), 3);
+
+    assert_eq!(map.get(&"Name"                  as &dyn Utf16Hash), Some(&1));
+    assert_eq!(map.get(&String::from("Value")   as &dyn Utf16Hash), Some(&2));
+    assert_eq!(map.get(&units("Value")          as &dyn Utf16Hash), Some(&2));
+    assert_eq!(map.get(&&units("\u{10000}")[..] as &dyn Utf16Hash), Some(&3));
+    assert_eq!(map.get(&"name"                  as &dyn Utf16Hash), None);
+    assert_eq!(map.get(&&units("\u{10000}")[..1] as &dyn Utf16Hash), None);
+
+    let mut map = hashbrown::HashMap::new();
+    map.insert(Utf16Key(units("Item")), 4);
+    assert_eq!(map.get(&"Item" as &dyn Utf16Hash), Some(&4));
+
+    assert!(Utf16Key("Name") == Utf16Key(units("Name")));
+    assert!(Utf16Key("Name") != Utf16Key(units("Nam")));
+}
+
+#[cfg(windows)] #[cfg(feature = "bstr")] #[test] fn lookup_bstr() {
+    use crate::*;
+    use std::collections::HashMap;
+
+    let mut map = HashMap::new();
+    map.insert(Utf16Key(BString::from("Name")), 1);
+    map.insert(Utf16Key(BString::from("Value")), 2);
+
+    assert_eq!(map.get(&"Name"                  as &dyn Utf16Hash), Some(&1));
+    assert_eq!(map.get(&OsStr::new("Value")     as &dyn Utf16Hash), Some(&2));
+    assert_eq!(map.get(&bstr!("Value")          as &dyn Utf16Hash), Some(&2));
+}
@@ -0,0 +1,2 @@
+mod danger; pub use danger::*;
+mod safer;  pub use safer::*;
@@ -0,0 +1,75 @@
+// The dangerous bits of the WBuf wrapper.  Only code within this module has
+// access to `self.0`, hopefully making it simpler to audit access / verify
+// struct invariants are held.
+
+#![allow(unsafe_code)]
+
+
+
+/// ### Terminator Invariants
+/// * A `WCHAR field[N]` *should* be `0u16`-terminated, but nothing enforces that - a [WBuf] may contain no `0u16` at all!
+/// * [WBuf::as_units] stops at the first `0u16` if any, or spans the entire buffer if there is none.
+/// * Units after the first `0u16` are ignored for comparisons, hashing, etc.
+///
+/// ### Layout Invariants
+/// * [WBuf]&lt;N&gt; has exactly the same size and alignment as `[u16; N]` / `[WCHAR; N]`, and can be used in their place in FFI structs.
+mod invariants {}
+
+
+
+/// `WBuf<N>` is a fixed-capacity, inline, [UTF-16]ish string buffer, for `WCHAR field[N]` struct members.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// let mut face_name = [0u16; 32]; // e.g. LOGFONTW::lfFaceName
+/// let face_name = WBuf::from_array_mut(&mut face_name);
+/// face_name.set("Consolas").unwrap();
+/// assert_eq!(face_name, "Consolas");
+/// assert!(face_name.set("An unreasonably long font face name").is_err());
+/// assert_eq!(face_name, "Consolas");
+/// ```
+///
+/// [UTF-16]:   https://en.wikipedia.org/wiki/UTF-16
+#[derive(Clone, Copy)] #[repr(transparent)] pub struct WBuf<const N: usize>([u16; N]);
+
+impl<const N: usize> WBuf<N> {
+    /// Create a zeroed (empty) buffer.
+    pub const fn new() -> Self { Self([0; N]) }
+
+    /// Wrap an existing array.
+    pub const fn from_array(array: [u16; N]) -> Self { Self(array) }
+
+    /// Borrow an existing array (e.g. a `WCHAR field[N]` of a winapi struct) as a `WBuf`.
+    pub fn from_array_ref(array: &[u16; N]) -> &Self { unsafe { &*(array as *const [u16; N] as *const Self) } }
+
+    /// Mutably borrow an existing array (e.g. a `WCHAR field[N]` of a winapi struct) as a `WBuf`.
+    pub fn from_array_mut(array: &mut [u16; N]) -> &mut Self { unsafe { &mut *(array as *mut [u16; N] as *mut Self) } }
+
+    /// Unwrap the underlying array.
+    pub const fn into_array(self) -> [u16; N] { self.0 }
+
+    /// The entire underlying array, including the terminator (if any) and everything after it.
+    pub fn as_array(&self) -> &[u16; N] { &self.0 }
+
+    /// The entire underlying array, including the terminator (if any) and everything after it.
+    pub fn as_array_mut(&mut self) -> &mut [u16; N] { &mut self.0 }
+}
+
+
+
+#[test] fn layout() {
+    use std::mem::align_of;
+    use std::mem::size_of;
+
+    assert_eq!(align_of::<WBuf<32>>(),  align_of::<[u16; 32]>());
+    assert_eq!(size_of::<WBuf<32>>(),   size_of::<[u16; 32]>());
+    assert_eq!(size_of::<WBuf<0>>(),    0);
+
+    let mut array = [b'a' as u16, 0, 0];
+    assert_eq!(WBuf::from_array_ref(&array).as_array() as *const _, &array as *const _);
+    WBuf::from_array_mut(&mut array).as_array_mut()[1] = b'b' as u16;
+    assert_eq!(array, [b'a' as u16, b'b' as u16, 0]);
+    assert_eq!(WBuf::from_array(array).into_array(), array);
+    assert_eq!(WBuf::<4>::new().into_array(), [0; 4]);
+}
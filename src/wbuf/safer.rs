@@ -0,0 +1,239 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+#[cfg(windows)] use std::ffi::{OsStr, OsString};
+#[cfg(windows)] use std::os::windows::ffi::OsStringExt;
+
+
+
+#[cfg(feature = "display")]
+impl<const N: usize> Display            for WBuf<N>     { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&String::from_utf16_lossy(self.as_units()), fmt) } }
+impl<const N: usize> Debug              for WBuf<N>     { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { debug_utf16(self.as_units(), fmt) } }
+impl<const N: usize> Default            for WBuf<N>     { fn default() -> Self { Self::new() } }
+impl<const N: usize> AsRef<[u16]>       for WBuf<N>     { fn as_ref(&self) -> &[u16] { self.as_units() } }
+impl<const N: usize> From<[u16; N]>     for WBuf<N>     { fn from(value: [u16; N]) -> Self { Self::from_array(value) } }
+impl<const N: usize> From<WBuf<N>>      for [u16; N]    { fn from(value: WBuf<N>) -> Self { value.into_array() } }
+impl<const N: usize> From<&WBuf<N>>     for WString     { fn from(value: &WBuf<N>) -> Self { value.to_wstring() } }
+impl<const N: usize> Eq                 for WBuf<N>     {}
+impl<const N: usize> Ord                for WBuf<N>     { fn cmp(&self, other: &WBuf<N>) -> Ordering { self.as_units().cmp(other.as_units()) } }
+impl<const N: usize> Hash               for WBuf<N>     { fn hash<H: Hasher>(&self, state: &mut H) { self.as_units().hash(state) } }
+
+peo!(impl[const N: usize] WBuf<N>, WBuf<N>);
+
+peo!(impl[const N: usize] &?[u16],           WBuf<N>); peo!(impl[const N: usize] WBuf<N>, &?[u16]         );
+peo!(impl[const N: usize] &?str,             WBuf<N>); peo!(impl[const N: usize] WBuf<N>, &?str           );
+peo!(impl[const N: usize] String,            WBuf<N>); peo!(impl[const N: usize] WBuf<N>, String          );
+peo!(impl[const N: usize] Cow<'_, [u16]>,    WBuf<N>); peo!(impl[const N: usize] WBuf<N>, Cow<'_, [u16]>  );
+peo!(impl[const N: usize] Cow<'_, str>,      WBuf<N>); peo!(impl[const N: usize] WBuf<N>, Cow<'_, str>    );
+peo!(impl[const N: usize] &WStr,             WBuf<N>); peo!(impl[const N: usize] WBuf<N>, &WStr           );
+peo!(impl[const N: usize] WString,           WBuf<N>); peo!(impl[const N: usize] WBuf<N>, WString         );
+
+#[cfg(windows)] mod windows_peo {
+    use super::*;
+    peo!(impl[const N: usize] &?OsStr,           WBuf<N>); peo!(impl[const N: usize] WBuf<N>, &?OsStr         );
+    peo!(impl[const N: usize] OsString,          WBuf<N>); peo!(impl[const N: usize] WBuf<N>, OsString        );
+
+    #[cfg(feature = "bstr")] peo!(impl[const N: usize] &BStr,   WBuf<N>); #[cfg(feature = "bstr")] peo!(impl[const N: usize] WBuf<N>, &BStr  );
+    #[cfg(feature = "bstr")] peo!(impl[const N: usize] BString, WBuf<N>); #[cfg(feature = "bstr")] peo!(impl[const N: usize] WBuf<N>, BString);
+}
+
+impl<'s, const N: usize> UTF16ish<'s> for WBuf<N> {
+    type Iter = std::iter::Copied<std::slice::Iter<'s, u16>>;
+    fn utf16ish(&'s self) -> Self::Iter { self.as_units().iter().copied() }
+}
+
+impl<const N: usize> Utf16Hash for WBuf<N> { fn utf16_units(&self) -> Utf16Units<'_> { self.as_units().into() } }
+
+
+
+/// The error type for [WBuf::set]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] pub enum WBufError {
+    /// The string (plus a terminal `0u16`) is too long for the buffer (`STRSAFE_E_INSUFFICIENT_BUFFER`)
+    InsufficientBuffer,
+
+    /// The string contains a `0u16`, which would truncate it
+    InteriorNul,
+}
+
+impl Display for WBufError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            WBufError::InsufficientBuffer   => fmt.write_str("string too long for WBuf"),
+            WBufError::InteriorNul          => fmt.write_str("string contains an interior '\\0'"),
+        }
+    }
+}
+
+impl std::error::Error for WBufError {}
+
+
+
+impl<const N: usize> WBuf<N> {
+    /// The capacity of the buffer in [u16] unicode [code unit]s, **including** the terminal `0u16`
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub const fn capacity(&self) -> usize { N }
+
+    /// The [u16] unicode [code unit]s of the string, up to (but excluding) the first `0u16`.
+    /// If the buffer isn't terminated, this is the entire buffer.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn as_units(&self) -> &[u16] { let a = self.as_array(); &a[..self.len()] }
+
+    /// The [u16] unicode [code unit]s of the string, including the first `0u16`.
+    /// Returns [None] if the buffer isn't terminated.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn as_units0(&self) -> Option<&[u16]> { let a = self.as_array(); a.get(..self.len()+1) }
+
+    /// Borrow the buffer as a &amp;[WStr].  Returns [None] if the buffer isn't terminated.
+    pub fn as_wstr(&self) -> Option<&WStr> { WStr::from_units0(self.as_units0()?) }
+
+    /// Length in [u16] unicode [code unit]s, excluding the terminal `0u16` (if any)
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn len(&self) -> usize { self.as_array().iter().position(|&u| u == 0).unwrap_or(N) }
+
+    /// Returns `true` if the string is empty
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns `true` if the buffer contains a terminal `0u16`
+    pub fn is_terminated(&self) -> bool { self.as_array().contains(&0) }
+
+    /// Zero the entire buffer
+    pub fn clear(&mut self) { *self.as_array_mut() = [0; N]; }
+
+    /// Replace the contents of the buffer with `s`, zeroing any unused space.
+    ///
+    /// Unlike `StringCchCopyW`, the buffer is left unmodified if `s` doesn't fit - use [set_truncated](Self::set_truncated) instead for that.
+    pub fn set(&mut self, s: &(impl Utf16Hash + ?Sized)) -> Result<(), WBufError> {
+        let mut len = 0;
+        for unit in s.utf16_units() {
+            if unit == 0 { return Err(WBufError::InteriorNul); }
+            len += 1;
+        }
+        if len >= N { return Err(WBufError::InsufficientBuffer); }
+
+        let a = self.as_array_mut();
+        for (dst, src) in a.iter_mut().zip(s.utf16_units().chain(std::iter::repeat(0))) { *dst = src; }
+        Ok(())
+    }
+
+    /// Replace the contents of the buffer with as much of `s` as fits (like `StringCchCopyW`), zeroing any unused space.
+    ///
+    /// * Copying stops at the first `0u16` of `s`, if any.
+    /// * Surrogate pairs are never split: if the pair doesn't fit, neither half is copied.
+    /// * The buffer is always terminated (unless `N == 0`.)
+    ///
+    /// Returns `true` if `s` was truncated.
+    pub fn set_truncated(&mut self, s: &(impl Utf16Hash + ?Sized)) -> bool {
+        if N == 0 { return true; } // not even a terminator fits
+        let mut src = s.utf16_units().take_while(|&u| u != 0);
+
+        let a = self.as_array_mut();
+        let mut len = 0;
+        for (dst, unit) in a[..N-1].iter_mut().zip(&mut src) { *dst = unit; len += 1; }
+        let next = src.next();
+        if len > 0 && is_high_surrogate(a[len-1]) && next.is_some_and(is_low_surrogate) { len -= 1; }
+        for dst in a[len..].iter_mut() { *dst = 0; }
+        next.is_some()
+    }
+
+    /// Shorten the string to at most `len` [u16] unicode [code unit]s, zeroing the rest of the buffer.
+    /// If `len` would split a surrogate pair, the string is shortened to `len-1` instead.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len() { return; }
+        let a = self.as_array_mut();
+        let len = if len > 0 && is_high_surrogate(a[len-1]) && is_low_surrogate(a[len]) { len - 1 } else { len };
+        for dst in a[len..].iter_mut() { *dst = 0; }
+    }
+
+    /// Copy the string into a [WString]
+    pub fn to_wstring(&self) -> WString { WString::from_code_units(self.as_units().iter().copied()).unwrap() }
+
+    /// Copy the string into a [BString]
+    #[cfg(windows)] #[cfg(feature = "bstr")]
+    pub fn to_bstring(&self) -> BString { BString::from_code_units(self.as_units().iter().copied()).unwrap() }
+
+    /// Decode the string as UTF-16, replacing invalid data (unpaired surrogates) with [U+FFFD](std::char::REPLACEMENT_CHARACTER)
+    pub fn to_string_lossy(&self) -> String { String::from_utf16_lossy(self.as_units()) }
+
+    /// Convert the string into an [OsString]
+    #[cfg(windows)]
+    pub fn to_os_string(&self) -> OsString { OsString::from_wide(self.as_units()) }
+}
+
+fn is_high_surrogate(unit: u16) -> bool { (0xD800 ..= 0xDBFF).contains(&unit) }
+fn is_low_surrogate (unit: u16) -> bool { (0xDC00 ..= 0xDFFF).contains(&unit) }
+
+
+
+#[test] fn set() {
+    let mut buf = WBuf::<4>::from_array([1, 2, 3, 4]);
+    assert!(!buf.is_terminated());
+    assert_eq!(buf.len(), 4);
+    assert_eq!(buf.as_units0(), None);
+    assert!(buf.as_wstr().is_none());
+
+    assert_eq!(buf.set("abc"), Ok(()));
+    assert_eq!(buf.into_array(), [b'a' as u16, b'b' as u16, b'c' as u16, 0]);
+    assert_eq!(buf.set("abcd"), Err(WBufError::InsufficientBuffer));
+    assert_eq!(buf.set("a\0c"), Err(WBufError::InteriorNul));
+    assert_eq!(buf, "abc");
+    assert_eq!(buf.as_wstr().unwrap(), "abc");
+
+    assert_eq!(buf.set(&[b'x' as u16][..]), Ok(()));
+    assert_eq!(buf.into_array(), [b'x' as u16, 0, 0, 0]);
+    assert_eq!(buf.as_units0().unwrap(), [b'x' as u16, 0]);
+
+    assert!(buf.set_truncated("abcdef"));
+    assert_eq!(buf, "abc");
+    assert!(!buf.set_truncated("ab\0cdef"));
+    assert_eq!(buf.into_array(), [b'a' as u16, b'b' as u16, 0, 0]);
+
+    // surrogate pairs are never split
+    assert!(buf.set_truncated("ab\u{10000}"));
+    assert_eq!(buf.into_array(), [b'a' as u16, b'b' as u16, 0, 0]);
+    assert!(!buf.set_truncated("a\u{10000}"));
+    assert_eq!(buf, "a\u{10000}");
+    buf.truncate(2);
+    assert_eq!(buf, "a");
+    buf.truncate(8);
+    assert_eq!(buf, "a");
+    buf.clear();
+    assert!(buf.is_empty());
+
+    let mut empty = WBuf::<0>::new();
+    assert_eq!(empty.set(""), Err(WBufError::InsufficientBuffer));
+    assert!(empty.set_truncated(""));
+    assert_eq!(empty, "");
+}
+
+#[test] fn comparisons() {
+    let buf = WBuf::from_array([b'b' as u16, 0, b'z' as u16]);
+    let units : &[u16] = &[b'b' as u16];
+    assert_eq!(buf, "b");
+    assert_eq!("b", buf);
+    assert_eq!(buf, units);
+    assert_eq!(buf, WString::from_str("b").unwrap());
+    assert_eq!(buf, WBuf::from_array([b'b' as u16, 0, b'y' as u16]));
+    assert!(buf < "c");
+    assert!(buf > *"a");
+    assert_eq!(buf.to_wstring(), "b");
+    assert_eq!(format!("{:?}", buf), "\"b\"");
+}
+
+#[cfg(windows)] #[cfg(feature = "bstr")] #[test] fn bstr_comparisons() {
+    let mut buf = WBuf::<8>::new();
+    buf.set(bstr!("abc")).unwrap();
+    assert_eq!(buf, bstr!("abc"));
+    assert_eq!(buf.to_bstring(), bstr!("abc"));
+    assert_eq!(bstr!("abc"), buf);
+}
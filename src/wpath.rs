@@ -0,0 +1,444 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+
+use std::fmt::{self, Debug, Formatter};
+use std::iter::FusedIterator;
+
+const SLASH     : u16 = b'/'  as u16;
+const BACKSLASH : u16 = b'\\' as u16;
+const DOT       : u16 = b'.'  as u16;
+const SPACE     : u16 = b' '  as u16;
+const COLON     : u16 = b':'  as u16;
+const QUESTION  : u16 = b'?'  as u16;
+
+/// The traditional Win32 path length limit, in [u16] unicode [code unit]s, **including** the terminal `0u16`.
+///
+/// [code unit]:    https://unicode.org/glossary/#code_unit
+pub const MAX_PATH : usize = 260;
+
+
+
+/// A borrowed, portable, [UTF-16]ish Windows path, for manipulating paths from `&BStr`s / `&[u16]`s without converting them
+/// into [PathBuf](std::path::PathBuf)s (which isn't possible on non-Windows hosts.)
+///
+/// Both `\` and `/` are separators, except in verbatim (`\\?\`) paths, which Windows passes through without any normalization.
+///
+/// ### Example
+/// ```
+/// # use winstr::*;
+/// let units = r"C:\Users\.\Me\..\Public\Desktop. ".encode_utf16().collect::<Vec<_>>();
+/// let path  = WPath::new(&units);
+/// assert_eq!(path.prefix(), Some(WPathPrefix::Disk(b'C' as u16)));
+/// assert_eq!(path.normalize(), r"C:\Users\Public\Desktop");
+/// assert_eq!(path.to_verbatim().unwrap(), r"\\?\C:\Users\Public\Desktop");
+/// ```
+///
+/// [UTF-16]:   https://en.wikipedia.org/wiki/UTF-16
+#[derive(Clone, Copy, PartialEq, Eq, Hash)] pub struct WPath<'p>(&'p [u16]);
+
+/// A [WPath] prefix, mirroring [std::path::Prefix].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] pub enum WPathPrefix<'p> {
+    /// `\\?\cat_pics`
+    Verbatim(&'p [u16]),
+
+    /// `\\?\UNC\server\share`
+    VerbatimUnc(&'p [u16], &'p [u16]),
+
+    /// `\\?\C:`
+    VerbatimDisk(u16),
+
+    /// `\\.\COM42`
+    DeviceNs(&'p [u16]),
+
+    /// `\\server\share`
+    Unc(&'p [u16], &'p [u16]),
+
+    /// `C:`
+    Disk(u16),
+}
+
+/// A single component of a [WPath], mirroring [std::path::Component].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] pub enum WPathComponent<'p> {
+    /// A prefix such as `C:` or `\\server\share`
+    Prefix(WPathPrefix<'p>),
+
+    /// The root directory separator, which may be implied by a [WPathPrefix::Unc] etc.
+    RootDir,
+
+    /// `.` (never yielded for verbatim paths)
+    CurDir,
+
+    /// `..` (never yielded for verbatim paths)
+    ParentDir,
+
+    /// A regular file or directory name
+    Normal(&'p [u16]),
+}
+
+impl Debug for WPath<'_> { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { debug_utf16(self.0, fmt) } }
+
+impl<'p> From<&'p [u16]> for WPath<'p> { fn from(value: &'p [u16]) -> Self { Self::new(value) } }
+impl<'p> From<&'p WStr > for WPath<'p> { fn from(value: &'p WStr ) -> Self { Self::new(value.units()) } }
+#[cfg(windows)] #[cfg(feature = "bstr")] impl<'p> From<&'p BStr> for WPath<'p> { fn from(value: &'p BStr) -> Self { Self::new(value.units()) } }
+
+impl WPathPrefix<'_> {
+    /// Returns `true` for `\\?\` prefixes.
+    pub fn is_verbatim(&self) -> bool { matches!(self, WPathPrefix::Verbatim(_) | WPathPrefix::VerbatimUnc(..) | WPathPrefix::VerbatimDisk(_)) }
+
+    /// Returns `true` if the prefix implies a root directory even without a trailing separator (e.g. `\\server\share`.)
+    fn has_implicit_root(&self) -> bool { !matches!(self, WPathPrefix::Disk(_)) }
+}
+
+impl<'p> WPath<'p> {
+    /// Wrap `units` as a path.  Everything from the first `0u16` onwards, if any, is ignored.
+    pub fn new(units: &'p [u16]) -> Self {
+        let end = units.iter().position(|&u| u == 0).unwrap_or(units.len());
+        Self(&units[..end])
+    }
+
+    /// The [u16] unicode [code unit]s of the path
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn units(&self) -> &'p [u16] { self.0 }
+
+    /// The prefix of the path, if any.
+    pub fn prefix(&self) -> Option<WPathPrefix<'p>> { parse_prefix(self.0).map(|(prefix, _)| prefix) }
+
+    /// Returns `true` if the path has a `\\?\` prefix.
+    pub fn is_verbatim(&self) -> bool { self.prefix().is_some_and(|p| p.is_verbatim()) }
+
+    /// Returns `true` if the path has a root directory, explicit (`C:\`, `\`) or implicit (`\\server\share`.)
+    pub fn has_root(&self) -> bool { self.components().any(|c| c == WPathComponent::RootDir) }
+
+    /// Returns `true` if the path doesn't depend on the current directory or drive.  `\foo` and `C:foo` are **not** absolute.
+    pub fn is_absolute(&self) -> bool {
+        match self.prefix() {
+            None                        => false,
+            Some(WPathPrefix::Disk(_))  => self.has_root(),
+            Some(_)                     => true,
+        }
+    }
+
+    /// Returns `true` if the path is too long for APIs limited to [MAX_PATH] (including the terminal `0u16`.)
+    pub fn exceeds_max_path(&self) -> bool { self.0.len() >= MAX_PATH }
+
+    /// Iterate over the components of the path.  Empty components (from repeated separators) are skipped.
+    pub fn components(&self) -> WPathComponents<'p> {
+        let (prefix, len) = match parse_prefix(self.0) { Some((prefix, len)) => (Some(prefix), len), None => (None, 0) };
+        let verbatim    = prefix.is_some_and(|p| p.is_verbatim());
+        let remaining   = &self.0[len..];
+        let root        = remaining.first().is_some_and(|&u| is_sep(u, verbatim)) || prefix.is_some_and(|p| p.has_implicit_root());
+        WPathComponents { prefix, root, remaining, verbatim }
+    }
+
+    /// Lexically normalize the path like `GetFullPathNameW`, without consulting the current directory:
+    ///
+    /// * `/`s become `\`s, and repeated separators are collapsed.
+    /// * `.` components are removed, and `..` components remove the previous component (but never the root or prefix.)
+    /// * A single trailing `.` is removed from each component.
+    /// * Trailing `.`s and spaces are removed from the final component, unless the path ends with a separator.
+    /// * Verbatim (`\\?\`) paths are returned unmodified.
+    ///
+    /// Unlike `GetFullPathNameW`, relative paths stay relative (leading `..`s are kept), and an empty result becomes `.`.
+    pub fn normalize(&self) -> WString {
+        let mut units = Vec::new();
+        let (prefix, len) = match parse_prefix(self.0) {
+            Some((prefix, _)) if prefix.is_verbatim() => return to_wstring(self.0),
+            Some((prefix, len)) => (Some(prefix), len),
+            None => (None, 0),
+        };
+
+        match prefix {
+            Some(WPathPrefix::Disk(drive))          => units.extend_from_slice(&[drive, COLON]),
+            Some(WPathPrefix::DeviceNs(device))     => { units.extend_from_slice(&[BACKSLASH, BACKSLASH, DOT, BACKSLASH]); units.extend_from_slice(device); },
+            Some(WPathPrefix::Unc(server, share))   => {
+                units.extend_from_slice(&[BACKSLASH, BACKSLASH]);
+                units.extend_from_slice(server);
+                units.push(BACKSLASH);
+                units.extend_from_slice(share);
+            },
+            _ => {},
+        }
+
+        let remaining       = &self.0[len..];
+        let explicit_root   = remaining.first().is_some_and(|&u| is_sep(u, false));
+        let rooted          = explicit_root || prefix.is_some_and(|p| p.has_implicit_root());
+        let trailing_sep    = remaining.last().is_some_and(|&u| is_sep(u, false));
+
+        let mut segments = Vec::<&[u16]>::new();
+        let n = remaining.split(|&u| is_sep(u, false)).filter(|s| !s.is_empty()).count();
+        for (i, segment) in remaining.split(|&u| is_sep(u, false)).filter(|s| !s.is_empty()).enumerate() {
+            match segment {
+                [DOT]       => {},
+                [DOT, DOT]  => match segments.last() {
+                    Some(&[DOT, DOT])   => segments.push(segment),
+                    Some(_)             => { segments.pop(); },
+                    None if rooted      => {},
+                    None                => segments.push(segment),
+                },
+                _ => {
+                    let segment = if i+1 == n && !trailing_sep {
+                        let end = segment.iter().rposition(|&u| u != DOT && u != SPACE).map_or(0, |e| e+1);
+                        &segment[..end]
+                    } else if segment.ends_with(&[DOT]) && !segment.ends_with(&[DOT, DOT]) {
+                        &segment[..segment.len()-1]
+                    } else {
+                        segment
+                    };
+                    if !segment.is_empty() { segments.push(segment); }
+                },
+            }
+        }
+
+        if explicit_root || (rooted && !segments.is_empty()) { units.push(BACKSLASH); }
+        for (i, segment) in segments.iter().enumerate() {
+            if i > 0 { units.push(BACKSLASH); }
+            units.extend_from_slice(segment);
+        }
+        if trailing_sep && !segments.is_empty() { units.push(BACKSLASH); }
+        if units.is_empty() { units.push(DOT); }
+        to_wstring(&units)
+    }
+
+    /// Convert an absolute path into a normalized, verbatim (`\\?\`) path, which bypasses [MAX_PATH] limits.
+    ///
+    /// Returns [None] for paths that aren't absolute (`foo`, `\foo`, `C:foo`), as they depend on the current directory.
+    pub fn to_verbatim(&self) -> Option<WString> {
+        let prefix = self.prefix()?;
+        if prefix.is_verbatim() { return Some(to_wstring(self.0)); }
+        if !self.is_absolute() { return None; }
+
+        let normalized = self.normalize();
+        let normalized = normalized.units();
+        let mut units = vec![BACKSLASH, BACKSLASH, QUESTION, BACKSLASH];
+        match prefix {
+            WPathPrefix::Unc(..)        => { units.extend("UNC".encode_utf16()); units.extend_from_slice(&normalized[1..]); },
+            WPathPrefix::DeviceNs(_)    => units.extend_from_slice(&normalized[4..]),
+            _                           => units.extend_from_slice(normalized),
+        }
+        Some(to_wstring(&units))
+    }
+
+    /// Normalize the path, converting it into a verbatim path if necessary to avoid exceeding [MAX_PATH].
+    ///
+    /// Returns [None] if a verbatim path is required, but the path isn't absolute.
+    pub fn to_verbatim_if_long(&self) -> Option<WString> {
+        let normalized = self.normalize();
+        if !WPath::from(&*normalized).exceeds_max_path() { return Some(normalized); }
+        WPath::from(&*normalized).to_verbatim()
+    }
+
+    /// Convert a verbatim (`\\?\C:\...` or `\\?\UNC\...`) path into a regular path.  Non-verbatim paths are returned unmodified.
+    ///
+    /// Returns [None] if the regular path would refer to something else, or exceed [MAX_PATH] - e.g. if it contains `/`,
+    /// `.`, `..`, or trailing dots or spaces, which Windows would normalize away.
+    pub fn strip_verbatim(&self) -> Option<WString> {
+        let units = match self.prefix() {
+            Some(WPathPrefix::VerbatimDisk(_))  => {
+                if self.0.get(6) != Some(&BACKSLASH) { return None; }
+                self.0[4..].to_vec()
+            },
+            Some(WPathPrefix::VerbatimUnc(..))  => {
+                let mut units = vec![BACKSLASH, BACKSLASH];
+                units.extend_from_slice(&self.0[8..]);
+                units
+            },
+            Some(WPathPrefix::Verbatim(_))      => return None,
+            _                                   => return Some(to_wstring(self.0)),
+        };
+
+        let path = WPath(&units);
+        if path.exceeds_max_path() || path.normalize().units() != &units[..] { return None; }
+        Some(to_wstring(&units))
+    }
+}
+
+
+
+/// An [Iterator] over the [WPathComponent]s of a [WPath] - see [WPath::components].
+#[derive(Clone, Debug)] pub struct WPathComponents<'p> {
+    prefix:     Option<WPathPrefix<'p>>,
+    root:       bool,
+    remaining:  &'p [u16],
+    verbatim:   bool,
+}
+
+impl<'p> Iterator for WPathComponents<'p> {
+    type Item = WPathComponent<'p>;
+    fn next(&mut self) -> Option<WPathComponent<'p>> {
+        if let Some(prefix) = self.prefix.take() { return Some(WPathComponent::Prefix(prefix)); }
+        if std::mem::take(&mut self.root) { return Some(WPathComponent::RootDir); }
+
+        let verbatim = self.verbatim;
+        let start = self.remaining.iter().position(|&u| !is_sep(u, verbatim))?;
+        let (segment, remaining) = split_segment(&self.remaining[start..], verbatim);
+        self.remaining = remaining;
+        Some(match segment {
+            [DOT]       if !verbatim => WPathComponent::CurDir,
+            [DOT, DOT]  if !verbatim => WPathComponent::ParentDir,
+            _                        => WPathComponent::Normal(segment),
+        })
+    }
+}
+
+impl FusedIterator for WPathComponents<'_> {}
+
+
+
+fn to_wstring(units: &[u16]) -> WString { WString::from_code_units(units.iter().copied()).unwrap() }
+
+fn is_sep(unit: u16, verbatim: bool) -> bool { unit == BACKSLASH || (!verbatim && unit == SLASH) }
+
+fn is_drive_letter(unit: u16) -> bool { matches!(unit, 0x41 ..= 0x5A | 0x61 ..= 0x7A) }
+
+fn is_disk(units: &[u16], verbatim: bool) -> bool {
+    match units {
+        [drive, COLON]          => is_drive_letter(*drive),
+        [drive, COLON, sep, ..] => is_drive_letter(*drive) && (!verbatim || is_sep(*sep, verbatim)),
+        _                       => false,
+    }
+}
+
+/// Split `units` at the first separator: the segment, and the remaining units (starting with the separator, if any)
+fn split_segment(units: &[u16], verbatim: bool) -> (&[u16], &[u16]) {
+    let end = units.iter().position(|&u| is_sep(u, verbatim)).unwrap_or(units.len());
+    units.split_at(end)
+}
+
+/// Split `units` into `server`, `share`, and the number of units consumed
+fn split_server_share(units: &[u16], verbatim: bool) -> (&[u16], &[u16], usize) {
+    let (server, remaining) = split_segment(units, verbatim);
+    if remaining.is_empty() { return (server, &[], server.len()); }
+    let (share, _) = split_segment(&remaining[1..], verbatim);
+    (server, share, server.len() + 1 + share.len())
+}
+
+fn parse_prefix(units: &[u16]) -> Option<(WPathPrefix<'_>, usize)> {
+    match units {
+        [BACKSLASH, BACKSLASH, QUESTION, BACKSLASH, rest @ ..] => {
+            let unc = rest.len() >= 4 && String::from_utf16_lossy(&rest[..3]).eq_ignore_ascii_case("UNC") && rest[3] == BACKSLASH;
+            if unc {
+                let (server, share, len) = split_server_share(&rest[4..], true);
+                Some((WPathPrefix::VerbatimUnc(server, share), 8 + len))
+            } else if is_disk(rest, true) {
+                Some((WPathPrefix::VerbatimDisk(rest[0]), 6))
+            } else {
+                let (segment, _) = split_segment(rest, true);
+                Some((WPathPrefix::Verbatim(segment), 4 + segment.len()))
+            }
+        },
+        [a, b, rest @ ..] if is_sep(*a, false) && is_sep(*b, false) => match rest {
+            [DOT, sep, rest @ ..] if is_sep(*sep, false) => {
+                let (device, _) = split_segment(rest, false);
+                Some((WPathPrefix::DeviceNs(device), 4 + device.len()))
+            },
+            _ => {
+                let (server, share, len) = split_server_share(rest, false);
+                Some((WPathPrefix::Unc(server, share), 2 + len))
+            },
+        },
+        _ if is_disk(units, false) => Some((WPathPrefix::Disk(units[0]), 2)),
+        _ => None,
+    }
+}
+
+
+
+#[test] fn prefixes() {
+    let u = |s: &str| s.encode_utf16().collect::<Vec<_>>();
+    let prefix = |s: &str| WPath::new(&u(s)).prefix().map(|p| format!("{:?}", p));
+    let fmt = |p: WPathPrefix| Some(format!("{:?}", p));
+
+    assert_eq!(prefix(r"foo\bar"),                      None);
+    assert_eq!(prefix(r"\foo"),                         None);
+    assert_eq!(prefix(r"C:foo"),                        fmt(WPathPrefix::Disk(b'C' as u16)));
+    assert_eq!(prefix(r"c:\foo"),                       fmt(WPathPrefix::Disk(b'c' as u16)));
+    assert_eq!(prefix(r"\\server\share\foo"),           fmt(WPathPrefix::Unc(&u("server"), &u("share"))));
+    assert_eq!(prefix(r"//server/share/foo"),           fmt(WPathPrefix::Unc(&u("server"), &u("share"))));
+    assert_eq!(prefix(r"\\server"),                     fmt(WPathPrefix::Unc(&u("server"), &[])));
+    assert_eq!(prefix(r"\\.\COM42"),                    fmt(WPathPrefix::DeviceNs(&u("COM42"))));
+    assert_eq!(prefix(r"\\?\C:\foo"),                   fmt(WPathPrefix::VerbatimDisk(b'C' as u16)));
+    assert_eq!(prefix(r"\\?\C:/foo"),                   fmt(WPathPrefix::Verbatim(&u("C:/foo"))));
+    assert_eq!(prefix(r"\\?\UNC\server\share\foo"),     fmt(WPathPrefix::VerbatimUnc(&u("server"), &u("share"))));
+    assert_eq!(prefix(r"\\?\Volume{guid}\foo"),         fmt(WPathPrefix::Verbatim(&u("Volume{guid}"))));
+
+    let absolute = |s: &str| WPath::new(&u(s)).is_absolute();
+    assert!( absolute(r"C:\foo"));
+    assert!(!absolute(r"C:foo"));
+    assert!(!absolute(r"\foo"));
+    assert!(!absolute(r"foo"));
+    assert!( absolute(r"\\server\share"));
+    assert!( absolute(r"\\?\C:\foo"));
+    assert!( WPath::new(&u(r"\\?\C:\foo")).is_verbatim());
+    assert!(!WPath::new(&u(r"\\.\C:\foo")).is_verbatim());
+}
+
+#[test] fn components() {
+    use WPathComponent::*;
+    let u = |s: &str| s.encode_utf16().collect::<Vec<_>>();
+    let c = |s: &str| WPath::new(&u(s)).components().map(|c| format!("{:?}", c)).collect::<Vec<_>>();
+    let f = |c: &[WPathComponent]| c.iter().map(|c| format!("{:?}", c)).collect::<Vec<_>>();
+
+    assert_eq!(c(r"C:\a\\.\..\b/"),     f(&[Prefix(WPathPrefix::Disk(b'C' as u16)), RootDir, Normal(&u("a")), CurDir, ParentDir, Normal(&u("b"))]));
+    assert_eq!(c(r"C:a"),               f(&[Prefix(WPathPrefix::Disk(b'C' as u16)), Normal(&u("a"))]));
+    assert_eq!(c(r"\\server\share"),    f(&[Prefix(WPathPrefix::Unc(&u("server"), &u("share"))), RootDir]));
+    assert_eq!(c(r"\\?\C:\a/b\..\."),   f(&[Prefix(WPathPrefix::VerbatimDisk(b'C' as u16)), RootDir, Normal(&u("a/b")), Normal(&u("..")), Normal(&u("."))]));
+    assert_eq!(c(r"a\b"),               f(&[Normal(&u("a")), Normal(&u("b"))]));
+    assert_eq!(c(r""),                  f(&[]));
+}
+
+#[test] fn normalize() {
+    let u = |s: &str| s.encode_utf16().collect::<Vec<_>>();
+    let n = |s: &str| WPath::new(&u(s)).normalize().to_string_lossy();
+
+    assert_eq!(n(r"C:\a\.\b\..\c"),             r"C:\a\c");
+    assert_eq!(n(r"C:/a//b/"),                  r"C:\a\b\");
+    assert_eq!(n(r"C:\..\..\a"),                r"C:\a");
+    assert_eq!(n(r"C:a\..\.."),                 r"C:..");
+    assert_eq!(n(r"C:\"),                       r"C:\");
+    assert_eq!(n(r"C:\a. \b.\c..\d. . "),       r"C:\a. \b\c..\d");
+    assert_eq!(n(r"C:\a\trailing space \"),     r"C:\a\trailing space \");
+    assert_eq!(n(r"C:\a\..."),                  r"C:\a");
+    assert_eq!(n(r"\\server\share\..\a"),       r"\\server\share\a");
+    assert_eq!(n(r"//server/share"),            r"\\server\share");
+    assert_eq!(n(r"\\.\COM1\.."),               r"\\.\COM1\");
+    assert_eq!(n(r"\a\..\b"),                   r"\b");
+    assert_eq!(n(r"a\..\..\b"),                 r"..\b");
+    assert_eq!(n(r"a\.."),                      r".");
+    assert_eq!(n(r""),                          r".");
+    assert_eq!(n(r"\\?\C:\a\..\b. "),           r"\\?\C:\a\..\b. ");
+
+    let surrogate = [b'C' as u16, COLON, BACKSLASH, 0xD800, DOT, BACKSLASH, 0xDC00];
+    assert_eq!(WPath::new(&surrogate).normalize().units(), [b'C' as u16, COLON, BACKSLASH, 0xD800, BACKSLASH, 0xDC00]);
+}
+
+#[test] fn verbatim() {
+    let u = |s: &str| s.encode_utf16().collect::<Vec<_>>();
+    let to = |s: &str| WPath::new(&u(s)).to_verbatim().map(|p| p.to_string_lossy());
+    let from = |s: &str| WPath::new(&u(s)).strip_verbatim().map(|p| p.to_string_lossy());
+
+    assert_eq!(to(r"C:\a\..\b").as_deref(),             Some(r"\\?\C:\b"));
+    assert_eq!(to(r"\\server\share\a").as_deref(),      Some(r"\\?\UNC\server\share\a"));
+    assert_eq!(to(r"\\.\COM1").as_deref(),              Some(r"\\?\COM1"));
+    assert_eq!(to(r"\\?\C:\a\..").as_deref(),           Some(r"\\?\C:\a\.."));
+    assert_eq!(to(r"C:a"),                              None);
+    assert_eq!(to(r"\a"),                               None);
+    assert_eq!(to(r"a"),                                None);
+
+    assert_eq!(from(r"\\?\C:\a\b").as_deref(),          Some(r"C:\a\b"));
+    assert_eq!(from(r"\\?\UNC\server\share\a").as_deref(), Some(r"\\server\share\a"));
+    assert_eq!(from(r"C:\a").as_deref(),                Some(r"C:\a"));
+    assert_eq!(from(r"\\?\C:\a\.."),                    None);
+    assert_eq!(from(r"\\?\C:\a."),                      None);
+    assert_eq!(from(r"\\?\C:\a/b"),                     None);
+    assert_eq!(from(r"\\?\C:"),                         None);
+    assert_eq!(from(r"\\?\Volume{guid}\a"),             None);
+
+    let long = format!(r"C:\{}", "a".repeat(MAX_PATH));
+    assert!(WPath::new(&u(&long)).exceeds_max_path());
+    assert_eq!(WPath::new(&u(&long)).to_verbatim_if_long().unwrap().to_string_lossy(), format!(r"\\?\{}", long));
+    assert_eq!(from(&format!(r"\\?\{}", long)),         None);
+    assert_eq!(WPath::new(&u(r"C:\a\.")).to_verbatim_if_long().unwrap(), r"C:\a");
+    assert_eq!(WPath::new(&u(&long[3..])).to_verbatim_if_long(), None);
+}
@@ -0,0 +1,20 @@
+#[cfg(doc)]
+#[macro_export]
+/// Create a &[WStr] literal at compile time
+macro_rules! wstr {
+    ( $string:literal ) => {
+        $crate::winstr_macros::wstr_impl!(($crate) $string)
+    };
+}
+
+#[cfg(not(doc))] // use wildcards for better error messages from proc macro
+#[macro_export]
+macro_rules! wstr {
+    ( $($tt:tt)+ ) => {
+        $crate::winstr_macros::wstr_impl!(($crate) $($tt)+)
+    };
+}
+
+mod danger; pub use danger::*;
+mod safer;
+mod traits; pub use traits::*;
@@ -0,0 +1,245 @@
+// The dangerous bits of the WSTR wrappers.  Only code within this module has
+// access to `self.0`, hopefully making it simpler to audit access / verify
+// struct invariants are held.
+
+#![allow(unsafe_code)]
+
+#[cfg(windows)] use winapi::shared::ntdef::LPCWSTR;
+#[cfg(not(windows))] #[allow(clippy::upper_case_acronyms)] type LPCWSTR = *const u16;
+
+use std::ops::Deref;
+use std::ptr::null;
+
+
+
+/// ### Terminator Invariants
+/// A valid `LPCWSTR` is terminated by the first `0u16` [code unit]:
+/// * There is no length prefix - finding the length requires scanning for the terminal `0u16`
+/// * The string can be empty (just the terminal `0u16`)
+/// * Rust's [WString]s and &[WStr]s **never** contain interior `0u16`s - the string would be silently truncated!
+///
+/// ### Pointer Invariants
+/// * Win32 LPCWSTRs may be null.
+/// * **Rust's [WString]s and &[WStr]s are never null** (instead, use [Option]&lt;WString&gt; or [Option]&lt;&amp;WStr&gt;).
+///
+/// [code unit]:    https://unicode.org/glossary/#code_unit
+mod invariants {}
+
+
+
+/// `WString` is a non-null, owned, `0u16`-terminated [UTF-16]ish string, suitable for passing to `LPCWSTR` parameters.
+///
+/// [UTF-16]:   https://en.wikipedia.org/wiki/UTF-16
+pub struct WString(Box<[u16]>);
+
+// WString is implicitly Send and Sync, just like the Box<[u16]> it wraps.
+
+impl Deref for WString {
+    type Target = WStr;
+    fn deref(&self) -> &WStr { unsafe { &*(self.0.as_ptr() as *const WStr) } }
+}
+
+impl WString {
+    /// Create an owned, `0u16`-terminated string from 0 or more [u16] unicode code points.
+    ///
+    /// Returns [None] if `code_units` contains a `0u16`.
+    pub fn from_code_units(code_units: impl Iterator<Item = u16>) -> Option<WString> {
+        let mut units0 = Vec::with_capacity(code_units.size_hint().0 + 1);
+        for unit in code_units {
+            if unit == 0 { return None; }
+            units0.push(unit);
+        }
+        units0.push(0u16);
+        Some(WString(units0.into_boxed_slice()))
+    }
+}
+
+
+
+/// `&WStr` is a non-null, borrowed, `0u16`-terminated [UTF-16]ish string.  Unlike &[OsStr](std::ffi::OsStr) or &[str], this is **not** a [DST]!
+///
+/// This means `&WStr` is ABI compatible with `LPCWSTR` (and [Option]&lt;&amp;WStr&gt; is ABI compatible with a nullable `LPCWSTR`.)
+/// It also means finding the length of the string requires scanning for the terminal `0u16`.
+///
+/// [DST]:      https://doc.rust-lang.org/nomicon/exotic-sizes.html#dynamically-sized-types-dsts
+/// [UTF-16]:   https://en.wikipedia.org/wiki/UTF-16
+#[repr(transparent)] pub struct WStr(u16);
+
+// &WStr is implicitly Send and Sync.  This should be 100% fine.
+
+impl WStr {
+    #[doc(hidden)]
+    pub fn wstr_macro_impl_detail(data: &'static [u16]) -> &'static WStr {
+        Self::from_units0(data).expect("`data` was supposed to be `\0`-terminated without interior `\0`s")
+    }
+
+    /// Borrows `units0` as a &amp;[WStr].
+    ///
+    /// Returns [None] unless `units0` contains exactly one `0u16`, as it's final [code unit].
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn from_units0(units0: &[u16]) -> Option<&WStr> {
+        let (&last, units) = units0.split_last()?;
+        if last != 0 || units.contains(&0) { return None; }
+        Some(unsafe { &*(units0.as_ptr() as *const WStr) })
+    }
+
+    /// Converts a &amp;[LPCWSTR] into an Option&lt;&amp;[WStr]&gt;.
+    /// By requiring a reference, this API [bounds] &amp;[WStr]'s lifetime, helping avoid bugs.
+    ///
+    /// ### Safety
+    ///
+    /// * `lpcwstr` must be null, or a valid `0u16`-terminated string for the duration of `&WStr`'s lifetime.
+    ///
+    /// [LPCWSTR]:      https://docs.microsoft.com/en-us/windows/win32/learnwin32/working-with-strings
+    /// [bounds]:       https://doc.rust-lang.org/nomicon/unbounded-lifetimes.html
+    pub unsafe fn from_lpcwstr(lpcwstr: &LPCWSTR) -> Option<&WStr> {
+        (*lpcwstr as *const WStr).as_ref()
+    }
+
+    /// Converts a [LPCWSTR] into an Option&lt;&amp;[WStr]&gt;.
+    ///
+    /// ### Safety
+    ///
+    /// * `lpcwstr` must be null, or a valid `0u16`-terminated string for the duration of `&WStr`'s lifetime.
+    /// * <span style="color: red">**&WStr's lifetime is [unbounded], an easy source of bugs.  Prefer [from_lpcwstr]!**</span>
+    ///
+    /// [LPCWSTR]:      https://docs.microsoft.com/en-us/windows/win32/learnwin32/working-with-strings
+    /// [unbounded]:    https://doc.rust-lang.org/nomicon/unbounded-lifetimes.html
+    /// [from_lpcwstr]: #method.from_lpcwstr
+    pub unsafe fn from_lpcwstr_unbounded<'w>(lpcwstr: LPCWSTR) -> Option<&'w WStr> {
+        (lpcwstr as *const WStr).as_ref()
+    }
+
+    /// The `&WStr` as a winapi-friendly `LPCWSTR`.
+    ///
+    /// ### Safety
+    ///
+    /// * `s.as_lpcwstr()` is guaranteed to be non-null and `0u16`-terminated
+    /// * It is **not** safe to modify the contents of the string through the returned pointer!
+    pub fn as_lpcwstr(&self) -> LPCWSTR { self as *const WStr as LPCWSTR }
+
+    /// Returns `true` if the string is empty (consists of only the terminal `0u16`.)  This is O(1).
+    pub fn is_empty(&self) -> bool { self.0 == 0 }
+
+    /// The [u16] unicode [code unit]s of the string, including the terminal `0u16`
+    ///
+    /// This is O(n) - the string is scanned for it's terminator.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn units0(&self) -> &[u16] {
+        let start = self.as_lpcwstr();
+        let mut len0 = 1;
+        // Safe: `self` is `0u16`-terminated per struct invariants, so we stop before reading out of bounds
+        while unsafe { *start.add(len0-1) } != 0 { len0 += 1; }
+        unsafe { std::slice::from_raw_parts(start, len0) }
+    }
+}
+
+
+
+/// Utility trait for borrowing function arguments as [LPCWSTR]s
+///
+/// ### Safety
+///
+/// By implementing this trait, you promise that [as_lpcwstr_ptr] will return a valid, **non-null**, `0u16`-terminated [LPCWSTR].
+///
+/// [as_lpcwstr_ptr]:   #method.as_lpcwstr_ptr
+/// [LPCWSTR]:          https://docs.microsoft.com/en-us/windows/win32/learnwin32/working-with-strings
+pub unsafe trait AsWStrPtr : AsRef<WStr> {
+    /// Borrow `self` as a raw [LPCWSTR]
+    ///
+    /// [LPCWSTR]:  https://docs.microsoft.com/en-us/windows/win32/learnwin32/working-with-strings
+    fn as_lpcwstr_ptr(&self) -> LPCWSTR { self.as_ref().as_lpcwstr() }
+}
+
+unsafe impl<W: AsRef<WStr>> AsWStrPtr for W {}
+
+
+
+/// Utility trait for borrowing function arguments as [LPCWSTR]s or NULL
+///
+/// ### Safety
+///
+/// By implementing this trait, you promise that [as_opt_lpcwstr_ptr] will return a valid, `0u16`-terminated [LPCWSTR] or null.
+///
+/// [as_opt_lpcwstr_ptr]:   #method.as_opt_lpcwstr_ptr
+/// [LPCWSTR]:              https://docs.microsoft.com/en-us/windows/win32/learnwin32/working-with-strings
+pub unsafe trait AsOptWStrPtr {
+    /// Borrow `self` as a raw [LPCWSTR]
+    ///
+    /// [LPCWSTR]:  https://docs.microsoft.com/en-us/windows/win32/learnwin32/working-with-strings
+    fn as_opt_lpcwstr_ptr(&self) -> LPCWSTR;
+}
+
+unsafe impl<W: AsRef<WStr>> AsOptWStrPtr for Option<W> {
+    fn as_opt_lpcwstr_ptr(&self) -> LPCWSTR { self.as_ref().map_or(null(), |s| s.as_ref().as_lpcwstr()) }
+}
+
+unsafe impl<W: AsRef<WStr>> AsOptWStrPtr for W {
+    fn as_opt_lpcwstr_ptr(&self) -> LPCWSTR { self.as_ref().as_lpcwstr() }
+}
+
+
+
+#[test] fn layout() {
+    use std::mem::align_of;
+    use std::mem::size_of;
+
+    assert_eq!(align_of::<&WStr>(),             align_of::<LPCWSTR>());
+    assert_eq!(align_of::<Option<&WStr>>(),     align_of::<LPCWSTR>());
+    assert_eq!(size_of::<&WStr>(),              size_of::<LPCWSTR>());
+    assert_eq!(size_of::<Option<&WStr>>(),      size_of::<LPCWSTR>());
+}
+
+#[test] fn core_apis() {
+    fn dbg<T: std::fmt::Debug>(v: &T) -> String { format!("{:?}", v) }
+
+    let hello_world = "Hello, world!\r\n\t\x12\u{1234}\u{10000}©™";
+    let a = WString::from_code_units(hello_world.encode_utf16()).unwrap();
+    let b : &WStr = &a;
+    let c = b.as_lpcwstr();
+    let d = unsafe { WStr::from_lpcwstr(&c) }.unwrap();
+    let e = unsafe { WStr::from_lpcwstr_unbounded(c) }.unwrap();
+    let units0 = hello_world.encode_utf16().chain(Some(0)).collect::<Vec<_>>();
+    let f = WStr::from_units0(&units0).unwrap();
+    let g = wstr!("Hello, world!\r\n\t\x12\u{1234}\u{10000}©™");
+
+    assert_eq!(dbg(&hello_world), dbg(&a));
+    assert_eq!(dbg(&hello_world), dbg(&b));
+    assert_eq!(dbg(&hello_world), dbg(&d));
+    assert_eq!(dbg(&hello_world), dbg(&e));
+    assert_eq!(dbg(&hello_world), dbg(&f));
+    assert_eq!(dbg(&hello_world), dbg(&g));
+
+    assert_eq!(a, a);
+    assert_eq!(a, b);
+    assert_eq!(a, d);
+    assert_eq!(a, e);
+    assert_eq!(a, f);
+    assert_eq!(a, g);
+
+    assert_eq!(c, a.as_lpcwstr());
+    assert_eq!(c, b.as_lpcwstr());
+    assert_eq!(c, d.as_lpcwstr());
+    assert_eq!(c, e.as_lpcwstr());
+    assert_eq!(a.len(), hello_world.encode_utf16().count());
+    assert_eq!(a.len(), d.len0()-1);
+    assert_eq!(a.len(), f.len0()-1);
+    assert_eq!(a.len(), g.len0()-1);
+
+    assert!(hello_world.encode_utf16().eq(d.units().iter().copied()));
+    assert!(hello_world.encode_utf16().eq(f.units().iter().copied()));
+    assert!(hello_world.encode_utf16().chain(Some(0)).eq(d.units0().iter().copied()));
+    assert!(hello_world.encode_utf16().chain(Some(0)).eq(g.units0().iter().copied()));
+    assert_eq!(units0.as_ptr(), f.as_lpcwstr());
+
+    assert!(unsafe { WStr::from_lpcwstr(&null()) }.is_none());
+    assert!(WString::from_code_units("interior\0nul".encode_utf16()).is_none());
+    assert!(WStr::from_units0(&[]).is_none());
+    assert!(WStr::from_units0(&[b'a' as u16]).is_none());
+    assert!(WStr::from_units0(&[b'a' as u16, 0, 0]).is_none());
+    assert!(WStr::from_units0(&[0]).unwrap().is_empty());
+    assert!(wstr!("").is_empty());
+    assert_eq!(wstr!(r"C:\Windows"), "C:\\Windows");
+}
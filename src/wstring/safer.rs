@@ -0,0 +1,180 @@
+#![forbid(unsafe_code)]
+
+use crate::*;
+
+use std::borrow::{Borrow, Cow};
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+#[cfg(windows)] use std::ffi::{OsStr, OsString};
+#[cfg(windows)] use std::os::windows::ffi::{OsStrExt, OsStringExt};
+#[cfg(windows)] use std::path::{Path, PathBuf};
+
+
+
+#[cfg(feature = "display")]
+impl Display                for WString { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&**self, fmt) } }
+impl Debug                  for WString { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(&**self, fmt) } }
+impl AsRef<WStr>            for WString { fn as_ref(&self) -> &WStr { self } }
+impl AsRef<[u16]>           for WString { fn as_ref(&self) -> &[u16] { self.units() } }
+impl Borrow<WStr>           for WString { fn borrow(&self) -> &WStr { self } }
+impl Borrow<[u16]>          for WString { fn borrow(&self) -> &[u16] { self.units() } }
+impl Clone                  for WString { fn clone(&self) -> Self { Self::from_wstr(self) } }
+impl From<&WStr>            for WString { fn from(value: &WStr      ) -> Self { Self::from_wstr(value) } }
+#[cfg(windows)] #[cfg(feature = "bstr")] impl From<&WStr> for BString { fn from(value: &WStr) -> Self { Self::from_code_units(value.units().iter().copied()).unwrap() } }
+impl Eq                     for WString {}
+impl Ord                    for WString { fn cmp(&self, other: &WString) -> Ordering { self.units().cmp(other.units()) } }
+impl Hash                   for WString { fn hash<H: Hasher>(&self, state: &mut H) { self.units().hash(state) } }
+
+#[cfg(feature = "display")]
+impl Display                for WStr    { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&String::from_utf16_lossy(self.units()), fmt) } }
+impl Debug                  for WStr    { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { debug_utf16(self.units(), fmt) } }
+impl AsRef<WStr>            for WStr    { fn as_ref(&self) -> &WStr { self } }
+impl AsRef<[u16]>           for WStr    { fn as_ref(&self) -> &[u16] { self.units() } }
+impl Borrow<[u16]>          for WStr    { fn borrow(&self) -> &[u16] { self.units() } }
+impl ToOwned                for WStr    { type Owned = WString; fn to_owned(&self) -> WString { WString::from_wstr(self) } }
+impl Eq                     for &WStr   {}
+impl Ord                    for &WStr   { fn cmp(&self, other: &&WStr) -> Ordering { self.units().cmp(other.units()) } }
+impl Hash                   for &WStr   { fn hash<H: Hasher>(&self, state: &mut H) { self.units().hash(state) } }
+
+peo!(&WStr,   &WStr  );
+peo!(WString, WString);
+
+peo!(WString,           &WStr  ); peo!(&WStr,   WString         );
+peo!(&?[u16],           &WStr  ); peo!(&WStr,   &?[u16]         );
+peo!(&?str,             &WStr  ); peo!(&WStr,   &?str           );
+peo!(String,            &WStr  ); peo!(&WStr,   String          );
+peo!(Cow<'_, [u16]>,    &WStr  ); peo!(&WStr,   Cow<'_, [u16]>  );
+peo!(Cow<'_, str>,      &WStr  ); peo!(&WStr,   Cow<'_, str>    );
+
+//peo!(&WStr,           WString); peo!(WString, &WStr           ); // already covered
+peo!(&?str,             WString); peo!(WString, &?str           );
+peo!(&?[u16],           WString); peo!(WString, &?[u16]         );
+peo!(String,            WString); peo!(WString, String          );
+peo!(Cow<'_, [u16]>,    WString); peo!(WString, Cow<'_, [u16]>  );
+peo!(Cow<'_, str>,      WString); peo!(WString, Cow<'_, str>    );
+
+#[cfg(windows)] mod windows_peo {
+    use super::*;
+    peo!(&?OsStr,           &WStr  ); peo!(&WStr,   &?OsStr         );
+    peo!(OsString,          &WStr  ); peo!(&WStr,   OsString        );
+    peo!(&?Path,            &WStr  ); peo!(&WStr,   &?Path          );
+    peo!(PathBuf,           &WStr  ); peo!(&WStr,   PathBuf         );
+    peo!(Cow<'_, OsStr>,    &WStr  ); peo!(&WStr,   Cow<'_, OsStr>  );
+    peo!(Cow<'_, Path>,     &WStr  ); peo!(&WStr,   Cow<'_, Path>   );
+
+    peo!(&?OsStr,           WString); peo!(WString, &?OsStr         );
+    peo!(OsString,          WString); peo!(WString, OsString        );
+    peo!(&?Path,            WString); peo!(WString, &?Path          );
+    peo!(PathBuf,           WString); peo!(WString, PathBuf         );
+    peo!(Cow<'_, OsStr>,    WString); peo!(WString, Cow<'_, OsStr>  );
+    peo!(Cow<'_, Path>,     WString); peo!(WString, Cow<'_, Path>   );
+
+    #[cfg(feature = "bstr")] peo!(&BStr,    &WStr  ); #[cfg(feature = "bstr")] peo!(&WStr,   &BStr   );
+    #[cfg(feature = "bstr")] peo!(BString,  &WStr  ); #[cfg(feature = "bstr")] peo!(&WStr,   BString );
+    #[cfg(feature = "bstr")] peo!(&BStr,    WString); #[cfg(feature = "bstr")] peo!(WString, &BStr   );
+    #[cfg(feature = "bstr")] peo!(BString,  WString); #[cfg(feature = "bstr")] peo!(WString, BString );
+}
+
+
+
+impl<'s> UTF16ish<'s> for WStr {
+    type Iter = std::iter::Copied<std::slice::Iter<'s, u16>>;
+    fn utf16ish(&'s self) -> Self::Iter { self.units().iter().copied() }
+}
+
+impl<'s> UTF16ish<'s> for WString {
+    type Iter = std::iter::Copied<std::slice::Iter<'s, u16>>;
+    fn utf16ish(&'s self) -> Self::Iter { self.units().iter().copied() }
+}
+
+impl Utf16Hash for WStr     { fn utf16_units(&self) -> Utf16Units<'_> { self.units().into() } }
+impl Utf16Hash for WString  { fn utf16_units(&self) -> Utf16Units<'_> { self.units().into() } }
+
+
+
+impl WString {
+    /// Create a [WString] from a [str].  Returns [None] if `s` contains `'\0'`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: impl AsRef<str>) -> Option<Self> { Self::from_code_units(s.as_ref().encode_utf16()) }
+
+    /// Create a [WString] from a [OsStr].  Returns [None] if `s` contains `'\0'`.
+    #[cfg(windows)]
+    pub fn from_osstr(s: impl AsRef<OsStr>) -> Option<Self> { Self::from_code_units(s.as_ref().encode_wide()) }
+
+    /// Create a [WString] from a [BStr].  Returns [None] if `s` contains `'\0'`.
+    #[cfg(windows)] #[cfg(feature = "bstr")]
+    pub fn from_bstr(s: impl AsRef<BStr>) -> Option<Self> { Self::from_code_units(s.as_ref().units().iter().copied()) }
+
+    /// Create a [WString] from a [WStr]
+    pub fn from_wstr(s: impl AsRef<WStr>) -> Self { Self::from_code_units(s.as_ref().units().iter().copied()).unwrap() }
+}
+
+
+
+impl WStr {
+    /// Length in [u16] unicode [code unit]s, excluding the terminal `0u16`
+    ///
+    /// This is O(n) - the string is scanned for it's terminator.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn len(&self) -> usize { self.len0() - 1 }
+
+    /// Length in [u16] unicode [code unit]s, including the terminal `0u16`
+    ///
+    /// This is O(n) - the string is scanned for it's terminator.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn len0(&self) -> usize { self.units0().len() }
+
+    /// The [u16] unicode [code unit]s of the string, excluding the terminal `0u16`
+    ///
+    /// This is O(n) - the string is scanned for it's terminator.
+    ///
+    /// [code unit]:    https://unicode.org/glossary/#code_unit
+    pub fn units(&self) -> &[u16] { let u = self.units0(); &u[..u.len()-1] }
+
+    /// Decode the string as UTF-16, replacing invalid data (unpaired surrogates) with [U+FFFD](std::char::REPLACEMENT_CHARACTER)
+    pub fn to_string_lossy(&self) -> String { String::from_utf16_lossy(self.units()) }
+
+    /// Convert the string into an [OsString]
+    #[cfg(windows)]
+    pub fn to_os_string(&self) -> OsString { OsString::from_wide(self.units()) }
+}
+
+#[cfg(windows)] #[cfg(feature = "bstr")]
+impl BStr {
+    /// Borrow the `&BStr` as a &amp;[WStr] without copying.  Returns [None] if `self` contains interior `0u16`s.
+    pub fn as_wstr(&self) -> Option<&WStr> { WStr::from_units0(self.units0()) }
+}
+
+
+
+#[test] fn comparisons() {
+    let a = WString::from_str("abc").unwrap();
+    let b : &WStr = &a;
+    assert_eq!(a, "abc");
+    assert_eq!(b, "abc");
+    assert_eq!("abc", a);
+    assert_eq!(String::from("abc"), b);
+    assert_eq!(a, &[b'a' as u16, b'b' as u16, b'c' as u16][..]);
+    assert!(a < *"abd");
+    assert!(b > "ab");
+    assert_eq!(b.to_owned(), a);
+    assert_eq!(a.to_string_lossy(), "abc");
+    assert_eq!(format!("{:?}", WString::from_code_units([b'a' as u16, 0xD800, b'"' as u16].iter().copied()).unwrap()), "\"a\\u{d800}\\\"\"");
+    #[cfg(feature = "display")] assert_eq!(format!("{}", WString::from_code_units([b'a' as u16, 0xD800].iter().copied()).unwrap()), "a\u{FFFD}");
+}
+
+#[cfg(windows)] #[cfg(feature = "bstr")] #[test] fn bstr_conversions() {
+    let a = bstr!("abc");
+    let b = a.as_wstr().unwrap();
+    assert_eq!(a.as_lpcwstr(), b.as_lpcwstr());
+    assert_eq!(a, b);
+    assert_eq!(WString::from_bstr(a).unwrap(), a);
+    assert_eq!(BString::from(b), b);
+    assert!(bstr!("a\0c").as_wstr().is_none());
+    assert!(WString::from_bstr(bstr!("a\0c")).is_none());
+    assert_eq!(WString::from_osstr(OsStr::new("abc")).unwrap(), OsStr::new("abc"));
+    assert_eq!(b.to_os_string(), OsStr::new("abc"));
+}
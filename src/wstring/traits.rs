@@ -0,0 +1,69 @@
+use crate::*;
+
+#[cfg(windows)] use std::ffi::{OsStr, OsString};
+#[cfg(windows)] use std::path::{Path, PathBuf};
+
+
+
+/// Similar to `Into<WString>`, except that this won't require copying `&WStr` or `&WString` arguments.
+///
+/// Conversions panic if the string contains interior `'\0'`s, which would otherwise silently truncate the string.
+pub trait IntoWStr {
+    type WStr : AsWStrPtr;
+    fn into(self) -> Self::WStr;
+}
+
+impl<W: AsRef<WStr>> IntoWStr for W {
+    type WStr = Self;
+    fn into(self) -> Self { self }
+}
+
+impl IntoWStr for  String   { type WStr = WString; fn into(self) -> WString { WString::from_str(&self).unwrap() } }
+impl IntoWStr for &String   { type WStr = WString; fn into(self) -> WString { WString::from_str( self).unwrap() } }
+impl IntoWStr for &str      { type WStr = WString; fn into(self) -> WString { WString::from_str( self).unwrap() } }
+#[cfg(windows)] impl IntoWStr for  OsString { type WStr = WString; fn into(self) -> WString { WString::from_osstr(&self).unwrap() } }
+#[cfg(windows)] impl IntoWStr for &OsString { type WStr = WString; fn into(self) -> WString { WString::from_osstr( self).unwrap() } }
+#[cfg(windows)] impl IntoWStr for &OsStr    { type WStr = WString; fn into(self) -> WString { WString::from_osstr( self).unwrap() } }
+#[cfg(windows)] impl IntoWStr for  PathBuf  { type WStr = WString; fn into(self) -> WString { WString::from_osstr(&self).unwrap() } }
+#[cfg(windows)] impl IntoWStr for &PathBuf  { type WStr = WString; fn into(self) -> WString { WString::from_osstr( self).unwrap() } }
+#[cfg(windows)] impl IntoWStr for &Path     { type WStr = WString; fn into(self) -> WString { WString::from_osstr( self).unwrap() } }
+
+
+
+/// Similar to `Into<Option<WString>>`, except that this won't require copying
+/// `&WStr` or `&WString` arguments.  Additionally, you can use `()` in lieu of
+/// `None` - whereas trying to pass `None` to a function accepting
+/// `Into<Option<WString>>` would cause ambiguous type errors.
+///
+/// Conversions panic if the string contains interior `'\0'`s, which would otherwise silently truncate the string.
+pub trait IntoOptWStr {
+    type OptWStr : AsOptWStrPtr;
+    fn into(self) -> Self::OptWStr;
+}
+
+impl<W: AsOptWStrPtr + Sized> IntoOptWStr for W {
+    type OptWStr = Self;
+    fn into(self) -> Self { self }
+}
+
+impl IntoOptWStr for ()        { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { None } }
+
+impl IntoOptWStr for  String   { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { Some(WString::from_str(&self).unwrap()) } }
+impl IntoOptWStr for &String   { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { Some(WString::from_str( self).unwrap()) } }
+impl IntoOptWStr for &str      { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { Some(WString::from_str( self).unwrap()) } }
+#[cfg(windows)] impl IntoOptWStr for  OsString { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { Some(WString::from_osstr(&self).unwrap()) } }
+#[cfg(windows)] impl IntoOptWStr for &OsString { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { Some(WString::from_osstr( self).unwrap()) } }
+#[cfg(windows)] impl IntoOptWStr for &OsStr    { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { Some(WString::from_osstr( self).unwrap()) } }
+#[cfg(windows)] impl IntoOptWStr for  PathBuf  { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { Some(WString::from_osstr(&self).unwrap()) } }
+#[cfg(windows)] impl IntoOptWStr for &PathBuf  { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { Some(WString::from_osstr( self).unwrap()) } }
+#[cfg(windows)] impl IntoOptWStr for &Path     { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { Some(WString::from_osstr( self).unwrap()) } }
+
+impl IntoOptWStr for Option< String  > { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { self.map(|s| WString::from_str(&s).unwrap()) } }
+impl IntoOptWStr for Option<&String  > { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { self.map(|s| WString::from_str( s).unwrap()) } }
+impl IntoOptWStr for Option<&str     > { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { self.map(|s| WString::from_str( s).unwrap()) } }
+#[cfg(windows)] impl IntoOptWStr for Option< OsString> { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { self.map(|s| WString::from_osstr(&s).unwrap()) } }
+#[cfg(windows)] impl IntoOptWStr for Option<&OsString> { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { self.map(|s| WString::from_osstr( s).unwrap()) } }
+#[cfg(windows)] impl IntoOptWStr for Option<&OsStr   > { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { self.map(|s| WString::from_osstr( s).unwrap()) } }
+#[cfg(windows)] impl IntoOptWStr for Option< PathBuf > { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { self.map(|s| WString::from_osstr(&s).unwrap()) } }
+#[cfg(windows)] impl IntoOptWStr for Option<&PathBuf > { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { self.map(|s| WString::from_osstr( s).unwrap()) } }
+#[cfg(windows)] impl IntoOptWStr for Option<&Path    > { type OptWStr = Option<WString>; fn into(self) -> Option<WString> { self.map(|s| WString::from_osstr( s).unwrap()) } }
@@ -0,0 +1,160 @@
+use proc_macro::{TokenStream, TokenTree, Delimiter, Group, Ident, Literal, Punct, Spacing, Span};
+
+use std::convert::TryFrom;
+use std::iter::FromIterator;
+
+
+
+/// Parses `($crate) "literal"` into `$crate` and the literal
+pub(super) fn parse_args(input: TokenStream, macro_name: &str) -> Result<(TokenStream, Literal), TokenStream> {
+    let mut input = input.into_iter();
+
+    let crate_ = match input.next() {
+        Some(TokenTree::Group(g)) => match g.delimiter() {
+            Delimiter::Brace        => return Err(compile_error("expected `($crate)` as first token, got `{ ... }`", g.span()).into()),
+            Delimiter::Bracket      => return Err(compile_error("expected `($crate)` as first token, got `[ ... ]`", g.span()).into()),
+            Delimiter::None         => return Err(compile_error("expected `($crate)` as first token, got `Ø ... Ø`", g.span()).into()),
+            Delimiter::Parenthesis  => g.stream(),
+        },
+        Some(tt)    => return Err(compile_error(format!("expected `($crate)` as first token, got `{}`", tt), tt.span()).into()),
+        None        => return Err(compile_error("expected `($crate)` as first token, got nothing", Span::call_site()).into()),
+    };
+
+    let literal = match input.next() {
+        Some(TokenTree::Literal(lit)) => {
+            if let Some(unexpected) = input.next() {
+                return Err(compile_error(format!("{}!(...) expects a single string argument, unexpected `{}` token after said argument", macro_name, unexpected), unexpected.span()).into());
+            }
+            lit
+        },
+        Some(TokenTree::Group(g)) => match g.delimiter() {
+            Delimiter::Brace        => return Err(compile_error("expected `\"string\"` as second token, got `{ ... }`", g.span()).into()),
+            Delimiter::Bracket      => return Err(compile_error("expected `\"string\"` as second token, got `[ ... ]`", g.span()).into()),
+            Delimiter::None         => return Err(compile_error("expected `\"string\"` as second token, got `Ø ... Ø`", g.span()).into()),
+            Delimiter::Parenthesis  => return Err(compile_error("expected `\"string\"` as second token, got `( ... )`", g.span()).into()),
+        },
+        Some(tt)    => return Err(compile_error(format!("expected `\"string\"` as second token, got `{}`", tt), tt.span()).into()),
+        None        => return Err(compile_error(format!("expected string argument to {}!() macro", macro_name), Span::call_site()).into()),
+    };
+
+    Ok((crate_, literal))
+}
+
+/// Parses a string literal (including escape sequences) into UTF-16 code units
+pub(super) fn parse_str(literal: &Literal) -> Result<Vec<u16>, TokenStream> {
+    let s = literal.span();
+
+    let literal = literal.to_string();
+    let (raw, mut literal) = match literal.strip_prefix('r') {
+        Some(literal)   => (true, literal),
+        None            => (false, &literal[..]),
+    };
+
+    while let Some(l) = literal.strip_prefix("#") {
+        literal = l.strip_suffix("#").ok_or_else(|| compile_error("expected string literal to havea balanced number of starting and ending `#`s", s))?;
+    }
+
+    let literal = literal
+        .strip_prefix("\"").ok_or_else(|| compile_error("expected string literal to start with `\"`", s))?
+        .strip_suffix("\"").ok_or_else(|| compile_error("expected string literal to end with `\"`", s))?;
+
+    let mut utf16 = Vec::new();
+    let mut chars = literal.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' if !raw => {
+                match chars.next() {
+                    Some('0') => utf16.push(b'\0' as u16),
+                    Some('t') => utf16.push(b'\t' as u16),
+                    Some('n') => utf16.push(b'\n' as u16),
+                    Some('r') => utf16.push(b'\r' as u16),
+                    Some('\\') => utf16.push(b'\\' as u16),
+                    Some('\'') => utf16.push(b'\'' as u16),
+                    Some('\"') => utf16.push(b'\"' as u16),
+                    Some('x') => {
+                        let mut v = 0u16;
+                        for _ in 0..2 {
+                            let ch = chars.next().ok_or_else(|| compile_error("expected two hexidecimal characters after `\\x` escape sequence", s))?;
+                            v = v * 16 + match ch {
+                                ch @ '0' ..= '9'    => ch as u16 - b'0' as u16,
+                                ch @ 'a' ..= 'f'    => ch as u16 - b'a' as u16 + 10,
+                                ch @ 'A' ..= 'F'    => ch as u16 - b'A' as u16 + 10,
+                                _                   => Err(compile_error("expected two hexidecimal characters after `\\x` escape sequence", s))?,
+                            };
+                        }
+                        utf16.push(v);
+                    },
+                    Some('u') => {
+                        let mut v = 0u32;
+                        if chars.next() != Some('{') { Err(compile_error("expected `{` after `\\u` escape sequence", s))? }
+                        for i in 0..7 {
+                            let ch = chars.next().ok_or_else(|| compile_error("expected 1-6 hexidecimal characters in `\\u{...}` escape sequence", s))?;
+                            v = v * 16 + match ch {
+                                ch @ '0' ..= '9' if i != 6  => ch as u32 - b'0' as u32,
+                                ch @ 'a' ..= 'f' if i != 6  => ch as u32 - b'a' as u32 + 10,
+                                ch @ 'A' ..= 'F' if i != 6  => ch as u32 - b'A' as u32 + 10,
+                                '}'              if i != 0  => break,
+                                _                           => Err(compile_error("expected 1-6 hexidecimal characters in `\\u{...}` escape sequence", s))?,
+                            };
+                        }
+                        let ch = char::try_from(v).map_err(|_| compile_error(format!("invalid unicode codepoint U+{:04X} in `\\u{{...}}` escape sequence", v), s))?;
+                        let mut buf = [0, 0];
+                        utf16.extend(ch.encode_utf16(&mut buf[..]).iter().copied());
+                    },
+                    Some(ch) => {
+                        let mut buf = [0, 0];
+                        utf16.extend(ch.encode_utf16(&mut buf[..]).iter().copied());
+                    },
+                    None => return Err(compile_error("expected character after `\\` in string", s).into()),
+                }
+            },
+            ch => {
+                let mut buf = [0, 0];
+                utf16.extend(ch.encode_utf16(&mut buf[..]).iter().copied());
+            },
+        }
+    }
+
+    Ok(utf16)
+}
+
+pub(super) fn ttid(string: &str, span: Span) -> TokenTree {
+    Ident::new(string, span).into()
+}
+
+pub(super) fn ttp(ch: char, spacing: Spacing, span: Span) -> TokenTree {
+    let mut o = Punct::new(ch, spacing);
+    o.set_span(span);
+    o.into()
+}
+
+pub(super) fn ttg(delimiter: Delimiter, span: Span, tts: impl IntoIterator<Item = TokenTree>) -> TokenTree {
+    let mut o = Group::new(delimiter, TokenStream::from_iter(tts));
+    o.set_span(span);
+    o.into()
+}
+
+pub(super) fn tts(str: impl AsRef<str>, span: Span) -> TokenTree {
+    let mut o = Literal::string(str.as_ref());
+    o.set_span(span);
+    o.into()
+}
+
+pub(super) fn ttn(n: u32, span: Span) -> TokenTree {
+    let mut o = Literal::u32_unsuffixed(n);
+    o.set_span(span);
+    o.into()
+}
+
+pub(super) fn compile_error(error: impl AsRef<str>, s: Span) -> TokenTree {
+    ttg(Delimiter::None, s, vec![
+        ttid("core", s),
+        ttp(':', Spacing::Joint, s),
+        ttp(':', Spacing::Joint, s),
+        ttid("compile_error", s),
+        ttp('!', Spacing::Joint, s),
+        ttg(Delimiter::Parenthesis, s, vec![
+            tts(error.as_ref(), s),
+        ]),
+    ])
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "hstr")]
+
+use super::common::*;
+
+use proc_macro::{TokenStream, TokenTree, Delimiter, Spacing, Span};
+
+
+
+pub(super) fn hstr_impl(input: TokenStream) -> TokenStream {
+    let (crate_, literal) = match parse_args(input, "hstr") {
+        Ok(r) => r,
+        Err(err) => return err,
+    };
+
+    let parsed_literal = match parse_str(&literal).and_then(|utf16| hstr_tokens(utf16, literal.span())) {
+        Ok(r) => r,
+        Err(err) => return err,
+    };
+
+    // { static HSTR : $crate::HStringRef<'static> = $crate::HStringRef::hstr_macro_impl_detail(&[...]); &HSTR }
+    let s = literal.span();
+    let mut ty = TokenStream::new();
+    ty.extend(crate_);
+    ty.extend(vec![
+        ttp(':', Spacing::Joint, s),
+        ttp(':', Spacing::Joint, s),
+        ttid("HStringRef", s),
+    ]);
+
+    let mut block = vec![ttid("static", s), ttid("HSTR", s), ttp(':', Spacing::Alone, s)];
+    block.extend(ty.clone());
+    block.extend(vec![
+        ttp('<', Spacing::Joint, s),
+        ttp('\'', Spacing::Joint, s),
+        ttid("static", s),
+        ttp('>', Spacing::Alone, s),
+        ttp('=', Spacing::Alone, s),
+    ]);
+    block.extend(ty);
+    block.extend(vec![
+        ttp(':', Spacing::Joint, s),
+        ttp(':', Spacing::Joint, s),
+        ttid("hstr_macro_impl_detail", s),
+        ttg(Delimiter::Parenthesis, s, vec![parsed_literal]),
+        ttp(';', Spacing::Alone, s),
+        ttp('&', Spacing::Joint, s),
+        ttid("HSTR", s),
+    ]);
+
+    ttg(Delimiter::Brace, s, block).into()
+}
+
+fn hstr_tokens(utf16: Vec<u16>, s: Span) -> Result<TokenTree, TokenStream> {
+    // Length is stored in the fast-pass header - interior `0u16`s are fine, but a terminal `0u16` is still required
+    if utf16.len() >= u32::MAX as usize { return Err(compile_error("hstr!(...) string too long", s).into()); }
+
+    let mut tokens = Vec::new();
+    for cu in utf16 {
+        tokens.push(ttn(cu.into(), s));
+        tokens.push(ttp(',', Spacing::Joint, s));
+    }
+    tokens.push(ttn(0, s));
+
+    Ok(ttg(Delimiter::None, s, vec![
+        ttp('&', Spacing::Joint, s),
+        ttg(Delimiter::Bracket, s, tokens),
+    ]))
+}
@@ -1,6 +1,19 @@
 extern crate proc_macro;
 
+#[cfg(any(all(windows, feature = "bstr"), feature = "wstr", feature = "ustr", feature = "hstr"))] mod common;
 #[cfg(windows)] mod bstring;
+mod wstring;
+mod ustring;
+mod hstring;
 
 #[cfg(feature = "bstr")] #[cfg(windows)] #[proc_macro]
 pub fn bstr_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream { bstring::bstr_impl(input) }
+
+#[cfg(feature = "wstr")] #[proc_macro]
+pub fn wstr_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream { wstring::wstr_impl(input) }
+
+#[cfg(feature = "ustr")] #[proc_macro]
+pub fn ustr_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream { ustring::ustr_impl(input) }
+
+#[cfg(feature = "hstr")] #[proc_macro]
+pub fn hstr_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream { hstring::hstr_impl(input) }
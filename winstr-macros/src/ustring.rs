@@ -0,0 +1,51 @@
+#![cfg(feature = "ustr")]
+
+use super::common::*;
+
+use proc_macro::{TokenStream, TokenTree, Delimiter, Spacing, Span};
+
+
+
+pub(super) fn ustr_impl(input: TokenStream) -> TokenStream {
+    let (crate_, literal) = match parse_args(input, "ustr") {
+        Ok(r) => r,
+        Err(err) => return err,
+    };
+
+    let parsed_literal = match parse_str(&literal).and_then(|utf16| ustr_tokens(utf16, literal.span())) {
+        Ok(r) => r,
+        Err(err) => return err,
+    };
+
+    let s = literal.span();
+    let mut o = TokenStream::new();
+    o.extend(crate_);
+    o.extend(vec![
+        ttp(':', Spacing::Joint, s),
+        ttp(':', Spacing::Joint, s),
+        ttid("UnicodeStr", s),
+        ttp(':', Spacing::Joint, s),
+        ttp(':', Spacing::Joint, s),
+        ttid("ustr_macro_impl_detail", s),
+        ttg(Delimiter::Parenthesis, s, vec![parsed_literal])
+    ]);
+
+    o
+}
+
+fn ustr_tokens(utf16: Vec<u16>, s: Span) -> Result<TokenTree, TokenStream> {
+    // Length is stored in the `UNICODE_STRING` header - interior `0u16`s are fine, but `Length` is only 16 bits (in bytes)
+    if utf16.len() > 0x7FFF { return Err(compile_error("ustr!(...) cannot be longer than 32767 UTF-16 code units, as `UNICODE_STRING::Length` would overflow", s).into()); }
+
+    let mut tokens = Vec::new();
+    for cu in utf16 {
+        tokens.push(ttn(cu.into(), s));
+        tokens.push(ttp(',', Spacing::Joint, s));
+    }
+    tokens.push(ttn(0, s));
+
+    Ok(ttg(Delimiter::None, s, vec![
+        ttp('&', Spacing::Joint, s),
+        ttg(Delimiter::Bracket, s, tokens),
+    ]))
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "wstr")]
+
+use super::common::*;
+
+use proc_macro::{TokenStream, TokenTree, Delimiter, Spacing, Span};
+
+
+
+pub(super) fn wstr_impl(input: TokenStream) -> TokenStream {
+    let (crate_, literal) = match parse_args(input, "wstr") {
+        Ok(r) => r,
+        Err(err) => return err,
+    };
+
+    let parsed_literal = match parse_str(&literal).and_then(|utf16| wstr_tokens(utf16, literal.span())) {
+        Ok(r) => r,
+        Err(err) => return err,
+    };
+
+    let s = literal.span();
+    let mut o = TokenStream::new();
+    o.extend(crate_);
+    o.extend(vec![
+        ttp(':', Spacing::Joint, s),
+        ttp(':', Spacing::Joint, s),
+        ttid("WStr", s),
+        ttp(':', Spacing::Joint, s),
+        ttp(':', Spacing::Joint, s),
+        ttid("wstr_macro_impl_detail", s),
+        ttg(Delimiter::Parenthesis, s, vec![parsed_literal])
+    ]);
+
+    o
+}
+
+fn wstr_tokens(utf16: Vec<u16>, s: Span) -> Result<TokenTree, TokenStream> {
+    // No length prefix - just the code units and a terminal `0u16`
+    if utf16.contains(&0) { return Err(compile_error("wstr!(...) cannot contain `\\0`s, as they would terminate the string early", s).into()); }
+
+    let mut tokens = Vec::new();
+    for cu in utf16 {
+        tokens.push(ttn(cu.into(), s));
+        tokens.push(ttp(',', Spacing::Joint, s));
+    }
+    tokens.push(ttn(0, s));
+
+    Ok(ttg(Delimiter::None, s, vec![
+        ttp('&', Spacing::Joint, s),
+        ttg(Delimiter::Bracket, s, tokens),
+    ]))
+}